@@ -1,36 +1,61 @@
-/// REST API 演示 —— 从零 TCP 实现的 HTTP 服务器
-///
-/// 运行方式：
-///   cd example && cargo run --bin web-demo
-///
-/// 接口：
-///   GET  /health                → {"status":"ok"}
-///   GET  /products              → 所有商品 JSON 数组
-///   GET  /products/{id}         → 单个商品
-///   POST /products              → 创建商品（JSON body）
-///   PUT  /products/{id}         → 更新商品
-///   DELETE /products/{id}       → 删除商品
-///
-/// curl 测试：
-///   curl -s http://localhost:8080/health
-///   curl -s http://localhost:8080/products
-///   curl -s -X POST http://localhost:8080/products \
-///        -H "Content-Type: application/json" \
-///        -d '{"name":"Rust Book","price":39.9,"stock":100}'
-///   curl -s http://localhost:8080/products/1
-///   curl -s -X PUT  http://localhost:8080/products/1 \
-///        -d '{"name":"Rust Book 2nd Ed","price":45.0,"stock":80}'
-///   curl -s -X DELETE http://localhost:8080/products/1
+//! REST API 演示 —— 从零 TCP 实现的 HTTP 服务器
+//!
+//! 运行方式：
+//!   cd example && cargo run --bin web-demo
+//!
+//! 接口：
+//!   GET  /health                → {"status":"ok"}
+//!   GET  /products              → 所有商品 JSON 数组（?in_stock=yes 只看有库存的）
+//!   GET  /products/{id}         → 单个商品
+//!   POST /products              → 创建商品（Json<T> body，#[Valid] 校验失败返回 400，非 JSON 返回 415）
+//!   PUT  /products/{id}         → 更新商品（同样校验；非数字的 id 返回 400）
+//!   DELETE /products/{id}       → 删除商品
+//!   GET  /export/products.csv   → 以 chunked 编码逐行输出的 CSV（流式响应）
+//!   GET  /stats                 → 商品统计（`async fn` handler）
+//!   POST /cart/{id}             → 把商品放进当前会话的购物车（#[Scope("session")]）
+//!   GET  /cart                  → 当前会话购物车中的商品 id
+//!
+//! 请求由 `server.threads` 个工作线程并发处理；在终端按回车优雅停机：
+//! 不再接受新连接，处理完进行中的请求后关闭容器。
+//!
+//! 使用 tokio + hyper 实现的异步服务器：
+//!   cd example && cargo run --bin web_demo --features async
+//!
+//! curl 测试（端口来自 application.properties 中的 server.port）：
+//!   curl -s http://localhost:9090/health
+//!   curl -s http://localhost:9090/products
+//!   curl -s -X POST http://localhost:9090/products \
+//!        -H "Content-Type: application/json" \
+//!        -d '{"name":"Rust Book","price":39.9,"stock":100}'
+//!   curl -s http://localhost:9090/products/1
+//!   curl -s -X PUT  http://localhost:9090/products/1 \
+//!        -H "Content-Type: application/json" \
+//!        -d '{"name":"Rust Book 2nd Ed","price":45.0,"stock":80}'
+//!   curl -s -X DELETE http://localhost:9090/products/1
+//!   curl -s --raw http://localhost:9090/export/products.csv
+//!   curl -sI http://localhost:9090/products            # HEAD：与 GET 相同的头部，没有 body
+//!   curl -s http://localhost:9090/health http://localhost:9090/products   # 复用同一个连接
+//!   curl -s -c jar -b jar -X POST http://localhost:9090/cart/1
+//!   curl -s -c jar -b jar http://localhost:9090/cart
 
+use std::fmt;
+use std::sync::Mutex;
+use std::thread;
+
+use serde::{Deserialize, Serialize};
 use spring_boot::{
-    Application, ApplicationContext, DeleteMapping, GetMapping, HttpServer,
-    PostMapping, PutMapping, Repository,
+    Application, ApplicationContext, Component, ConfigurableApplicationContext, DeleteMapping,
+    GetMapping, PostMapping, PutMapping, Repository, ServerProperties, Validate,
 };
-use spring_boot::web::{HttpRequest, HttpResponse};
+#[cfg(feature = "async")]
+use spring_boot::AsyncHttpServer;
+#[cfg(not(feature = "async"))]
+use spring_boot::HttpServer;
+use spring_boot::web::{HttpRequest, HttpResponse, Json, ResponseError, StatusCode};
 
 // ── 实体 ──────────────────────────────────────────────────────────────────────
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 struct Product {
     name:  String,
     price: f64,
@@ -41,12 +66,53 @@ impl Product {
     fn new(name: &str, price: f64, stock: u32) -> Self {
         Self { name: name.to_string(), price, stock }
     }
+}
+
+/// 响应中的商品：`{"id":1,"name":"…","price":9.9,"stock":50}`
+#[derive(Debug, Serialize)]
+struct ProductView {
+    id: u64,
+    #[serde(flatten)]
+    product: Product,
+}
+
+/// POST / PUT /products 的请求体：serde 负责解析，#[derive(Validate)] 负责校验
+#[derive(Debug, Deserialize, Validate)]
+struct NewProduct {
+    #[NotBlank]
+    #[Size(max = 64)]
+    name:  String,
+    #[Min(0)]
+    price: f64,
+    #[Max(10000)]
+    stock: u32,
+}
+
+impl From<Json<NewProduct>> for Product {
+    fn from(Json(p): Json<NewProduct>) -> Self {
+        Self { name: p.name, price: p.price, stock: p.stock }
+    }
+}
+
+/// handler 返回 `Err(ProductError)` 时由 ResponseError 转换为 404
+#[derive(Debug)]
+enum ProductError {
+    NotFound(u64),
+}
+
+impl fmt::Display for ProductError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProductError::NotFound(id) => write!(f, "product {} not found", id),
+        }
+    }
+}
 
-    fn to_json(&self, id: u64) -> String {
-        format!(
-            r#"{{"id":{},"name":"{}","price":{},"stock":{}}}"#,
-            id, self.name, self.price, self.stock
-        )
+impl ResponseError for ProductError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ProductError::NotFound(_) => StatusCode::NOT_FOUND,
+        }
     }
 }
 
@@ -67,104 +133,112 @@ fn health(_req: &HttpRequest) -> HttpResponse {
 //
 // 宏从参数类型 `ProductRepository` 推导 bean_name = "productRepository"
 
-/// GET /products — 所有商品
+/// GET /products — 所有商品；`?in_stock=yes` 只返回有库存的商品。返回值由 serde 序列化为 JSON 数组
 #[GetMapping("/products")]
-fn list_products(repo: &ProductRepository, _req: &HttpRequest) -> HttpResponse {
-    let all = repo.find_all_cloned();
-    let items: Vec<String> = all.iter().map(|(id, p)| p.to_json(*id)).collect();
-    HttpResponse::ok().json(format!("[{}]", items.join(",")))
+fn list_products(repo: &ProductRepository, #[RequestParam(default = "false")] in_stock: bool) -> Vec<ProductView> {
+    repo.find_all_cloned()
+        .into_iter()
+        .filter(|(_, p)| !in_stock || p.stock > 0)
+        .map(|(id, product)| ProductView { id, product })
+        .collect()
 }
 
-/// GET /products/{id}
+/// GET /products/{id} — 路径参数经 ConversionService 转换，非数字的 id 直接返回 400
 #[GetMapping("/products/{id}")]
-fn get_product(repo: &ProductRepository, req: &HttpRequest) -> HttpResponse {
-    let id: u64 = req.path_param("id").unwrap_or("0").parse().unwrap_or(0);
-    repo.find_by_id(id, |p| match p {
-        Some(p) => HttpResponse::ok().json(p.to_json(id)),
-        None    => HttpResponse::not_found().json(
-            format!(r#"{{"error":"product {} not found"}}"#, id)
-        ),
-    })
+fn get_product(repo: &ProductRepository, #[PathVariable] id: u64) -> Result<ProductView, ProductError> {
+    repo.find_by_id(id, |p| p.cloned())
+        .map(|product| ProductView { id, product })
+        .ok_or(ProductError::NotFound(id))
 }
 
 /// POST /products  body: {"name":"…","price":9.9,"stock":50}
+/// 名称为空、价格为负或库存超过 10000 时返回 400 与违规列表
 #[PostMapping("/products")]
-fn create_product(repo: &ProductRepository, req: &HttpRequest) -> HttpResponse {
-    let body = req.body_str();
-    match parse_product_json(body) {
-        Some(p) => {
-            let id = repo.save(p);
-            repo.find_by_id(id, |prod| {
-                HttpResponse::created().json(prod.unwrap().to_json(id))
-            })
-        }
-        None => HttpResponse::bad_request().json(
-            r#"{"error":"invalid JSON, expected {\"name\":\"…\",\"price\":9.9,\"stock\":50}"}"#
-        ),
-    }
+fn create_product(repo: &ProductRepository, #[Valid] #[RequestBody] product: Json<NewProduct>) -> HttpResponse {
+    let product = Product::from(product);
+    let id = repo.save(product.clone());
+    HttpResponse::created().json_of(&ProductView { id, product })
 }
 
 /// PUT /products/{id}  body: {"name":"…","price":9.9,"stock":50}
 #[PutMapping("/products/{id}")]
-fn update_product(repo: &ProductRepository, req: &HttpRequest) -> HttpResponse {
-    let id: u64 = req.path_param("id").unwrap_or("0").parse().unwrap_or(0);
-    match parse_product_json(req.body_str()) {
-        Some(p) => {
-            if repo.update(id, p) {
-                repo.find_by_id(id, |prod| {
-                    HttpResponse::ok().json(prod.unwrap().to_json(id))
-                })
-            } else {
-                HttpResponse::not_found().json(
-                    format!(r#"{{"error":"product {} not found"}}"#, id)
-                )
-            }
-        }
-        None => HttpResponse::bad_request().json(r#"{"error":"invalid JSON body"}"#),
+fn update_product(
+    repo: &ProductRepository,
+    #[PathVariable] id: u64,
+    #[Valid] #[RequestBody] product: Json<NewProduct>,
+) -> Result<ProductView, ProductError> {
+    let product = Product::from(product);
+    if repo.update(id, product.clone()) {
+        Ok(ProductView { id, product })
+    } else {
+        Err(ProductError::NotFound(id))
     }
 }
 
 /// DELETE /products/{id}
 #[DeleteMapping("/products/{id}")]
-fn delete_product(repo: &ProductRepository, req: &HttpRequest) -> HttpResponse {
-    let id: u64 = req.path_param("id").unwrap_or("0").parse().unwrap_or(0);
+fn delete_product(repo: &ProductRepository, #[PathVariable] id: u64) -> Result<HttpResponse, ProductError> {
     if repo.delete_by_id(id) {
-        HttpResponse::no_content()
+        Ok(HttpResponse::no_content())
     } else {
-        HttpResponse::not_found().json(
-            format!(r#"{{"error":"product {} not found"}}"#, id)
-        )
+        Err(ProductError::NotFound(id))
     }
 }
 
-// ── 简单 JSON 工具 ─────────────────────────────────────────────────────────────
-
-/// 从 JSON 字符串中提取指定 key 的值（字符串或数字）。
-/// 不依赖任何外部 crate，仅用于演示目的。
-fn json_field<'a>(json: &'a str, key: &str) -> Option<&'a str> {
-    let pattern = format!("\"{}\"", key);
-    let key_pos = json.find(pattern.as_str())?;
-    let after_key = json[key_pos + pattern.len()..].trim_start();
-    let after_colon = after_key.strip_prefix(':')?.trim_start();
-    if after_colon.starts_with('"') {
-        // 字符串值
-        let inner = &after_colon[1..];
-        let end = inner.find('"')?;
-        Some(&inner[..end])
-    } else {
-        // 数字 / bool / null
-        let end = after_colon
-            .find(|c: char| c == ',' || c == '}' || c == ' ' || c == '\n')
-            .unwrap_or(after_colon.len());
-        Some(after_colon[..end].trim())
-    }
+/// GET /export/products.csv — 每个商品一个 chunk，客户端边收边处理
+#[GetMapping("/export/products.csv")]
+fn export_products(repo: &ProductRepository, _req: &HttpRequest) -> HttpResponse {
+    let rows: Vec<String> = repo
+        .find_all_cloned()
+        .iter()
+        .map(|(id, p)| format!("{},{},{},{}\n", id, p.name, p.price, p.stock))
+        .collect();
+    HttpResponse::ok()
+        .header("Content-Type", "text/csv; charset=utf-8")
+        .chunks(std::iter::once("id,name,price,stock\n".to_string()).chain(rows))
+}
+
+/// GET /stats — `async fn` handler：std 服务器在工作线程上执行到完成，
+/// 开启 `async` feature 后由 AsyncHttpServer 在事件循环中 await
+#[GetMapping("/stats")]
+async fn product_stats(repo: &ProductRepository) -> Json<ProductStats> {
+    let all = repo.find_all_cloned();
+    Json(ProductStats {
+        products: all.len(),
+        stock:    all.iter().map(|(_, p)| u64::from(p.stock)).sum(),
+    })
+}
+
+#[derive(Debug, Serialize)]
+struct ProductStats {
+    products: usize,
+    stock:    u64,
+}
+
+// ── session 作用域的购物车 ──────────────────────────────────────────────────────
+//
+// 每个会话一个 ShoppingCart：第一次访问时创建会话并通过 Set-Cookie: SESSIONID=… 返回，
+// 之后带着同一个 cookie 的请求拿到同一个实例
+
+#[Component]
+#[Scope("session")]
+#[derive(Debug, Default)]
+struct ShoppingCart {
+    items: Mutex<Vec<u64>>,
+}
+
+/// POST /cart/{id}
+#[PostMapping("/cart/{id}")]
+fn add_to_cart(cart: &ShoppingCart, #[PathVariable] id: u64) -> HttpResponse {
+    let mut items = cart.items.lock().unwrap();
+    items.push(id);
+    HttpResponse::ok().json(format!(r#"{{"items":{}}}"#, items.len()))
 }
 
-fn parse_product_json(json: &str) -> Option<Product> {
-    let name  = json_field(json, "name")?;
-    let price: f64 = json_field(json, "price")?.parse().ok()?;
-    let stock: u32 = json_field(json, "stock")?.parse().ok()?;
-    Some(Product::new(name, price, stock))
+/// GET /cart
+#[GetMapping("/cart")]
+fn show_cart(cart: &ShoppingCart) -> Vec<u64> {
+    cart.items.lock().unwrap().clone()
 }
 
 // ── main ──────────────────────────────────────────────────────────────────────
@@ -173,8 +247,9 @@ fn main() {
     println!("=== spring-web demo ===");
     println!("Starting IoC container...");
 
-    // 1. 启动 IoC 容器（注册所有 #[Component] / #[Repository] bean）
-    let context = Application::run();
+    // 1. 启动 IoC 容器（注册所有 #[Component] / #[Repository] bean）；
+    //    工作线程共享同一个容器，因此使用线程安全的 run_concurrent()
+    let context = Application::run_concurrent();
 
     println!("\nSeeding initial products...");
     // 2. 手动向 repository 写入初始数据（通过 context.get_bean）
@@ -187,6 +262,73 @@ fn main() {
         }
     }
 
-    // 3. 启动 HTTP 服务（阻塞）
-    HttpServer::run(8080, context);
+    // 3. 启动 HTTP 服务：server.* 配置从 application.properties / 命令行读取
+    let properties = ServerProperties::from_environment(context.get_environment())
+        .unwrap_or_else(|e| panic!("invalid server properties: {}", e));
+    #[cfg(not(feature = "async"))]
+    let server = HttpServer::bind(properties, context).expect("failed to bind server port");
+    #[cfg(feature = "async")]
+    let server = AsyncHttpServer::bind(properties, context).expect("failed to bind server port");
+
+    // 4. 按回车停机（在另一个线程中等待输入）
+    let shutdown = server.shutdown_handle();
+    thread::spawn(move || {
+        let mut line = String::new();
+        let _ = std::io::stdin().read_line(&mut line);
+        shutdown.shutdown();
+    });
+
+    // 阻塞直到停机；返回时容器已经 close()
+    #[cfg(not(feature = "async"))]
+    server.serve();
+    #[cfg(feature = "async")]
+    server.serve_blocking();
+    println!("Bye.");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use spring_boot::web::Router;
+    use spring_boot::ConcurrentApplicationContext;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    /// 直接返回 `T: Serialize`，宏包装成 `Json<T>`
+    #[GetMapping("/test/point")]
+    fn point(_req: &HttpRequest) -> Point {
+        Point { x: 1, y: 2 }
+    }
+
+    /// `Result<T, E>` 的 `Ok` 同样被包装
+    #[GetMapping("/test/points/{id}")]
+    fn point_by_id(#[PathVariable] id: u64) -> Result<Point, ProductError> {
+        (id == 1).then_some(Point { x: 1, y: 2 }).ok_or(ProductError::NotFound(id))
+    }
+
+    fn get(path: &str) -> HttpResponse {
+        let mut context = ConcurrentApplicationContext::default();
+        context.refresh().unwrap();
+        let raw = format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path);
+        let mut req = HttpRequest::read_from(&mut Cursor::new(raw.into_bytes()), &mut Vec::new()).unwrap().unwrap();
+        Router::from_registry().dispatch(&mut req, &context)
+    }
+
+    #[test]
+    fn test_serializable_return_values_become_json() {
+        for path in ["/test/point", "/test/points/1"] {
+            let resp = get(path);
+            assert_eq!(resp.status, StatusCode::OK);
+            assert_eq!(resp.body, br#"{"x":1,"y":2}"#);
+            assert!(resp.headers.get("Content-Type").is_some_and(|v| v.starts_with("application/json")));
+        }
+        assert_eq!(get("/test/points/2").status, StatusCode::NOT_FOUND);
+    }
 }
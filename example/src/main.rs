@@ -1,4 +1,14 @@
-use spring_boot::{Application, ApplicationContext, AopMethods, Aspect, Bean, Before, After, Around, Component, ConditionalOnProperty, JoinPoint, Lazy, Repository, Scope, Value};
+// 演示用的 bean 字段只通过 Debug 输出，关闭 dead_code 提示
+#![allow(dead_code)]
+
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use spring_boot::{Application, ApplicationContext, ApplicationEvent, ApplicationEventPublisher, AopMethods, Aspect, Bean, Before, After, Around, Bindable, Component, ConfigurableApplicationContext, ConfigurationProperties, ConversionError, ConverterRegistration, DataSize, Validate, EventListener, JoinPoint, Lazy, LazyBean, ObjectProvider, PostConstruct, PreDestroy, Profile, Repository};
+use spring_boot::event::{ContextClosedEvent, ContextRefreshedEvent};
 
 // ── 基础 bean ──────────────────────────────────────────────────────────────────
 
@@ -20,14 +30,159 @@ struct User {
     name: String,
 }
 
+// ── Arc<T> 共享注入 ──────────────────────────────────────────────────────────
+// 字段类型为 Arc<T> 时注入的是容器中同一个实例，计数在所有注入方之间共享
+
+#[Component]
+#[derive(Debug, Default)]
+struct VisitCounter {
+    visits: AtomicU32,
+}
+
+#[Component]
+#[derive(Debug, Default)]
+struct HomePage {
+    #[autowired]
+    counter: Arc<VisitCounter>,
+}
+
+#[Component]
+#[derive(Debug, Default)]
+struct AboutPage {
+    #[autowired]
+    counter: Arc<VisitCounter>,
+}
+
+// ── Lazy<T> 打破循环依赖 ──────────────────────────────────────────────────────
+// Inventory → Arc<Checkout> → Lazy<Inventory>：Checkout 先拿到提前引用，
+// Inventory 创建完成后句柄才生效
+
+#[Component]
+#[derive(Debug, Default)]
+struct Checkout {
+    #[autowired]
+    inventory: Lazy<Inventory>,
+}
+
+#[Component]
+#[derive(Debug, Default)]
+struct Inventory {
+    #[autowired]
+    checkout: Arc<Checkout>,
+}
+
+// ── 生命周期回调 ──────────────────────────────────────────────────────────────
+// 注入完成后调用 #[PostConstruct]，context.close() 时按依赖倒序调用 #[PreDestroy]
+
+#[Component]
+#[derive(Debug, Default)]
+struct ConnectionPool {
+    size: u32,
+}
+
+impl ConnectionPool {
+    #[PostConstruct]
+    fn open(&mut self) {
+        self.size = 4;
+        println!("[Lifecycle]  connectionPool opened with {} connections", self.size);
+    }
+
+    #[PreDestroy]
+    fn shutdown(&self) {
+        println!("[Lifecycle]  connectionPool closed (after its users)");
+    }
+}
+
+#[Component]
+#[derive(Debug, Default)]
+struct ReportJob {
+    #[autowired]
+    pool: Arc<ConnectionPool>,
+}
+
+impl ReportJob {
+    #[PreDestroy]
+    fn stop(&self) {
+        println!("[Lifecycle]  reportJob stopped (pool still has {} connections)", self.pool.size);
+    }
+}
+
+// ── 应用事件 ──────────────────────────────────────────────────────────────────
+// #[EventListener] 按事件类型分发；#[EventListener(async)] 在线程池中执行
+
+#[derive(Debug)]
+struct OrderPlaced {
+    item: String,
+}
+
+impl ApplicationEvent for OrderPlaced {}
+
+#[Component]
+#[derive(Debug, Default)]
+struct AuditLog {
+    orders: AtomicU32,
+}
+
+#[EventListener]
+impl AuditLog {
+    #[EventListener]
+    fn on_refresh(&self, _event: &ContextRefreshedEvent) {
+        println!("[Event]      context refreshed, all singletons are ready");
+    }
+
+    #[EventListener]
+    fn on_order(&self, event: &OrderPlaced) {
+        let n = self.orders.fetch_add(1, Ordering::SeqCst) + 1;
+        println!("[Event]      order #{} placed: {}", n, event.item);
+    }
+
+    #[EventListener(async)]
+    fn notify_warehouse(&self, event: &OrderPlaced) {
+        let thread = std::thread::current();
+        println!("[Event]      warehouse notified about '{}' on thread {}", event.item, thread.name().unwrap_or("?"));
+    }
+
+    #[EventListener]
+    fn on_close(&self, _event: &ContextClosedEvent) {
+        println!("[Event]      context closing after {} orders", self.orders.load(Ordering::SeqCst));
+    }
+}
+
 // ── #[Scope("prototype")] ─────────────────────────────────────────────────────
-// 每次 do_create_bean 都创建新实例，不缓存到 singleton_objects
+// 不缓存到 singleton_objects：create_prototype 与 ObjectProvider 每次都创建新实例
+
+static NEXT_REQUEST_ID: AtomicUsize = AtomicUsize::new(1);
 
 #[Component]
 #[Scope("prototype")]
 #[derive(Debug, Default, Clone)]
 struct RequestContext {
-    request_id: i32,
+    request_id: usize,
+}
+
+impl RequestContext {
+    #[PostConstruct]
+    fn assign_id(&mut self) {
+        self.request_id = NEXT_REQUEST_ID.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+// singleton 持有 ObjectProvider<T>，需要时才向容器要一个新的 prototype 实例
+#[Component]
+#[derive(Debug, Default)]
+struct RequestDispatcher {
+    #[autowired]
+    request_context: ObjectProvider<RequestContext>,
+}
+
+// ── #[Scope("thread")] ────────────────────────────────────────────────────────
+// 每个线程一个实例，通过 get_bean_wrapper 获取；同一线程内多次获取拿到同一个实例
+
+#[Component]
+#[Scope("thread")]
+#[derive(Debug, Default)]
+struct TraceBuffer {
+    lines: std::sync::Mutex<Vec<String>>,
 }
 
 // ── #[Lazy] ───────────────────────────────────────────────────────────────────
@@ -40,6 +195,21 @@ struct HeavyService {
     initialized: bool,
 }
 
+// ── Option<T> / LazyBean<T> ───────────────────────────────────────────────────
+// Option：容器中没有该 bean（如条件未满足）时为 None；
+// LazyBean：注入时不创建，第一次 get() 时才创建 #[Lazy] bean
+
+#[Component]
+#[derive(Debug, Default)]
+struct Dashboard {
+    #[autowired]
+    cache: Option<Arc<CacheService>>,
+    #[autowired]
+    analytics: Option<Arc<AnalyticsService>>,
+    #[autowired]
+    heavy: LazyBean<HeavyService>,
+}
+
 // ── #[Bean] ───────────────────────────────────────────────────────────────────
 // 函数式定义 bean，类似 Java @Configuration + @Bean
 
@@ -57,6 +227,116 @@ fn create_app_config() -> AppConfig {
     }
 }
 
+// ── #[Autowired] 构造函数 / #[Bean] 参数注入 ───────────────────────────────────
+// 参数按类型（或 #[Qualifier]）从容器注入：字段不需要 Default，创建后不再修改
+
+#[Component]
+#[derive(Debug)]
+struct BannerPrinter {
+    version: String,
+    pool: Arc<ConnectionPool>,
+}
+
+#[Component]
+impl BannerPrinter {
+    #[Autowired]
+    fn new(config: &AppConfig, pool: Arc<ConnectionPool>) -> Self {
+        BannerPrinter { version: config.version.clone(), pool }
+    }
+}
+
+#[derive(Debug)]
+struct ConnectionBudget {
+    per_worker: u32,
+}
+
+#[Bean]
+fn connection_budget(#[Qualifier("appConfig")] config: &AppConfig, pool: Arc<ConnectionPool>) -> ConnectionBudget {
+    ConnectionBudget { per_worker: config.max_connections / pool.size }
+}
+
+// ── #[Primary] / #[Qualifier] ─────────────────────────────────────────────────
+// 同一类型有多个 bean 时按类型注入 #[Primary] 的那个，#[Qualifier] 按名称指定
+
+#[derive(Debug, Default)]
+struct MessageQueue {
+    url: &'static str,
+}
+
+#[Bean(name = "mainQueue")]
+#[Primary]
+fn main_queue() -> MessageQueue {
+    MessageQueue { url: "amqp://main:5672" }
+}
+
+#[Bean(name = "auditQueue")]
+fn audit_queue() -> MessageQueue {
+    MessageQueue { url: "amqp://audit:5672" }
+}
+
+#[Component]
+#[derive(Debug, Default)]
+struct Notifier {
+    #[autowired]
+    queue: Arc<MessageQueue>,
+    #[autowired]
+    #[Qualifier("auditQueue")]
+    audit: Arc<MessageQueue>,
+}
+
+// ── 集合注入 ─────────────────────────────────────────────────────────────────────
+// provides 声明 bean 实现的 trait，Vec / HashMap 注入所有实现，按 #[Order] 排序
+
+trait PaymentGateway: Send + Sync {
+    fn pay(&self, cents: u64) -> String;
+}
+
+#[Component(provides = "dyn PaymentGateway")]
+#[Order(2)]
+#[derive(Debug, Default)]
+struct PaypalGateway;
+
+impl PaymentGateway for PaypalGateway {
+    fn pay(&self, cents: u64) -> String {
+        format!("paypal:{}", cents)
+    }
+}
+
+#[Component(provides = "dyn PaymentGateway")]
+#[Order(1)]
+#[derive(Debug, Default)]
+struct StripeGateway;
+
+impl PaymentGateway for StripeGateway {
+    fn pay(&self, cents: u64) -> String {
+        format!("stripe:{}", cents)
+    }
+}
+
+struct VoucherGateway {
+    prefix: &'static str,
+}
+
+impl PaymentGateway for VoucherGateway {
+    fn pay(&self, cents: u64) -> String {
+        format!("{}:{}", self.prefix, cents)
+    }
+}
+
+#[Bean(name = "voucherGateway", provides = "dyn PaymentGateway")]
+fn voucher_gateway() -> VoucherGateway {
+    VoucherGateway { prefix: "voucher" }
+}
+
+#[Component]
+#[derive(Default)]
+struct PaymentRouter {
+    #[autowired]
+    gateways: Vec<Arc<dyn PaymentGateway>>,
+    #[autowired]
+    gateways_by_name: HashMap<String, Arc<dyn PaymentGateway>>,
+}
+
 // ── #[Value] 配置注入 ────────────────────────────────────────────────────────────────────
 // 字段从 application.properties 注入，相当于 Java @Value
 
@@ -71,6 +351,100 @@ struct ServerConfig {
     version: String,
     #[Value("${app.max-connections:100}")]
     max_connections: u32,
+    // 以下字段经 ConversionService 转换：bool 接受 on/off/yes/no，Endpoint 使用自定义转换器
+    #[Value("${app.maintenance:off}")]
+    maintenance: bool,
+    #[Value("${app.gateway:localhost:8080}")]
+    gateway: Endpoint,
+    // 占位符可以嵌入文本、相互嵌套，属性值中的占位符会递归解析
+    #[Value("${app.docs-url:${app.base-url}/docs}")]
+    docs_url: String,
+}
+
+// ── YAML 配置 ────────────────────────────────────────────────────────────────
+// application.yml 中的嵌套结构展开为 app.info.description、app.info.owners[0] 等 key；
+// 第二个文档只在 dev profile 激活时生效
+
+#[Component]
+#[derive(Debug, Default, Clone)]
+struct AppInfo {
+    #[Value("${app.info.description:}")]
+    description: String,
+    #[Value("${app.info.owners[0]:}")]
+    first_owner: String,
+}
+
+// ── #[ConfigurationProperties] 类型安全绑定 ────────────────────────────────────
+// app.datasource 下的属性一次性绑定到整个 struct：嵌套 struct、Vec、HashMap、Option、
+// Duration（30s）与 DataSize（10MB）。任何字段转换失败都会汇总后启动失败。
+// 同时 derive(Validate)，绑定后立即校验：例如 --app.datasource.pool.max-size=0 会导致启动失败
+
+#[ConfigurationProperties(prefix = "app.datasource")]
+#[derive(Debug, Default, Validate)]
+struct DataSourceProperties {
+    #[NotBlank]
+    #[Pattern("jdbc:[a-z0-9]+:.*")]
+    url: String,
+    username: Option<String>,
+    connect_timeout: Duration,
+    max_packet_size: DataSize,
+    #[Size(max = 4)]
+    replicas: Vec<String>,
+    #[Valid]
+    pool: PoolProperties,
+    options: HashMap<String, String>,
+    isolation: IsolationLevel,
+}
+
+#[derive(Debug, Default, Bindable, Validate)]
+struct PoolProperties {
+    #[Min(1)]
+    #[Max(64)]
+    max_size: u32,
+    idle_timeout: Duration,
+}
+
+// ── 类型转换 ──────────────────────────────────────────────────────────────────
+// 实现了 FromStr 的类型（如枚举）可以直接用于 #[Value] 与配置绑定；
+// 没有 FromStr 的类型通过 ConverterRegistration 向共享的 ConversionService 注册转换器
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum IsolationLevel {
+    #[default]
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl FromStr for IsolationLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "read-committed" => Ok(IsolationLevel::ReadCommitted),
+            "repeatable-read" => Ok(IsolationLevel::RepeatableRead),
+            "serializable" => Ok(IsolationLevel::Serializable),
+            other => Err(format!("unknown isolation level '{}'", other)),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Endpoint {
+    host: String,
+    port: u16,
+}
+
+inventory::submit! {
+    ConverterRegistration {
+        register: |service| {
+            service.add_converter(|s: &str| {
+                let (host, port) = s.trim().rsplit_once(':').ok_or_else(|| ConversionError::new("expected <host>:<port>"))?;
+                let port = port.parse().map_err(|e| ConversionError::new(format!("invalid port: {}", e)))?;
+                Ok(Endpoint { host: host.to_string(), port })
+            })
+        },
+    }
 }
 
 // ── SpEL 表达式注入 ───────────────────────────────────────────────────────────────────────
@@ -131,6 +505,26 @@ struct CacheService {
 #[ConditionalOnProperty("feature.analytics.enabled", having = "true")]
 #[derive(Debug, Default, Clone)]
 struct AnalyticsService;
+
+// ── #[Profile] 演示 ─────────────────────────────────────────────────────────
+// application.properties 中 spring.profiles.active=dev，
+// 同时叠加 application-dev.properties（cache.ttl=30）
+#[Profile("dev")]
+#[Component]
+#[derive(Debug, Default)]
+struct ConsoleMailSender;
+
+#[Component]
+#[Profile("!dev")]
+#[derive(Debug, Default)]
+struct SmtpMailSender;
+
+#[Bean(name = "dataSourceUrl")]
+#[Profile("dev", "test")]
+fn embedded_data_source_url() -> String {
+    "h2:mem:devdb".to_string()
+}
+
 // ── Spring Data 风格 Repository 演示 ────────────────────────────────
 // Product: 普通 Rust 结构体，即实体类型
 #[derive(Debug, Clone)]
@@ -180,41 +574,115 @@ fn main() {
     let mut context = Application::run();
 
     // 1. 普通 singleton bean
-    if let Some(bean) = context.get_bean("person") {
-        if let Some(person) = bean.downcast_ref::<Person>() {
-            println!("[Singleton]  person bean: {:?}", person);
-        }
+    if let Some(person) = context.get_bean_typed::<Person>("person") {
+        println!("[Singleton]  person bean: {:?}", person);
     }
 
     // 2. autowired 注入
-    if let Some(bean) = context.get_bean("user") {
-        if let Some(user) = bean.downcast_ref::<User>() {
-            println!("[Autowired]  user bean:   {:?}", user);
+    if let Some(user) = context.get_bean_typed::<User>("user") {
+        println!("[Autowired]  user bean:   {:?}", user);
+    }
+
+    // 2b. Arc<T> 共享注入 —— 两个页面持有同一个计数器
+    if let (Some(home), Some(about)) = (context.get_bean("homePage"), context.get_bean("aboutPage")) {
+        if let (Some(home), Some(about)) = (home.downcast_ref::<HomePage>(), about.downcast_ref::<AboutPage>()) {
+            home.counter.visits.fetch_add(1, Ordering::SeqCst);
+            about.counter.visits.fetch_add(1, Ordering::SeqCst);
+            println!(
+                "[Shared]     visitCounter seen by homePage: {}, by aboutPage: {}",
+                home.counter.visits.load(Ordering::SeqCst),
+                about.counter.visits.load(Ordering::SeqCst)
+            );
         }
     }
 
-    // 3. Prototype bean — 每次 do_create_bean 产生新实例
-    context.do_create_bean("requestContext");
-    println!("[Prototype]  requestContext: prototype bean (not cached in singleton store)");
+    // 2c. Lazy<T> 循环依赖
+    if let Some(inventory) = context.get_bean_typed::<Inventory>("inventory") {
+        let back = inventory.checkout.inventory.get();
+        println!(
+            "[Cycle]      inventory -> checkout -> Lazy<inventory> resolved: {}",
+            back.is_some_and(|i| std::ptr::eq(Arc::as_ptr(&i), inventory))
+        );
+    }
+
+    // 2d. 发布自定义事件
+    context.publish(OrderPlaced { item: "keyboard".to_string() });
+
+    // 3. Prototype bean — create_prototype 交出新实例的所有权，ObjectProvider 每次获取都新建
+    let owned = context.create_prototype("requestContext").expect("failed to create requestContext");
+    if let Ok(owned) = owned.downcast::<RequestContext>() {
+        println!("[Prototype]  requestContext created: {:?}", owned);
+    }
+    if let Some(dispatcher) = context.get_bean_typed::<RequestDispatcher>("requestDispatcher") {
+        let first = dispatcher.request_context.get();
+        let second = dispatcher.request_context.get();
+        println!(
+            "[Prototype]  requestDispatcher got request ids {} and {} from ObjectProvider",
+            first.request_id, second.request_id
+        );
+    }
+
+    // 3b. Thread scope — 同一线程共享一个实例，不进入 singleton 缓存
+    let first = context.get_bean_wrapper("traceBuffer").expect("failed to get traceBuffer");
+    let second = context.get_bean_wrapper("traceBuffer").expect("failed to get traceBuffer");
+    if let (Some(first), Some(second)) = (first.downcast_arc::<TraceBuffer>(), second.downcast_arc::<TraceBuffer>()) {
+        first.lines.lock().unwrap().push("order placed".to_string());
+        println!(
+            "[Thread]     traceBuffer shared within thread: {}, lines: {:?}",
+            Arc::ptr_eq(&first, &second),
+            second.lines.lock().unwrap()
+        );
+    }
 
-    // 4. Lazy singleton — refresh() 时跳过，首次 get_bean 时触发创建
+    // 4. Lazy singleton — refresh() 时跳过，注入到 LazyBean 中也不会创建，首次访问时才触发创建
     if context.get_bean("heavyService").is_none() {
         println!(
             "[Lazy]       heavyService: not yet initialized (lazy=true, needs do_create_bean)"
         );
-        context.do_create_bean("heavyService");
     }
+    let mut heavy = None;
+    if let Some(dashboard) = context.get_bean_typed::<Dashboard>("dashboard") {
+        println!(
+            "[Optional]   dashboard.cache -> {:?}, dashboard.analytics -> {:?}",
+            dashboard.cache.as_ref().map(|cache| cache.ttl),
+            dashboard.analytics
+        );
+        println!("[LazyBean]   dashboard.heavy before first access: {:?}", dashboard.heavy);
+        heavy = Some(dashboard.heavy.get());
+    }
+    context.do_create_bean("heavyService").expect("failed to create heavyService");
     if let Some(bean) = context.get_bean("heavyService") {
         if let Some(svc) = bean.downcast_ref::<HeavyService>() {
-            println!("[Lazy]       heavyService initialized: {:?}", svc);
+            let shared = heavy.is_some_and(|heavy| std::ptr::eq(svc, heavy.as_ref()));
+            println!("[Lazy]       heavyService initialized: {:?} (same instance as dashboard.heavy: {})", svc, shared);
         }
     }
 
-    // 5. @Bean 函数式定义
-    if let Some(bean) = context.get_bean("appConfig") {
-        if let Some(cfg) = bean.downcast_ref::<AppConfig>() {
-            println!("[Bean]       appConfig: {:?}", cfg);
-        }
+    // 5. @Bean 函数式定义（按类型查找，无需知道 bean 名称）
+    match context.get_bean_of_type::<AppConfig>() {
+        Ok(cfg) => println!("[Bean]       appConfig: {:?}", cfg),
+        Err(e) => println!("[Bean]       {}", e),
+    }
+    if let Some(banner) = context.get_bean_typed::<BannerPrinter>("bannerPrinter") {
+        println!(
+            "[Autowired]  bannerPrinter built by constructor: version {}, pool of {} connections",
+            banner.version, banner.pool.size
+        );
+    }
+    if let Ok(budget) = context.get_bean_of_type::<ConnectionBudget>() {
+        println!("[Bean]       connectionBudget from appConfig and connectionPool: {:?}", budget);
+    }
+    if let Some(notifier) = context.get_bean_typed::<Notifier>("notifier") {
+        println!(
+            "[Primary]    notifier.queue -> {} (primary), notifier.audit -> {} (qualifier)",
+            notifier.queue.url, notifier.audit.url
+        );
+    }
+    if let Some(router) = context.get_bean_typed::<PaymentRouter>("paymentRouter") {
+        let payments: Vec<String> = router.gateways.iter().map(|gateway| gateway.pay(100)).collect();
+        let mut names: Vec<&String> = router.gateways_by_name.keys().collect();
+        names.sort();
+        println!("[Collection] paymentRouter.gateways -> {:?}, by name: {:?}", payments, names);
     }
 
     // 6. #[Value] 配置注入
@@ -224,7 +692,17 @@ fn main() {
         }
     }
 
-    // 6b. #[Value("#{...}")] SpEL 表达式注入
+    // 6a. YAML 配置（多文档，按 profile 生效）
+    if let Some(info) = context.get_bean_typed::<AppInfo>("appInfo") {
+        println!("[YAML]       appInfo: {:?}", info);
+    }
+
+    // 6b. #[ConfigurationProperties] 绑定
+    if let Some(props) = context.get_bean_typed::<DataSourceProperties>("dataSourceProperties") {
+        println!("[Binding]    dataSourceProperties: {:?}", props);
+    }
+
+    // 6c. #[Value("#{...}")] SpEL 表达式注入
     if let Some(bean) = context.get_bean("spelConfig") {
         if let Some(cfg) = bean.downcast_ref::<SpelConfig>() {
             println!("[SpEL]       double_port (port*2 if >8000): {}", cfg.double_port);
@@ -257,6 +735,13 @@ fn main() {
         None => println!("  analyticsService NOT registered (feature.analytics.enabled not set) ✓"),
         Some(_) => println!("  analyticsService registered (unexpected)"),
     }
+    // 8b. #[Profile] 演示
+    println!("\n[Profile]");
+    println!("  consoleMailSender registered: {}", context.contains_bean("consoleMailSender"));
+    println!("  smtpMailSender registered:    {}", context.contains_bean("smtpMailSender"));
+    if let Some(url) = context.get_bean_typed::<String>("dataSourceUrl") {
+        println!("  dataSourceUrl (dev/test only): {}", url);
+    }
     // 9. Spring Data 风格 Repository CRUD 演示
     println!("\n[Repository]");
     if let Some(bean) = context.get_bean("productRepository") {
@@ -293,4 +778,8 @@ fn main() {
             // exists
             println!("  exists id={}: {}", id3, repo.exists_by_id(id3));
         }
-    }}
+    }
+    // 10. 关闭容器：按依赖倒序执行销毁回调
+    println!();
+    context.close();
+}
@@ -1,6 +1,14 @@
-pub trait BeanPostProcessor {
-    fn post_process_before_initialization(&self, bean_name: &str, bean: &mut dyn std::any::Any) -> ();
-    fn post_process_after_initialization(&self, bean_name: &str, bean: &mut dyn std::any::Any) -> () ;
+use crate::bean::lazy::EarlyReference;
+
+pub trait BeanPostProcessor: Send + Sync {
+    fn post_process_before_initialization(&self, bean_name: &str, bean: &mut dyn std::any::Any);
+    fn post_process_after_initialization(&self, bean_name: &str, bean: &mut dyn std::any::Any);
+    /// 正在创建的 singleton 被 `Lazy<T>` 提前引用时调用（由三级缓存的 singleton factory 触发），
+    /// 对标 Spring 的 `SmartInstantiationAwareBeanPostProcessor#getEarlyBeanReference`：
+    /// AOP 代理可以在这里换成自己的引用。默认原样返回
+    fn get_early_bean_reference(&self, _bean_name: &str, early: EarlyReference) -> EarlyReference {
+        early
+    }
     fn order(&self) -> i32 {
         0
     }
@@ -10,14 +18,12 @@ pub struct DefaultBeanPostProcessor{
 }
 
 impl BeanPostProcessor for DefaultBeanPostProcessor{
-    fn post_process_before_initialization(&self, bean_name: &str, _bean: &mut dyn std::any::Any) -> () {
+    fn post_process_before_initialization(&self, bean_name: &str, _bean: &mut dyn std::any::Any) {
         println!("DefaultBeanPostProcessor: Before Initialization of bean '{}'", bean_name);
-        ()
     }
 
-    fn post_process_after_initialization(&self, bean_name: &str, _bean: &mut dyn std::any::Any) -> () {
+    fn post_process_after_initialization(&self, bean_name: &str, _bean: &mut dyn std::any::Any) {
         println!("DefaultBeanPostProcessor: After Initialization of bean '{}'", bean_name);
-        ()
     }
 }
 
@@ -1,7 +1,11 @@
+use std::sync::Arc;
 use crate::bean::bean_post_processor::BeanPostProcessor;
+use crate::bean::lazy::EarlyReference;
 
+/// 克隆只复制 `Arc`：`ObjectProvider<T>` 在容器之外创建 prototype 时共用同一组后置处理器
+#[derive(Clone)]
 pub struct BeanPostProcessorRegistry {
-    processors: Vec<Box<dyn BeanPostProcessor>>,
+    processors: Vec<Arc<dyn BeanPostProcessor>>,
 }
 
 impl BeanPostProcessorRegistry {
@@ -12,11 +16,11 @@ impl BeanPostProcessorRegistry {
     }
 
     pub fn register(&mut self, processor: Box<dyn BeanPostProcessor>) {
-        self.processors.push(processor);
+        self.processors.push(Arc::from(processor));
         self.processors.sort_by_key(|p| p.order());
     }
 
-    pub fn get_processors(&self) -> &Vec<Box<dyn BeanPostProcessor>> {
+    pub fn get_processors(&self) -> &Vec<Arc<dyn BeanPostProcessor>> {
         &self.processors
     }
 
@@ -31,6 +35,12 @@ impl BeanPostProcessorRegistry {
         }
     }
 
+    pub fn apply_early_bean_reference(&self, bean_name: &str, early: EarlyReference) -> EarlyReference {
+        self.processors
+            .iter()
+            .fold(early, |early, processor| processor.get_early_bean_reference(bean_name, early))
+    }
+
     pub fn len(&self) -> usize {
         self.processors.len()
     }
@@ -40,3 +50,9 @@ impl BeanPostProcessorRegistry {
     }
 
 }
+
+impl Default for BeanPostProcessorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,246 @@
+use std::any::Any;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// A singleton held by the container behind a shared pointer.
+///
+/// Every consumer that autowires the bean as `Arc<T>` / `Rc<T>` receives a
+/// clone of the *same* pointer, so state changes are visible to everyone.
+///
+/// - Beans whose type is `Send + Sync` are stored as `Arc<dyn Any + Send + Sync>`
+///   and can be injected as `Arc<T>`.
+/// - All other beans are stored as `Rc<dyn Any>` and can be injected as `Rc<T>`.
+///
+/// The variant is chosen at compile time by the code `#[Component]` / `#[Bean]`
+/// generate (see [`WrapProbe`]).
+#[derive(Clone)]
+pub enum BeanWrapper {
+    Shared(Arc<dyn Any + Send + Sync>),
+    Local(Rc<dyn Any>),
+}
+
+impl BeanWrapper {
+    /// Wrap an instance whose thread-safety is unknown into an `Rc`.
+    pub fn local(instance: Box<dyn Any>) -> Self {
+        BeanWrapper::Local(Rc::from(instance))
+    }
+
+    /// Borrow the wrapped instance as `&dyn Any`.
+    pub fn as_any(&self) -> &dyn Any {
+        match self {
+            BeanWrapper::Shared(arc) => arc.as_ref(),
+            BeanWrapper::Local(rc) => rc.as_ref(),
+        }
+    }
+
+    /// Mutable access while the container still holds the only pointer
+    /// (used to run `BeanPostProcessor`s on a freshly created instance).
+    pub fn get_mut(&mut self) -> Option<&mut dyn Any> {
+        match self {
+            BeanWrapper::Shared(arc) => Arc::get_mut(arc).map(|b| b as &mut dyn Any),
+            BeanWrapper::Local(rc) => Rc::get_mut(rc),
+        }
+    }
+
+    /// Returns `true` if the bean is stored as `Arc<dyn Any + Send + Sync>`.
+    pub fn is_shared(&self) -> bool {
+        matches!(self, BeanWrapper::Shared(_))
+    }
+
+    /// Clone the `Arc<T>` pointing at the bean, or `None` if the bean is not a
+    /// thread-safe `T`.
+    pub fn downcast_arc<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        match self {
+            BeanWrapper::Shared(arc) => arc.clone().downcast::<T>().ok(),
+            BeanWrapper::Local(_) => None,
+        }
+    }
+
+    /// Clone the `Rc<T>` pointing at the bean, or `None` if the bean is not a
+    /// `T` stored behind an `Rc`.
+    pub fn downcast_rc<T: Any>(&self) -> Option<Rc<T>> {
+        match self {
+            BeanWrapper::Local(rc) => rc.clone().downcast::<T>().ok(),
+            BeanWrapper::Shared(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Debug for BeanWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BeanWrapper::Shared(_) => f.write_str("BeanWrapper::Shared(..)"),
+            BeanWrapper::Local(_) => f.write_str("BeanWrapper::Local(..)"),
+        }
+    }
+}
+
+/// Converts the `Box<dyn Any>` produced by a bean supplier into a [`BeanWrapper`].
+pub type WrapFn = fn(Box<dyn Any>) -> BeanWrapper;
+
+// ── compile-time Send + Sync detection ─────────────────────────────────────────
+//
+// 宏生成代码中写：
+//
+//   use spring_beans::bean::bean_wrapper::{WrapLocal as _, WrapShared as _};
+//   (&WrapProbe::<T>::new()).wrap_fn()
+//
+// 若 T: Send + Sync，方法解析在第一步命中 `WrapShared`（Arc）；
+// 否则自动引用后命中 `WrapLocal`（Rc）。
+
+/// Zero-sized probe used by generated code to pick the wrapper for `T`.
+pub struct WrapProbe<T>(PhantomData<T>);
+
+impl<T> WrapProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        WrapProbe(PhantomData)
+    }
+}
+
+pub trait WrapShared {
+    fn wrap_fn(&self) -> WrapFn;
+}
+
+impl<T: Any + Send + Sync> WrapShared for WrapProbe<T> {
+    fn wrap_fn(&self) -> WrapFn {
+        |instance| match instance.downcast::<T>() {
+            Ok(typed) => BeanWrapper::Shared(Arc::<T>::from(typed)),
+            Err(other) => BeanWrapper::local(other),
+        }
+    }
+}
+
+pub trait WrapLocal {
+    fn wrap_fn(&self) -> WrapFn;
+}
+
+impl<T: Any> WrapLocal for &WrapProbe<T> {
+    fn wrap_fn(&self) -> WrapFn {
+        BeanWrapper::local
+    }
+}
+
+/// Converts a bean into a trait-object pointer (`Arc<dyn X>` / `Rc<dyn X>`) boxed as `Box<dyn Any>`;
+/// `None` if the bean is not the expected type. Generated for `provides = "dyn X"`.
+pub type ViewFn = Arc<dyn Fn(&BeanWrapper) -> Option<Box<dyn Any>> + Send + Sync>;
+
+// ── compile-time trait-object views ─────────────────────────────────────────────
+//
+// `provides = "dyn X"` 生成：
+//
+//   use spring_beans::bean::bean_wrapper::{ViewLocal as _, ViewShared as _};
+//   (&ViewProbe::<T>::new()).view_fn::<dyn X>(|bean| bean, |bean| bean)
+//
+// 两个闭包负责 `Arc<T>` → `Arc<dyn X>` / `Rc<T>` → `Rc<dyn X>` 的 unsizing 转换；
+// 与 `WrapProbe` 相同，`Send + Sync` 的 T 按 `Arc` 取出，其余按 `Rc` 取出。
+
+/// Zero-sized probe used by generated code to build the [`ViewFn`] of `T`.
+pub struct ViewProbe<T>(PhantomData<T>);
+
+impl<T> ViewProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        ViewProbe(PhantomData)
+    }
+}
+
+pub trait ViewShared<T> {
+    fn view_fn<V: ?Sized + 'static>(&self, shared: fn(Arc<T>) -> Arc<V>, local: fn(Rc<T>) -> Rc<V>) -> ViewFn;
+}
+
+impl<T: Any + Send + Sync> ViewShared<T> for ViewProbe<T> {
+    fn view_fn<V: ?Sized + 'static>(&self, shared: fn(Arc<T>) -> Arc<V>, _local: fn(Rc<T>) -> Rc<V>) -> ViewFn {
+        Arc::new(move |bean: &BeanWrapper| bean.downcast_arc::<T>().map(|typed| Box::new(shared(typed)) as Box<dyn Any>))
+    }
+}
+
+pub trait ViewLocal<T> {
+    fn view_fn<V: ?Sized + 'static>(&self, shared: fn(Arc<T>) -> Arc<V>, local: fn(Rc<T>) -> Rc<V>) -> ViewFn;
+}
+
+impl<T: Any> ViewLocal<T> for &ViewProbe<T> {
+    fn view_fn<V: ?Sized + 'static>(&self, _shared: fn(Arc<T>) -> Arc<V>, local: fn(Rc<T>) -> Rc<V>) -> ViewFn {
+        Arc::new(move |bean: &BeanWrapper| bean.downcast_rc::<T>().map(|typed| Box::new(local(typed)) as Box<dyn Any>))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    struct Counter(AtomicU32);
+
+    struct LocalCounter(Cell<u32>);
+
+    // 与宏生成代码相同的调用形式
+    macro_rules! wrap_fn_of {
+        ($t:ty) => {
+            (&WrapProbe::<$t>::new()).wrap_fn()
+        };
+    }
+
+    #[test]
+    fn test_send_sync_bean_is_shared_as_arc() {
+        let wrap = wrap_fn_of!(Counter);
+        let bean = wrap(Box::new(Counter(AtomicU32::new(0))));
+        assert!(bean.is_shared());
+
+        let a = bean.downcast_arc::<Counter>().unwrap();
+        let b = bean.downcast_arc::<Counter>().unwrap();
+        a.0.fetch_add(1, Ordering::SeqCst);
+        assert_eq!(b.0.load(Ordering::SeqCst), 1);
+        assert!(bean.downcast_rc::<Counter>().is_none());
+    }
+
+    #[test]
+    fn test_local_bean_is_shared_as_rc() {
+        let wrap = wrap_fn_of!(LocalCounter);
+        let bean = wrap(Box::new(LocalCounter(Cell::new(0))));
+        assert!(!bean.is_shared());
+
+        let a = bean.downcast_rc::<LocalCounter>().unwrap();
+        a.0.set(7);
+        assert_eq!(bean.downcast_rc::<LocalCounter>().unwrap().0.get(), 7);
+        assert!(bean.as_any().downcast_ref::<LocalCounter>().is_some());
+    }
+
+    trait Count {
+        fn count(&self) -> u32;
+    }
+
+    impl Count for Counter {
+        fn count(&self) -> u32 {
+            self.0.load(Ordering::SeqCst)
+        }
+    }
+
+    impl Count for LocalCounter {
+        fn count(&self) -> u32 {
+            self.0.get()
+        }
+    }
+
+    macro_rules! view_fn_of {
+        ($t:ty) => {{
+            #[allow(unused_imports)]
+            use super::{ViewLocal as _, ViewShared as _};
+            (&ViewProbe::<$t>::new()).view_fn::<dyn Count>(|bean| bean, |bean| bean)
+        }};
+    }
+
+    #[test]
+    fn test_trait_object_views() {
+        let shared = wrap_fn_of!(Counter)(Box::new(Counter(AtomicU32::new(3))));
+        let view = view_fn_of!(Counter)(&shared).unwrap();
+        assert_eq!(view.downcast::<Arc<dyn Count>>().unwrap().count(), 3);
+
+        let local = wrap_fn_of!(LocalCounter)(Box::new(LocalCounter(Cell::new(5))));
+        let view = view_fn_of!(LocalCounter)(&local).unwrap();
+        assert_eq!(view.downcast::<Rc<dyn Count>>().unwrap().count(), 5);
+        assert!(view_fn_of!(Counter)(&local).is_none());
+    }
+}
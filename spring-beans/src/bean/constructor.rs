@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use crate::error::BeansError;
+use crate::factory::config::{DependencyDescriptor, ResolvedDependencies};
+
+/// 构造函数参数引用的 bean，按注入方式分组（与 `#[autowired]` 字段相同）
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConstructorDependencies {
+    pub dependencies: Vec<String>,
+    /// `Lazy<T>` 参数
+    pub lazy_dependencies: Vec<String>,
+    /// `ObjectProvider<T>` / `LazyBean<T>` 参数
+    pub provider_dependencies: Vec<String>,
+    /// `Option<..>` 参数
+    pub optional_dependencies: Vec<String>,
+    /// `Vec<..>` / `HashMap<String, ..>` 参数
+    pub collection_dependencies: Vec<String>,
+    /// 未标注 `#[Qualifier]` 的参数按类型注入
+    pub descriptors: Vec<DependencyDescriptor>,
+}
+
+/// `#[Component] impl` 块中的 `#[Autowired] fn new(..) -> Self`，由宏实现。
+///
+/// 有构造函数的 bean 不需要实现 `Default`：容器先解析参数引用的 bean，再调用构造函数创建实例，
+/// 之后照常注入 `#[autowired]` 字段与 `#[Value]`。
+pub trait AutowiredConstructor: Sized {
+    fn constructor_dependencies() -> ConstructorDependencies;
+
+    /// `bean_name` 用于错误信息
+    fn construct(
+        bean_name: &str,
+        resolved_deps: &ResolvedDependencies,
+        env: &HashMap<String, String>,
+    ) -> Result<Self, BeansError>;
+}
+
+// ── compile-time constructor detection ──────────────────────────────────────────
+//
+// #[Component] 生成的代码中写：
+//
+//   use spring_beans::bean::constructor::{ConstructDefault as _, ConstructAutowired as _};
+//   (&ConstructorProbe::<T>::new()).construct(name, deps, env)
+//
+// 与 `WrapProbe` 相同：T 有 `#[Autowired]` 构造函数时在第一步命中 `ConstructAutowired`，
+// 否则自动引用后命中 `ConstructDefault`（要求 `T: Default`）。
+
+/// Zero-sized probe used by generated code to pick how `T` is instantiated.
+pub struct ConstructorProbe<T>(PhantomData<T>);
+
+impl<T> ConstructorProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        ConstructorProbe(PhantomData)
+    }
+}
+
+pub trait ConstructAutowired<T> {
+    fn constructor_dependencies(&self) -> ConstructorDependencies;
+    fn construct(&self, bean_name: &str, resolved_deps: &ResolvedDependencies, env: &HashMap<String, String>) -> Result<T, BeansError>;
+}
+
+impl<T: AutowiredConstructor> ConstructAutowired<T> for ConstructorProbe<T> {
+    fn constructor_dependencies(&self) -> ConstructorDependencies {
+        T::constructor_dependencies()
+    }
+
+    fn construct(&self, bean_name: &str, resolved_deps: &ResolvedDependencies, env: &HashMap<String, String>) -> Result<T, BeansError> {
+        T::construct(bean_name, resolved_deps, env)
+    }
+}
+
+pub trait ConstructDefault<T> {
+    fn constructor_dependencies(&self) -> ConstructorDependencies;
+    fn construct(&self, bean_name: &str, resolved_deps: &ResolvedDependencies, env: &HashMap<String, String>) -> Result<T, BeansError>;
+}
+
+impl<T: Default> ConstructDefault<T> for &ConstructorProbe<T> {
+    fn constructor_dependencies(&self) -> ConstructorDependencies {
+        ConstructorDependencies::default()
+    }
+
+    fn construct(&self, _bean_name: &str, _resolved_deps: &ResolvedDependencies, _env: &HashMap<String, String>) -> Result<T, BeansError> {
+        Ok(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Plain(u32);
+
+    struct Wired(u32);
+
+    impl AutowiredConstructor for Wired {
+        fn constructor_dependencies() -> ConstructorDependencies {
+            ConstructorDependencies { dependencies: vec!["repo".to_string()], ..Default::default() }
+        }
+
+        fn construct(_: &str, _: &ResolvedDependencies, _: &HashMap<String, String>) -> Result<Self, BeansError> {
+            Ok(Wired(7))
+        }
+    }
+
+    // 与宏生成代码相同的调用形式
+    macro_rules! construct {
+        ($t:ty) => {{
+            #[allow(unused_imports)]
+            use super::{ConstructAutowired as _, ConstructDefault as _};
+            let probe = ConstructorProbe::<$t>::new();
+            let deps = (&probe).constructor_dependencies();
+            ((&probe).construct("bean", &ResolvedDependencies::new(), &HashMap::new()).unwrap(), deps)
+        }};
+    }
+
+    #[test]
+    fn test_autowired_constructor_takes_precedence() {
+        let (wired, deps) = construct!(Wired);
+        assert_eq!(wired.0, 7);
+        assert_eq!(deps.dependencies, ["repo"]);
+
+        let (plain, deps) = construct!(Plain);
+        assert_eq!(plain.0, 0);
+        assert_eq!(deps, ConstructorDependencies::default());
+    }
+}
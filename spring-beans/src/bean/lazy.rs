@@ -0,0 +1,151 @@
+use std::any::Any;
+use std::marker::PhantomData;
+use std::sync::{Arc, OnceLock, Weak};
+use super::bean_wrapper::BeanWrapper;
+use super::provider::{ObjectProvider, ObjectSupplier};
+use crate::error::BeansError;
+
+/// A reference to a singleton that may still be in creation.
+///
+/// The container hands one out when a bean asks for a [`Lazy`] dependency, and
+/// fills it in as soon as the target singleton has been fully created. This is
+/// what lets `A -> B -> Lazy<A>` cycles resolve.
+#[derive(Clone, Default)]
+pub struct EarlyReference {
+    slot: Arc<OnceLock<Weak<dyn Any + Send + Sync>>>,
+}
+
+impl EarlyReference {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An early reference to a bean that already exists.
+    pub fn resolved(bean: &BeanWrapper) -> Self {
+        let early = Self::new();
+        early.resolve(bean);
+        early
+    }
+
+    /// Point the reference at the finished bean. Only thread-safe (`Arc`) beans
+    /// can be reached through a `Lazy<T>`; returns `false` for any other bean.
+    pub fn resolve(&self, bean: &BeanWrapper) -> bool {
+        match bean {
+            BeanWrapper::Shared(arc) => {
+                let _ = self.slot.set(Arc::downgrade(arc));
+                true
+            }
+            BeanWrapper::Local(_) => false,
+        }
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.slot.get().is_some()
+    }
+
+    pub(crate) fn upgrade(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.slot.get()?.upgrade()
+    }
+}
+
+impl std::fmt::Debug for EarlyReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EarlyReference")
+            .field("resolved", &self.is_resolved())
+            .finish()
+    }
+}
+
+/// `#[autowired] Lazy<T>`：延迟解析的单例引用，用于打破循环依赖。
+///
+/// 注入时目标 bean 可能还在创建中，因此只能在注入完成之后通过 [`Lazy::get`]
+/// 取得 `Arc<T>`。句柄内部只持有弱引用，不会与目标 bean 形成引用环；
+/// 容器关闭后 `get` 返回 `None`。目标类型必须是 `Send + Sync` 的 singleton。
+pub struct Lazy<T> {
+    early: EarlyReference,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Any + Send + Sync> Lazy<T> {
+    pub fn from_early(early: EarlyReference) -> Self {
+        Lazy { early, _marker: PhantomData }
+    }
+
+    /// 目标 bean 已创建完成且仍在容器中时返回它的共享指针
+    pub fn get(&self) -> Option<Arc<T>> {
+        self.early.upgrade()?.downcast::<T>().ok()
+    }
+}
+
+impl<T> Clone for Lazy<T> {
+    fn clone(&self) -> Self {
+        Lazy { early: self.early.clone(), _marker: PhantomData }
+    }
+}
+
+/// 未绑定任何 bean 的句柄（`#[derive(Default)]` 的 bean 需要）
+impl<T> Default for Lazy<T> {
+    fn default() -> Self {
+        Lazy { early: EarlyReference::new(), _marker: PhantomData }
+    }
+}
+
+impl<T> std::fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Lazy<{}>({:?})", std::any::type_name::<T>(), self.early)
+    }
+}
+
+/// `#[autowired] LazyBean<T>`：第一次访问时才解析的依赖，对标 Spring 注入点上的 `@Lazy`。
+///
+/// 注入时不会创建目标，因此 `#[Lazy]` singleton 注入到非 lazy 的 bean 中仍保持延迟：
+/// 第一次调用 [`LazyBean::get`] 时才创建并交给容器管理，之后返回同一个实例
+/// （prototype 目标同样只在第一次访问时创建一个）。目标类型必须是 `Send + Sync`。
+pub struct LazyBean<T> {
+    provider: ObjectProvider<T>,
+    instance: Arc<OnceLock<Arc<T>>>,
+}
+
+impl<T: Any + Send + Sync> LazyBean<T> {
+    pub fn new(supplier: ObjectSupplier) -> Self {
+        LazyBean { provider: ObjectProvider::new(supplier), instance: Arc::default() }
+    }
+
+    /// 解析（必要时创建）目标 bean；失败时下次访问会重试
+    pub fn try_get(&self) -> Result<Arc<T>, BeansError> {
+        if let Some(instance) = self.instance.get() {
+            return Ok(instance.clone());
+        }
+        let instance = self.provider.get_object()?;
+        Ok(self.instance.get_or_init(|| instance).clone())
+    }
+
+    /// 同 [`LazyBean::try_get`]；解析失败时 panic
+    pub fn get(&self) -> Arc<T> {
+        self.try_get().unwrap_or_else(|e| panic!("{}", e.full_message()))
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.instance.get().is_some()
+    }
+}
+
+/// 克隆共享同一个解析结果
+impl<T> Clone for LazyBean<T> {
+    fn clone(&self) -> Self {
+        LazyBean { provider: self.provider.clone(), instance: Arc::clone(&self.instance) }
+    }
+}
+
+/// 未绑定任何 bean 的句柄（`#[derive(Default)]` 的 bean 需要）
+impl<T> Default for LazyBean<T> {
+    fn default() -> Self {
+        LazyBean { provider: ObjectProvider::default(), instance: Arc::default() }
+    }
+}
+
+impl<T> std::fmt::Debug for LazyBean<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LazyBean<{}>(resolved: {})", std::any::type_name::<T>(), self.instance.get().is_some())
+    }
+}
@@ -0,0 +1,112 @@
+use std::any::Any;
+use std::error::Error;
+use std::marker::PhantomData;
+
+/// 生命周期回调的返回值：初始化 / 销毁方法可以返回任意错误
+pub type LifecycleResult = Result<(), Box<dyn Error + Send + Sync>>;
+
+/// 注入完成后调用的初始化回调（类型擦除后由 BeanDefinition 持有）
+pub type InitFn = fn(&mut dyn Any) -> LifecycleResult;
+
+/// 容器关闭时调用的销毁回调
+pub type DestroyFn = fn(&dyn Any) -> LifecycleResult;
+
+/// 对标 Java 的 `InitializingBean`：所有依赖注入完成后由容器调用。
+pub trait InitializingBean {
+    fn after_properties_set(&mut self) -> LifecycleResult;
+}
+
+/// 对标 Java 的 `DisposableBean`：容器关闭时调用，先于其依赖的 bean 销毁。
+pub trait DisposableBean {
+    fn destroy(&self) -> LifecycleResult;
+}
+
+/// `#[PostConstruct]` / `#[PreDestroy]` / `init_method` 标注的方法可以返回 `()` 或 `Result<(), E>`
+pub trait IntoLifecycleResult {
+    fn into_lifecycle_result(self) -> LifecycleResult;
+}
+
+impl IntoLifecycleResult for () {
+    fn into_lifecycle_result(self) -> LifecycleResult {
+        Ok(())
+    }
+}
+
+impl<E: Into<Box<dyn Error + Send + Sync>>> IntoLifecycleResult for Result<(), E> {
+    fn into_lifecycle_result(self) -> LifecycleResult {
+        self.map_err(Into::into)
+    }
+}
+
+// ── 宏生成代码使用的探测 ─────────────────────────────────────────────────────────
+//
+// `#[PostConstruct]` / `#[PreDestroy]` 在类型上生成固有方法
+// `__spring_post_construct` / `__spring_pre_destroy`；没有标注时，
+// 方法解析落到下面对所有类型的空实现（固有方法优先于 trait 方法）。
+//
+// `InitializingBean` / `DisposableBean` 与 `WrapProbe` 相同，通过自动引用选择实现：
+//
+//   (&LifecycleProbe::<T>::new()).after_properties_set(bean)
+
+#[doc(hidden)]
+pub trait LifecycleFallback {
+    fn __spring_post_construct(&mut self) -> LifecycleResult {
+        Ok(())
+    }
+
+    fn __spring_pre_destroy(&self) -> LifecycleResult {
+        Ok(())
+    }
+}
+
+impl<T: ?Sized> LifecycleFallback for T {}
+
+#[doc(hidden)]
+pub struct LifecycleProbe<T>(PhantomData<T>);
+
+impl<T> LifecycleProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        LifecycleProbe(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaInitializingBean<T> {
+    fn after_properties_set(&self, bean: &mut T) -> LifecycleResult;
+}
+
+impl<T: InitializingBean> ViaInitializingBean<T> for LifecycleProbe<T> {
+    fn after_properties_set(&self, bean: &mut T) -> LifecycleResult {
+        bean.after_properties_set()
+    }
+}
+
+#[doc(hidden)]
+pub trait NoInitializingBean<T> {
+    fn after_properties_set(&self, _bean: &mut T) -> LifecycleResult {
+        Ok(())
+    }
+}
+
+impl<T> NoInitializingBean<T> for &LifecycleProbe<T> {}
+
+#[doc(hidden)]
+pub trait ViaDisposableBean<T> {
+    fn destroy(&self, bean: &T) -> LifecycleResult;
+}
+
+impl<T: DisposableBean> ViaDisposableBean<T> for LifecycleProbe<T> {
+    fn destroy(&self, bean: &T) -> LifecycleResult {
+        bean.destroy()
+    }
+}
+
+#[doc(hidden)]
+pub trait NoDisposableBean<T> {
+    fn destroy(&self, _bean: &T) -> LifecycleResult {
+        Ok(())
+    }
+}
+
+impl<T> NoDisposableBean<T> for &LifecycleProbe<T> {}
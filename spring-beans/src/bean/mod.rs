@@ -1,6 +1,15 @@
 pub mod bean_post_processor;
 pub mod bean_post_processor_register;
 pub mod bean_wrapper;
+pub mod constructor;
+pub mod lazy;
+pub mod lifecycle;
+pub mod provider;
 
 pub use bean_post_processor::{BeanPostProcessor, DefaultBeanPostProcessor};
 pub use bean_post_processor_register::BeanPostProcessorRegistry;
+pub use bean_wrapper::BeanWrapper;
+pub use constructor::{AutowiredConstructor, ConstructorDependencies};
+pub use lazy::{EarlyReference, Lazy, LazyBean};
+pub use lifecycle::{DisposableBean, InitializingBean};
+pub use provider::{ObjectProvider, ObjectSupplier, Provider};
@@ -0,0 +1,105 @@
+use std::any::Any;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use super::bean_wrapper::BeanWrapper;
+use crate::error::{BeansError, NoSuchBeanError};
+
+/// 在容器之外获取某个 bean 的函数：prototype 每次调用都新建实例，
+/// 自定义作用域的 bean 交给 `Scope`，singleton 返回容器中的实例。
+#[derive(Clone)]
+pub struct ObjectSupplier {
+    name: String,
+    supplier: Arc<dyn Fn() -> Result<BeanWrapper, BeansError> + Send + Sync>,
+}
+
+impl ObjectSupplier {
+    pub fn new(
+        name: impl Into<String>,
+        supplier: impl Fn() -> Result<BeanWrapper, BeansError> + Send + Sync + 'static,
+    ) -> Self {
+        Self { name: name.into(), supplier: Arc::new(supplier) }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get(&self) -> Result<BeanWrapper, BeansError> {
+        (self.supplier)()
+    }
+}
+
+impl std::fmt::Debug for ObjectSupplier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObjectSupplier").field("name", &self.name).finish()
+    }
+}
+
+/// `#[autowired] ObjectProvider<T>`：按需获取 bean，对标 Spring 的 `ObjectProvider` / JSR-330 `Provider`。
+///
+/// 注入 prototype 时，每次调用 [`ObjectProvider::get_object`] 都会得到一个经过完整创建流程
+/// （依赖注入、`BeanPostProcessor`、初始化回调）的新实例；注入 request / session 等作用域的 bean 时，
+/// 每次调用都从当前作用域中取出。目标类型必须是 `Send + Sync`。
+pub struct ObjectProvider<T> {
+    supplier: Option<ObjectSupplier>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+/// JSR-330 风格的别名
+pub type Provider<T> = ObjectProvider<T>;
+
+impl<T: Any + Send + Sync> ObjectProvider<T> {
+    pub fn new(supplier: ObjectSupplier) -> Self {
+        ObjectProvider { supplier: Some(supplier), _marker: PhantomData }
+    }
+
+    /// 获取（prototype 为新建）目标 bean
+    pub fn get_object(&self) -> Result<Arc<T>, BeansError> {
+        let supplier = self.supplier()?;
+        supplier.get()?.downcast_arc::<T>().ok_or_else(|| BeansError::BeanCreation {
+            bean_name: supplier.name().to_string(),
+            message: format!("bean is not a Send + Sync instance of '{}'", std::any::type_name::<T>()),
+            cause: None,
+        })
+    }
+
+    /// 同 [`ObjectProvider::get_object`]，获取失败时返回 `None`
+    pub fn get_if_available(&self) -> Option<Arc<T>> {
+        self.get_object().ok()
+    }
+
+    /// JSR-330 `Provider::get`；获取失败时 panic
+    pub fn get(&self) -> Arc<T> {
+        self.get_object().unwrap_or_else(|e| panic!("{}", e.full_message()))
+    }
+
+    /// 不做类型转换，直接返回容器创建的实例
+    pub fn get_bean_wrapper(&self) -> Result<BeanWrapper, BeansError> {
+        self.supplier()?.get()
+    }
+
+    fn supplier(&self) -> Result<&ObjectSupplier, BeansError> {
+        self.supplier.as_ref().ok_or_else(|| {
+            NoSuchBeanError::NoBeanOfType { type_name: std::any::type_name::<T>().to_string() }.into()
+        })
+    }
+}
+
+impl<T> Clone for ObjectProvider<T> {
+    fn clone(&self) -> Self {
+        ObjectProvider { supplier: self.supplier.clone(), _marker: PhantomData }
+    }
+}
+
+/// 未绑定任何 bean 的句柄（`#[derive(Default)]` 的 bean 需要）
+impl<T> Default for ObjectProvider<T> {
+    fn default() -> Self {
+        ObjectProvider { supplier: None, _marker: PhantomData }
+    }
+}
+
+impl<T> std::fmt::Debug for ObjectProvider<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ObjectProvider<{}>({:?})", std::any::type_name::<T>(), self.supplier.as_ref().map(ObjectSupplier::name))
+    }
+}
@@ -0,0 +1,182 @@
+use std::any::{type_name, Any, TypeId};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+
+use crate::env::bind::{parse_duration, DataSize};
+
+use super::converter::{ConversionError, Converter, ConverterRegistration};
+
+/// 类型转换服务，对标 Spring 的 `ConversionService`
+pub trait ConversionService {
+    fn can_convert<S: ?Sized + 'static, T: 'static>(&self) -> bool;
+
+    fn convert<S: ?Sized + 'static, T: 'static>(&self, source: &S) -> Result<T, ConversionError>;
+}
+
+/// 注册表中保存的转换函数；第二个参数是服务本身，便于列表转换器委托给元素转换器
+type ConverterFn<S, T> = Arc<dyn Fn(&S, &GenericConversionService) -> Result<T, ConversionError> + Send + Sync>;
+
+/// 以 `(TypeId::of::<S>(), TypeId::of::<T>())` 为 key 的转换器注册表，对标 Spring 的 `GenericConversionService`。
+///
+/// 注册 `str → T` 时会同时注册 `str → Vec<T>`（逗号分隔，逐个委托给 `str → T`），
+/// 因此自定义类型也能以列表形式注入。后注册的转换器覆盖先注册的。
+#[derive(Default)]
+pub struct GenericConversionService {
+    converters: HashMap<(TypeId, TypeId), Box<dyn Any + Send + Sync>>,
+}
+
+impl GenericConversionService {
+    /// 空的转换服务，不含任何内置转换器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 含全部内置转换器（见 [`GenericConversionService::add_default_converters`]）
+    pub fn with_default_converters() -> Self {
+        let mut service = Self::new();
+        service.add_default_converters();
+        service
+    }
+
+    /// 全局共享实例：内置转换器 + 通过 inventory 提交的 [`ConverterRegistration`]。
+    /// `#[Value]`、`#[ConfigurationProperties]` 与 web 参数绑定都使用它。
+    pub fn get_shared_instance() -> &'static GenericConversionService {
+        static SHARED: OnceLock<GenericConversionService> = OnceLock::new();
+        SHARED.get_or_init(|| {
+            let mut service = Self::with_default_converters();
+            for registration in inventory::iter::<ConverterRegistration> {
+                (registration.register)(&mut service);
+            }
+            service
+        })
+    }
+
+    pub fn add_converter<S, T>(&mut self, converter: impl Converter<S, T> + 'static)
+    where
+        S: ?Sized + 'static,
+        T: 'static,
+    {
+        let converter: ConverterFn<S, T> = Arc::new(move |source: &S, _: &GenericConversionService| converter.convert(source));
+        self.converters.insert(key::<S, T>(), Box::new(converter));
+        if TypeId::of::<S>() == TypeId::of::<str>() {
+            let list: ConverterFn<str, Vec<T>> = Arc::new(|source: &str, service: &GenericConversionService| {
+                source
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| service.convert::<str, T>(item))
+                    .collect()
+            });
+            self.converters.entry(key::<str, Vec<T>>()).or_insert_with(|| Box::new(list));
+        }
+    }
+
+    /// 内置转换器（源类型均为 `str`，同时提供对应的 `Vec<T>`）：
+    /// - `bool`：`true/false`、`yes/no`、`on/off`、`1/0`，不区分大小写
+    /// - 整数、浮点数、`char`、`String`、`PathBuf`（两端空白会被忽略）
+    /// - `Duration`：`500ms`、`30s`、`5m`、`2h`、`1d`，纯数字为毫秒
+    /// - `DataSize`：`512B`、`10KB`、`10MB`、`1GB`，纯数字为字节
+    ///
+    /// 其余实现了 `FromStr` 的类型（如枚举）在注入时自动回退到 `FromStr`。
+    pub fn add_default_converters(&mut self) {
+        self.add_converter(|s: &str| Ok(s.to_string()));
+        self.add_converter(|s: &str| Ok(PathBuf::from(s.trim())));
+        self.add_converter(|s: &str| match s.trim().to_ascii_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => Ok(true),
+            "false" | "no" | "off" | "0" => Ok(false),
+            other => Err(ConversionError::new(format!("invalid boolean value '{}'", other))),
+        });
+        self.add_converter(|s: &str| parse_duration(s).map_err(ConversionError::new));
+        self.add_converter(|s: &str| s.parse::<DataSize>().map_err(ConversionError::new));
+        macro_rules! from_str_converters {
+            ($($ty:ty),*) => {
+                $( self.add_converter(|s: &str| s.trim().parse::<$ty>().map_err(ConversionError::new)); )*
+            };
+        }
+        from_str_converters!(char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    }
+
+    /// 有注册的 `str → T` 转换器时使用它，否则回退到 `T::from_str`
+    pub fn convert_or_parse<T>(&self, source: &str) -> Result<T, ConversionError>
+    where
+        T: FromStr + 'static,
+        T::Err: Display,
+    {
+        if self.can_convert::<str, T>() {
+            self.convert(source)
+        } else {
+            source.trim().parse().map_err(ConversionError::new)
+        }
+    }
+}
+
+impl ConversionService for GenericConversionService {
+    fn can_convert<S: ?Sized + 'static, T: 'static>(&self) -> bool {
+        self.converters.contains_key(&key::<S, T>())
+    }
+
+    fn convert<S: ?Sized + 'static, T: 'static>(&self, source: &S) -> Result<T, ConversionError> {
+        let converter = self
+            .converters
+            .get(&key::<S, T>())
+            .and_then(|entry| entry.downcast_ref::<ConverterFn<S, T>>())
+            .ok_or_else(|| ConversionError::converter_not_found(type_name::<S>(), type_name::<T>()))?;
+        converter(source, self)
+    }
+}
+
+impl std::fmt::Debug for GenericConversionService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GenericConversionService")
+            .field("converters", &self.converters.len())
+            .finish()
+    }
+}
+
+fn key<S: ?Sized + 'static, T: 'static>() -> (TypeId, TypeId) {
+    (TypeId::of::<S>(), TypeId::of::<T>())
+}
+
+// `#[Value]` 生成的代码用它把字符串转换为字段类型：实现了 `FromStr` 的类型可以回退到
+// `from_str`，其余类型只能使用共享转换服务中注册的转换器。与 `WrapProbe` 相同，通过自动引用选择实现：
+//   (&ConversionProbe::<T>::new()).convert_str(raw)
+
+#[doc(hidden)]
+pub struct ConversionProbe<T>(PhantomData<T>);
+
+impl<T> ConversionProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        ConversionProbe(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaFromStr<T> {
+    fn convert_str(&self, source: &str) -> Result<T, ConversionError>;
+}
+
+impl<T> ViaFromStr<T> for ConversionProbe<T>
+where
+    T: FromStr + 'static,
+    T::Err: Display,
+{
+    fn convert_str(&self, source: &str) -> Result<T, ConversionError> {
+        GenericConversionService::get_shared_instance().convert_or_parse(source)
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaConversionService<T> {
+    fn convert_str(&self, source: &str) -> Result<T, ConversionError>;
+}
+
+impl<T: 'static> ViaConversionService<T> for &ConversionProbe<T> {
+    fn convert_str(&self, source: &str) -> Result<T, ConversionError> {
+        GenericConversionService::get_shared_instance().convert(source)
+    }
+}
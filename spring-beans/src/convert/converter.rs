@@ -0,0 +1,71 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use super::conversion_service::GenericConversionService;
+
+/// 把 `S` 转换为 `T`，对标 Spring 的 `Converter<S, T>`。
+///
+/// 闭包 `Fn(&S) -> Result<T, ConversionError>` 自动实现该 trait：
+///
+/// ```rust,ignore
+/// service.add_converter(|s: &str| s.parse::<Money>().map_err(ConversionError::new));
+/// ```
+pub trait Converter<S: ?Sized, T>: Send + Sync {
+    fn convert(&self, source: &S) -> Result<T, ConversionError>;
+}
+
+impl<S: ?Sized, T, F> Converter<S, T> for F
+where
+    F: Fn(&S) -> Result<T, ConversionError> + Send + Sync,
+{
+    fn convert(&self, source: &S) -> Result<T, ConversionError> {
+        self(source)
+    }
+}
+
+/// 转换失败或找不到转换器
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionError {
+    reason: String,
+}
+
+impl ConversionError {
+    pub fn new(reason: impl Display) -> Self {
+        Self { reason: reason.to_string() }
+    }
+
+    /// 没有注册 `source_type` → `target_type` 的转换器
+    pub fn converter_not_found(source_type: &str, target_type: &str) -> Self {
+        Self::new(format!(
+            "no converter found capable of converting from type '{}' to type '{}'",
+            source_type, target_type
+        ))
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.reason)
+    }
+}
+
+impl Error for ConversionError {}
+
+/// 向共享的 [`GenericConversionService`] 注册自定义转换器，启动时通过 inventory 收集：
+///
+/// ```rust,ignore
+/// inventory::submit! {
+///     ConverterRegistration {
+///         register: |service| service.add_converter(|s: &str| Money::parse(s).map_err(ConversionError::new)),
+///     }
+/// }
+/// ```
+pub struct ConverterRegistration {
+    pub register: fn(&mut GenericConversionService),
+}
+
+inventory::collect!(ConverterRegistration);
@@ -0,0 +1,74 @@
+//! 类型转换：`Converter<S, T>` 与按 `TypeId` 对注册的 `GenericConversionService`。
+
+pub mod conversion_service;
+pub mod converter;
+
+pub use conversion_service::{
+    ConversionProbe, ConversionService, GenericConversionService, ViaConversionService, ViaFromStr,
+};
+pub use converter::{ConversionError, Converter, ConverterRegistration};
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Money(u64);
+
+    #[derive(Debug, PartialEq)]
+    enum Level {
+        Low,
+        High,
+    }
+
+    impl std::str::FromStr for Level {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "low" => Ok(Level::Low),
+                "high" => Ok(Level::High),
+                _ => Err(format!("unknown level '{}'", s)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_and_custom_converters() {
+        let mut service = GenericConversionService::with_default_converters();
+        assert_eq!(service.convert::<str, bool>("Yes"), Ok(true));
+        assert_eq!(service.convert::<str, bool>("off"), Ok(false));
+        assert!(service.convert::<str, bool>("maybe").is_err());
+        assert_eq!(service.convert::<str, Duration>("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(service.convert::<str, Vec<u16>>("1, 2,3"), Ok(vec![1, 2, 3]));
+
+        assert!(!service.can_convert::<str, Money>());
+        let missing = service.convert::<str, Money>("$5").unwrap_err();
+        assert!(missing.reason().starts_with("no converter found"));
+        service.add_converter(|s: &str| {
+            s.strip_prefix('$')
+                .and_then(|n| n.parse().ok())
+                .map(Money)
+                .ok_or_else(|| ConversionError::new("expected $<amount>"))
+        });
+        assert_eq!(service.convert::<str, Money>("$5"), Ok(Money(5)));
+        assert_eq!(service.convert::<str, Vec<Money>>("$1,$2"), Ok(vec![Money(1), Money(2)]));
+        service.add_converter(|n: &u64| Ok(Money(*n)));
+        assert_eq!(service.convert::<u64, Money>(&7), Ok(Money(7)));
+    }
+
+    #[test]
+    #[allow(clippy::needless_borrow)]
+    fn test_from_str_fallback() {
+        let service = GenericConversionService::with_default_converters();
+        assert_eq!(service.convert_or_parse::<Level>("high"), Ok(Level::High));
+        assert_eq!(service.convert_or_parse::<bool>("on"), Ok(true));
+
+        #[allow(unused_imports)]
+        use super::{ViaConversionService as _, ViaFromStr as _};
+        assert_eq!((&ConversionProbe::<Level>::new()).convert_str("low"), Ok(Level::Low));
+        assert_eq!((&ConversionProbe::<Vec<i32>>::new()).convert_str("4,5"), Ok(vec![4, 5]));
+    }
+}
@@ -0,0 +1,61 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// 单个属性绑定失败：哪个 key、什么值、来自哪个 property source、要转换成什么类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindError {
+    pub name: String,
+    pub value: String,
+    /// property source 名称，如 `"application.properties"`、`"commandLineArgs"`
+    pub origin: Option<String>,
+    pub target_type: &'static str,
+    pub reason: String,
+}
+
+impl Display for BindError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "property '{}' with value '{}'", self.name, self.value)?;
+        if let Some(origin) = &self.origin {
+            write!(f, " (from {})", origin)?;
+        }
+        write!(f, " could not be converted to '{}': {}", self.target_type, self.reason)
+    }
+}
+
+impl Error for BindError {}
+
+/// 一次绑定中收集到的全部失败，不会在第一个错误处停下
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BindErrors {
+    errors: Vec<BindError>,
+}
+
+impl BindErrors {
+    pub fn new(errors: Vec<BindError>) -> Self {
+        Self { errors }
+    }
+
+    pub fn errors(&self) -> &[BindError] {
+        &self.errors
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+}
+
+impl Display for BindErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} binding failure(s):", self.errors.len())?;
+        for error in &self.errors {
+            write!(f, "\n    - {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for BindErrors {}
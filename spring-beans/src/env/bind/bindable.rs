@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::convert::ConversionService;
+
+use super::binder::Binder;
+use super::data_size::DataSize;
+
+/// 可以从 `Environment` 中按属性名绑定出来的类型。
+///
+/// - 标量（`String`、数字、`bool`、`Duration`、`DataSize` ...）读取单个属性并转换；
+/// - `Option<T>`、`Vec<T>`、`HashMap<String, T>` 递归绑定元素；
+/// - 结构体由 `#[derive(Bindable)]` / `#[ConfigurationProperties]` 生成实现。
+///
+/// 返回 `None` 表示没有任何可绑定的属性（或转换失败，错误已记录在 `binder` 中），字段保持默认值。
+pub trait Bind: Sized {
+    /// 能否由单个字符串转换而来，决定逗号分隔列表与 map key 的拆分方式
+    const SCALAR: bool = false;
+
+    fn bind(binder: &mut Binder<'_>, name: &str) -> Option<Self>;
+
+    /// 把一个字符串值转换为 `Self`，只有标量类型需要实现；`name` 用于报错
+    fn convert(_binder: &mut Binder<'_>, _name: &str, _value: &str) -> Option<Self> {
+        None
+    }
+}
+
+/// 由 `#[derive(Bindable)]` 生成：把 `prefix` 下的属性逐个绑定到已有实例的字段上。
+/// 返回是否绑定了至少一个字段。
+pub trait Bindable: Default {
+    fn bind_properties(&mut self, binder: &mut Binder<'_>, prefix: &str) -> bool;
+}
+
+// 标量统一交给 binder 的 ConversionService 转换（`bool` 因此也接受 yes/on 等写法）
+macro_rules! scalar_bind {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Bind for $ty {
+                const SCALAR: bool = true;
+
+                fn bind(binder: &mut Binder<'_>, name: &str) -> Option<Self> {
+                    let value = binder.get_property(name)?;
+                    Self::convert(binder, name, &value)
+                }
+
+                fn convert(binder: &mut Binder<'_>, name: &str, value: &str) -> Option<Self> {
+                    let service = binder.conversion_service();
+                    binder.convert(name, value, |value| service.convert::<str, $ty>(value).map_err(|e| e.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+scalar_bind! {
+    String, PathBuf, Duration, DataSize, bool, char,
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+    f32, f64,
+}
+
+impl<T: Bind> Bind for Option<T> {
+    fn bind(binder: &mut Binder<'_>, name: &str) -> Option<Self> {
+        T::bind(binder, name).map(Some)
+    }
+}
+
+/// `name[0]`、`name[1]` ... 形式的下标属性优先；标量元素也可以写成逗号分隔的 `name=a,b,c`。
+/// 两种写法同时存在时，来自优先级更高的 property source 的那一种生效。
+impl<T: Bind> Bind for Vec<T> {
+    fn bind(binder: &mut Binder<'_>, name: &str) -> Option<Self> {
+        let first = format!("{}[0]", name);
+        let indexed = binder.has_descendants(&first);
+        let joined = T::SCALAR && binder.contains_property(name);
+        if indexed && (!joined || binder.takes_precedence(&first, name)) {
+            let mut values = Vec::new();
+            let mut index = 0;
+            loop {
+                let element = format!("{}[{}]", name, index);
+                if !binder.has_descendants(&element) {
+                    break;
+                }
+                if let Some(value) = T::bind(binder, &element) {
+                    values.push(value);
+                }
+                index += 1;
+            }
+            return Some(values);
+        }
+        if joined {
+            let value = binder.get_property(name)?;
+            let values = value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .filter_map(|item| T::convert(binder, name, item))
+                .collect();
+            return Some(values);
+        }
+        None
+    }
+}
+
+/// `name.key=value`。标量值的 key 是 `name.` 之后的完整路径（可以包含 `.`），
+/// 结构体值的 key 是下一段，其余部分绑定到结构体字段上。
+impl<T: Bind> Bind for HashMap<String, T> {
+    fn bind(binder: &mut Binder<'_>, name: &str) -> Option<Self> {
+        let keys = binder.child_keys(name, !T::SCALAR);
+        if keys.is_empty() {
+            return None;
+        }
+        let mut map = HashMap::new();
+        for key in keys {
+            if let Some(value) = T::bind(binder, &format!("{}.{}", name, key)) {
+                map.insert(key, value);
+            }
+        }
+        Some(map)
+    }
+}
+
+// `#[derive(Bindable)]` 生成的字段绑定代码通过自动引用选择实现：
+//   (&BindProbe::<T>::new()).bind_field(binder, &name)
+// 1. `T: Bind` —— 内置标量、集合与嵌套的 Bindable 结构体；
+// 2. `T: FromStr` —— 例如枚举，优先使用已注册的转换器，否则回退到 `from_str`；
+// 3. 其余类型 —— 只能通过 ConversionService 中注册的 `str → T` 转换器。
+
+#[doc(hidden)]
+pub struct BindProbe<T>(PhantomData<T>);
+
+impl<T> BindProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        BindProbe(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaBind<T> {
+    fn bind_field(&self, binder: &mut Binder<'_>, name: &str) -> Option<T>;
+}
+
+impl<T: Bind> ViaBind<T> for BindProbe<T> {
+    fn bind_field(&self, binder: &mut Binder<'_>, name: &str) -> Option<T> {
+        T::bind(binder, name)
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaFromStrBind<T> {
+    fn bind_field(&self, binder: &mut Binder<'_>, name: &str) -> Option<T>;
+}
+
+impl<T> ViaFromStrBind<T> for &BindProbe<T>
+where
+    T: FromStr + 'static,
+    T::Err: Display,
+{
+    fn bind_field(&self, binder: &mut Binder<'_>, name: &str) -> Option<T> {
+        let value = binder.get_property(name)?;
+        let service = binder.conversion_service();
+        binder.convert(name, &value, |value| service.convert_or_parse::<T>(value).map_err(|e| e.to_string()))
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaConversionBind<T> {
+    fn bind_field(&self, binder: &mut Binder<'_>, name: &str) -> Option<T>;
+}
+
+impl<T: 'static> ViaConversionBind<T> for &&BindProbe<T> {
+    fn bind_field(&self, binder: &mut Binder<'_>, name: &str) -> Option<T> {
+        let value = binder.get_property(name)?;
+        let service = binder.conversion_service();
+        binder.convert(name, &value, |value| service.convert::<str, T>(value).map_err(|e| e.to_string()))
+    }
+}
@@ -0,0 +1,204 @@
+use std::any::Any;
+
+use crate::convert::GenericConversionService;
+use crate::env::{Environment, PropertyResolver};
+use crate::error::BeansError;
+
+use super::bind_error::{BindError, BindErrors};
+use super::bindable::{Bind, Bindable};
+
+/// `#[ConfigurationProperties]` / `#[Value]` 生成的绑定回调：实例创建之后、初始化回调之前由容器调用
+pub type BindFn = fn(&mut dyn Any, &Environment) -> Result<(), BeansError>;
+
+/// 把 `Environment` 中某个前缀下的属性绑定到 Rust 类型上，对标 Spring Boot 的 `Binder`。
+///
+/// 转换失败不会立即返回，而是记录下来继续绑定其余字段，最后一并以 [`BindErrors`] 报告。
+///
+/// ```rust,ignore
+/// let pool: Option<PoolProperties> = Binder::new(&env).bind("app.datasource.pool")?;
+/// ```
+pub struct Binder<'a> {
+    environment: &'a Environment,
+    /// 标量值的转换器，默认使用共享实例
+    conversion_service: &'a GenericConversionService,
+    /// 所有已知属性名（排序），用于查找列表下标与 map key
+    names: Vec<String>,
+    errors: Vec<BindError>,
+}
+
+impl<'a> Binder<'a> {
+    pub fn new(environment: &'a Environment) -> Self {
+        Self::with_conversion_service(environment, GenericConversionService::get_shared_instance())
+    }
+
+    pub fn with_conversion_service(environment: &'a Environment, conversion_service: &'a GenericConversionService) -> Self {
+        let mut names: Vec<String> = environment.as_map().into_keys().collect();
+        names.sort();
+        Self { environment, conversion_service, names, errors: Vec::new() }
+    }
+
+    pub fn conversion_service(&self) -> &'a GenericConversionService {
+        self.conversion_service
+    }
+
+    /// 绑定 `name` 处的值；没有任何相关属性时返回 `Ok(None)`
+    pub fn bind<T: Bind>(&mut self, name: &str) -> Result<Option<T>, BindErrors> {
+        let value = T::bind(self, name);
+        self.finish().map(|_| value)
+    }
+
+    /// 把 `prefix` 下的属性绑定到已有实例上，未出现的字段保持原值
+    pub fn bind_into<T: Bindable>(&mut self, prefix: &str, target: &mut T) -> Result<(), BindErrors> {
+        target.bind_properties(self, prefix);
+        self.finish()
+    }
+
+    fn finish(&mut self) -> Result<(), BindErrors> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(BindErrors::new(std::mem::take(&mut self.errors)))
+        }
+    }
+
+    /// 属性值，其中的 `${...}` 已解析；解析失败时记录错误并返回 `None`
+    pub fn get_property(&mut self, name: &str) -> Option<String> {
+        match self.environment.get_resolved_property(name) {
+            Ok(value) => value,
+            Err(err) => {
+                self.errors.push(BindError {
+                    name: name.to_string(),
+                    value: self.environment.get_property(name).unwrap_or_default().to_string(),
+                    origin: self.environment.get_property_origin(name).map(str::to_string),
+                    target_type: "String",
+                    reason: err.to_string(),
+                });
+                None
+            }
+        }
+    }
+
+    pub fn contains_property(&self, name: &str) -> bool {
+        self.environment.contains_property(name)
+    }
+
+    /// `name` 本身或以 `name.` / `name[` 开头的属性是否存在
+    pub fn has_descendants(&self, name: &str) -> bool {
+        self.environment.contains_property(name) || self.names.iter().any(|n| is_descendant(n, name))
+    }
+
+    /// `name.` 之下的 key：`first_segment` 为 true 时只取下一段（到 `.` / `[` 为止），否则取完整剩余路径
+    pub fn child_keys(&self, name: &str, first_segment: bool) -> Vec<String> {
+        let prefix = format!("{}.", name);
+        let mut keys: Vec<String> = Vec::new();
+        for rest in self.names.iter().filter_map(|n| n.strip_prefix(&prefix)) {
+            let key = match rest.find(['.', '[']) {
+                Some(end) if first_segment => &rest[..end],
+                _ => rest,
+            };
+            if !key.is_empty() && !keys.iter().any(|k| k == key) {
+                keys.push(key.to_string());
+            }
+        }
+        keys
+    }
+
+    /// 字段对应的属性名。依次尝试 kebab-case（规范形式）、原字段名、camelCase，
+    /// 都不存在时返回 kebab-case
+    pub fn field_name(&self, prefix: &str, field: &str) -> String {
+        let field = field.trim_start_matches("r#");
+        let kebab = field.replace('_', "-");
+        let camel = to_camel_case(field);
+        let join = |segment: &str| {
+            if prefix.is_empty() {
+                segment.to_string()
+            } else {
+                format!("{}.{}", prefix, segment)
+            }
+        };
+        let canonical = join(&kebab);
+        if self.has_descendants(&canonical) {
+            return canonical;
+        }
+        for candidate in [join(field), join(&camel)] {
+            if self.has_descendants(&candidate) {
+                return candidate;
+            }
+        }
+        canonical
+    }
+
+    /// `a` 所在的 property source 是否比 `b` 的优先级更高
+    pub fn takes_precedence(&self, a: &str, b: &str) -> bool {
+        match (self.precedence_of(a), self.precedence_of(b)) {
+            (Some(a), Some(b)) => a < b,
+            (Some(_), None) => true,
+            _ => false,
+        }
+    }
+
+    fn precedence_of(&self, name: &str) -> Option<usize> {
+        let sources = self.environment.get_property_sources();
+        std::iter::once(name)
+            .chain(self.names.iter().map(String::as_str).filter(|n| is_descendant(n, name)))
+            .filter_map(|n| self.environment.get_property_origin(n))
+            .filter_map(|origin| sources.precedence_of(origin))
+            .min()
+    }
+
+    /// 转换一个值；失败时记录错误（含属性来源）并返回 `None`
+    pub fn convert<T>(&mut self, name: &str, value: &str, parse: impl FnOnce(&str) -> Result<T, String>) -> Option<T> {
+        match parse(value) {
+            Ok(converted) => Some(converted),
+            Err(reason) => {
+                self.errors.push(BindError {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                    origin: self.environment.get_property_origin(name).map(str::to_string),
+                    target_type: std::any::type_name::<T>(),
+                    reason,
+                });
+                None
+            }
+        }
+    }
+}
+
+fn is_descendant(candidate: &str, name: &str) -> bool {
+    candidate
+        .strip_prefix(name)
+        .is_some_and(|rest| rest.starts_with('.') || rest.starts_with('['))
+}
+
+fn to_camel_case(field: &str) -> String {
+    let mut result = String::with_capacity(field.len());
+    let mut upper = false;
+    for c in field.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// `#[ConfigurationProperties]` 生成的 [`BindFn`] 调用此函数，把失败包装为 `BeanCreation` 错误
+pub fn bind_bean<T: Bindable + 'static>(
+    bean_name: &str,
+    prefix: &str,
+    bean: &mut dyn Any,
+    environment: &Environment,
+) -> Result<(), BeansError> {
+    let Some(target) = bean.downcast_mut::<T>() else { return Ok(()) };
+    Binder::new(environment)
+        .bind_into(prefix, target)
+        .map_err(|errors| BeansError::BeanCreation {
+            bean_name: bean_name.to_string(),
+            message: format!("could not bind properties with prefix '{}'", prefix),
+            cause: Some(Box::new(errors)),
+        })
+}
@@ -0,0 +1,82 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A size in bytes, like Spring's `DataSize`. Parsed from `"512B"`, `"10KB"`, `"10MB"`, `"1GB"`, `"2TB"`.
+/// Units are powers of 1024; a bare number means bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DataSize {
+    bytes: u64,
+}
+
+const UNITS: [(&str, u64); 5] = [
+    ("TB", 1 << 40),
+    ("GB", 1 << 30),
+    ("MB", 1 << 20),
+    ("KB", 1 << 10),
+    ("B", 1),
+];
+
+impl DataSize {
+    pub const fn of_bytes(bytes: u64) -> Self {
+        Self { bytes }
+    }
+
+    pub const fn of_kilobytes(kilobytes: u64) -> Self {
+        Self { bytes: kilobytes << 10 }
+    }
+
+    pub const fn of_megabytes(megabytes: u64) -> Self {
+        Self { bytes: megabytes << 20 }
+    }
+
+    pub const fn of_gigabytes(gigabytes: u64) -> Self {
+        Self { bytes: gigabytes << 30 }
+    }
+
+    pub const fn to_bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    pub const fn to_kilobytes(&self) -> u64 {
+        self.bytes >> 10
+    }
+
+    pub const fn to_megabytes(&self) -> u64 {
+        self.bytes >> 20
+    }
+
+    pub const fn to_gigabytes(&self) -> u64 {
+        self.bytes >> 30
+    }
+}
+
+impl FromStr for DataSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        let upper = text.to_ascii_uppercase();
+        let (number, multiplier) = UNITS
+            .iter()
+            .find_map(|(unit, multiplier)| upper.strip_suffix(unit).map(|n| (n.trim_end(), *multiplier)))
+            .unwrap_or((upper.as_str(), 1));
+        let amount: u64 = number
+            .parse()
+            .map_err(|_| format!("'{}' is not a valid data size (expected e.g. 512B, 10KB, 10MB, 1GB)", text))?;
+        amount
+            .checked_mul(multiplier)
+            .map(Self::of_bytes)
+            .ok_or_else(|| format!("'{}' is too large", text))
+    }
+}
+
+impl Display for DataSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (unit, multiplier) in UNITS {
+            if self.bytes != 0 && self.bytes.is_multiple_of(multiplier) {
+                return write!(f, "{}{}", self.bytes / multiplier, unit);
+            }
+        }
+        write!(f, "{}B", self.bytes)
+    }
+}
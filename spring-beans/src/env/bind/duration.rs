@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+/// Parse a duration in Spring's simple format: a whole number followed by
+/// `ns`, `us`, `ms`, `s`, `m`, `h` or `d`, e.g. `"30s"`, `"500ms"`, `"2h"`.
+/// A bare number means milliseconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let text = s.trim();
+    let split = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+    let invalid = || format!("'{}' is not a valid duration (expected e.g. 500ms, 30s, 5m, 2h, 1d)", text);
+    let amount: u64 = number.parse().map_err(|_| invalid())?;
+    let seconds = |multiplier: u64| amount.checked_mul(multiplier).map(Duration::from_secs);
+    let duration = match unit.trim().to_ascii_lowercase().as_str() {
+        "ns" => Some(Duration::from_nanos(amount)),
+        "us" => Some(Duration::from_micros(amount)),
+        "" | "ms" => Some(Duration::from_millis(amount)),
+        "s" => seconds(1),
+        "m" => seconds(60),
+        "h" => seconds(60 * 60),
+        "d" => seconds(24 * 60 * 60),
+        _ => return Err(invalid()),
+    };
+    duration.ok_or_else(|| format!("'{}' is too large", text))
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use super::property_source::PropertySource;
+
+/// Default name of the command line source.
+pub const COMMAND_LINE_PROPERTY_SOURCE_NAME: &str = "commandLineArgs";
+
+/// `--key=value` program arguments, mirroring Spring's `SimpleCommandLinePropertySource`.
+///
+/// - `--server.port=9000` → `server.port=9000`
+/// - `--debug` → `debug=` (present with an empty value)
+/// - a repeated option joins its values with `,`
+/// - anything else is a non-option argument, kept in order
+#[derive(Debug, Clone)]
+pub struct CommandLinePropertySource {
+    name: String,
+    options: HashMap<String, String>,
+    non_option_args: Vec<String>,
+}
+
+impl CommandLinePropertySource {
+    /// Parse `std::env::args()`, skipping the program name.
+    pub fn new() -> Self {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    pub fn parse<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut options: HashMap<String, String> = HashMap::new();
+        let mut non_option_args = Vec::new();
+        for arg in args {
+            let arg = arg.into();
+            let Some(option) = arg.strip_prefix("--").filter(|o| !o.is_empty() && !o.starts_with('=')) else {
+                non_option_args.push(arg);
+                continue;
+            };
+            let (key, value) = option.split_once('=').unwrap_or((option, ""));
+            options
+                .entry(key.to_string())
+                .and_modify(|existing| {
+                    existing.push(',');
+                    existing.push_str(value);
+                })
+                .or_insert_with(|| value.to_string());
+        }
+        Self {
+            name: COMMAND_LINE_PROPERTY_SOURCE_NAME.to_string(),
+            options,
+            non_option_args,
+        }
+    }
+
+    pub fn get_non_option_args(&self) -> &[String] {
+        &self.non_option_args
+    }
+}
+
+impl Default for CommandLinePropertySource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PropertySource for CommandLinePropertySource {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_properties(&self) -> Vec<(&str, &str)> {
+        self.options.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect()
+    }
+
+    fn get_property(&self, key: &str) -> Option<&str> {
+        self.options.get(key).map(|v| v.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        let source = CommandLinePropertySource::parse([
+            "--server.port=9000", "--debug", "input.txt", "--tag=a", "--tag=b", "--url=x=y", "--",
+        ]);
+        assert_eq!(source.get_property("server.port"), Some("9000"));
+        assert_eq!(source.get_property("debug"), Some(""));
+        assert_eq!(source.get_property("tag"), Some("a,b"));
+        assert_eq!(source.get_property("url"), Some("x=y"));
+        assert_eq!(source.get_non_option_args(), ["input.txt", "--"]);
+    }
+}
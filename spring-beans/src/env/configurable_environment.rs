@@ -0,0 +1,45 @@
+/// 激活 profile 的属性名，可以写在配置文件、环境变量 `SPRING_PROFILES_ACTIVE` 或命令行 `--spring.profiles.active=dev`
+pub const ACTIVE_PROFILES_PROPERTY_NAME: &str = "spring.profiles.active";
+
+/// 没有激活任何 profile 时生效的 profile
+pub const DEFAULT_PROFILE_NAME: &str = "default";
+
+/// Mirrors Spring's `ConfigurableEnvironment`: profile management on top of property lookup.
+pub trait ConfigurableEnvironment {
+    fn set_active_profiles(&mut self, profiles: Vec<String>);
+    fn add_active_profile(&mut self, profile: String);
+    fn get_active_profiles(&self) -> Vec<String>;
+    fn set_default_profiles(&mut self, profiles: Vec<String>);
+    fn get_default_profiles(&self) -> Vec<String>;
+
+    /// A profile is active if it was activated explicitly, or if nothing was
+    /// activated and it is one of the default profiles.
+    fn is_profile_active(&self, profile: &str) -> bool {
+        let active = self.get_active_profiles();
+        if active.is_empty() {
+            self.get_default_profiles().iter().any(|p| p == profile)
+        } else {
+            active.iter().any(|p| p == profile)
+        }
+    }
+
+    /// `true` if any of the expressions matches. `"dev"` matches when `dev` is active,
+    /// `"!prod"` matches when `prod` is not active. An empty list always matches.
+    fn accepts_profiles(&self, profiles: &[String]) -> bool {
+        profiles.is_empty()
+            || profiles.iter().any(|expr| match expr.trim().strip_prefix('!') {
+                Some(negated) => !self.is_profile_active(negated.trim()),
+                None => self.is_profile_active(expr.trim()),
+            })
+    }
+}
+
+/// Split a comma-separated profile list (`"dev, cloud"`), dropping empty entries.
+pub fn parse_profiles(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect()
+}
@@ -1,69 +1,281 @@
 use std::collections::HashMap;
+use std::sync::OnceLock;
 
-/// Central environment abstraction — holds all resolved key→value properties.
-/// Mirrors Spring's `Environment` interface.
-#[derive(Debug, Default, Clone)]
+use super::configurable_environment::{ConfigurableEnvironment, DEFAULT_PROFILE_NAME};
+use super::mutable_property_sources::MutablePropertySources;
+use super::property_placeholder_helper::{PlaceholderError, PropertyPlaceholderHelper};
+use super::property_resolver::{ConfigruablePropertyResolver, PropertyResolver};
+use super::property_source::{MapPropertySource, PropertySource};
+
+/// Name of the source that holds values set through [`Environment::set_property`].
+pub const PROGRAMMATIC_PROPERTY_SOURCE_NAME: &str = "programmatic";
+
+/// Central environment abstraction — resolves properties from an ordered list of
+/// property sources (see [`MutablePropertySources`]). Mirrors Spring's `Environment` interface.
+#[derive(Debug, Clone)]
 pub struct Environment {
-    properties: HashMap<String, String>,
+    property_sources: MutablePropertySources,
+    active_profiles: Vec<String>,
+    default_profiles: Vec<String>,
+    ignore_unresolvable_nested_placeholders: bool,
+    /// `as_map()` 的缓存，任何修改 property source 的操作都会清空
+    snapshot: OnceLock<HashMap<String, String>>,
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Environment {
     pub fn new() -> Self {
         Self {
-            properties: HashMap::new(),
+            property_sources: MutablePropertySources::new(),
+            active_profiles: Vec::new(),
+            default_profiles: vec![DEFAULT_PROFILE_NAME.to_string()],
+            ignore_unresolvable_nested_placeholders: false,
+            snapshot: OnceLock::new(),
         }
     }
 
-    /// Get a property by key, returns `None` if not found.
+    pub fn get_property_sources(&self) -> &MutablePropertySources {
+        &self.property_sources
+    }
+
+    /// Mutable access for `add_first` / `add_last` / `add_before` / ...
+    pub fn get_property_sources_mut(&mut self) -> &mut MutablePropertySources {
+        self.snapshot = OnceLock::new();
+        &mut self.property_sources
+    }
+
+    /// Get a property by key from the highest-precedence source that has it.
+    /// The value is returned as written; see [`PropertyResolver::get_resolved_property`]
+    /// for a value with its `${...}` placeholders resolved.
     pub fn get_property(&self, key: &str) -> Option<&str> {
-        self.properties.get(key).map(|s| s.as_str())
+        self.property_sources.iter().find_map(|source| source.get_property(key))
+    }
+
+    /// Name of the property source that supplies `key`, e.g. `"commandLineArgs"`.
+    pub fn get_property_origin(&self, key: &str) -> Option<&str> {
+        self.property_sources
+            .iter()
+            .find(|source| source.contains_property(key))
+            .map(|source| source.get_name())
+    }
+
+    pub fn contains_property(&self, key: &str) -> bool {
+        self.get_property(key).is_some()
     }
 
     /// Get a property by key, falling back to `default` if not found.
     pub fn get_property_or_default<'a>(&'a self, key: &str, default: &'a str) -> &'a str {
-        self.properties
-            .get(key)
-            .map(|s| s.as_str())
-            .unwrap_or(default)
+        self.get_property(key).unwrap_or(default)
     }
 
-    /// Set a property.
+    /// Set a property. It takes precedence over every other source.
     pub fn set_property(&mut self, key: impl Into<String>, value: impl Into<String>) {
-        self.properties.insert(key.into(), value.into());
+        let mut properties = self
+            .property_sources
+            .get(PROGRAMMATIC_PROPERTY_SOURCE_NAME)
+            .map(|source| {
+                source
+                    .get_properties()
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<HashMap<_, _>>()
+            })
+            .unwrap_or_default();
+        properties.insert(key.into(), value.into());
+        let source = MapPropertySource::new(PROGRAMMATIC_PROPERTY_SOURCE_NAME, properties);
+        let sources = self.get_property_sources_mut();
+        if !sources.replace(PROGRAMMATIC_PROPERTY_SOURCE_NAME, source.clone()) {
+            sources.add_first(source);
+        }
     }
 
-    /// Merge all entries from a property source into this environment.
-    pub fn merge_from(&mut self, source: &dyn super::property_source::PropertySource) {
-        for (k, v) in source.get_properties() {
-            self.properties
-                .entry(k.to_string())
-                .or_insert_with(|| v.to_string());
-        }
+    /// Add a snapshot of `source` with the lowest precedence, so that values
+    /// already present in the environment win.
+    pub fn merge_from(&mut self, source: &dyn PropertySource) {
+        let properties = source
+            .get_properties()
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        self.get_property_sources_mut()
+            .add_last(MapPropertySource::new(source.get_name(), properties));
     }
 
-    /// Resolve a `${key:default}` or `${key}` placeholder.
-    /// Returns the value string if resolved, or `None` if key absent and no default.
-    pub fn resolve_placeholder<'a>(&'a self, placeholder: &'a str) -> Option<String> {
-        // Strip ${ and }
-        let inner = placeholder
-            .strip_prefix("${")
-            .and_then(|s| s.strip_suffix('}'))?;
-        if let Some(colon_pos) = inner.find(':') {
-            let key = &inner[..colon_pos];
-            let default = &inner[colon_pos + 1..];
-            Some(
-                self.properties
-                    .get(key)
-                    .map(|s| s.clone())
-                    .unwrap_or_else(|| default.to_string()),
-            )
-        } else {
-            self.properties.get(inner).map(|s| s.clone())
-        }
+    /// Resolve every placeholder in `placeholder`, e.g. `${key:default}` or `http://${host}:${port}`.
+    /// Returns `None` if a placeholder without default cannot be resolved.
+    pub fn resolve_placeholder(&self, placeholder: &str) -> Option<String> {
+        self.resolve_required_placeholders(placeholder).ok()
+    }
+
+    fn placeholder_helper(&self, ignore_unresolvable_placeholders: bool) -> PropertyPlaceholderHelper {
+        let mut helper = PropertyPlaceholderHelper::new(ignore_unresolvable_placeholders);
+        helper.set_ignore_unresolvable_nested_placeholders(self.ignore_unresolvable_nested_placeholders);
+        helper
+    }
+
+    fn replace_placeholders(&self, text: &str, ignore_unresolvable_placeholders: bool) -> Result<String, PlaceholderError> {
+        self.placeholder_helper(ignore_unresolvable_placeholders)
+            .replace_placeholders(text, &|key| self.get_property(key).map(str::to_string))
     }
 
     /// Return a plain snapshot (cloned HashMap) for passing into supplier closures.
+    ///
+    /// Every key known to any source is resolved through [`Environment::get_property`],
+    /// so precedence and relaxed environment variable names apply to the snapshot as well.
     pub fn as_map(&self) -> HashMap<String, String> {
-        self.properties.clone()
+        self.snapshot
+            .get_or_init(|| {
+                let mut map = HashMap::new();
+                for source in self.property_sources.iter() {
+                    for name in source.get_property_names() {
+                        if map.contains_key(&name) {
+                            continue;
+                        }
+                        if let Some(value) = self.get_property(&name) {
+                            map.insert(name, value.to_string());
+                        }
+                    }
+                }
+                map
+            })
+            .clone()
+    }
+}
+
+impl PropertyResolver for Environment {
+    fn contains_property(&self, key: &str) -> bool {
+        Environment::contains_property(self, key)
+    }
+
+    fn get_resolved_property(&self, key: &str) -> Result<Option<String>, PlaceholderError> {
+        let Some(value) = self.get_property(key) else {
+            return Ok(None);
+        };
+        // 属性值中的占位符属于嵌套占位符
+        self.replace_placeholders(value, self.ignore_unresolvable_nested_placeholders).map(Some)
+    }
+
+    fn resolve_placeholders(&self, text: &str) -> Result<String, PlaceholderError> {
+        self.replace_placeholders(text, true)
+    }
+
+    fn resolve_required_placeholders(&self, text: &str) -> Result<String, PlaceholderError> {
+        self.replace_placeholders(text, false)
+    }
+}
+
+impl ConfigruablePropertyResolver for Environment {
+    fn set_ignore_unresolvable_nested_placeholders(&mut self, ignore: bool) {
+        self.ignore_unresolvable_nested_placeholders = ignore;
+    }
+
+    fn is_ignore_unresolvable_nested_placeholders(&self) -> bool {
+        self.ignore_unresolvable_nested_placeholders
+    }
+}
+
+impl ConfigurableEnvironment for Environment {
+    fn set_active_profiles(&mut self, profiles: Vec<String>) {
+        self.active_profiles = profiles;
+    }
+
+    fn add_active_profile(&mut self, profile: String) {
+        if !self.active_profiles.contains(&profile) {
+            self.active_profiles.push(profile);
+        }
+    }
+
+    fn get_active_profiles(&self) -> Vec<String> {
+        self.active_profiles.clone()
+    }
+
+    fn set_default_profiles(&mut self, profiles: Vec<String>) {
+        self.default_profiles = profiles;
+    }
+
+    fn get_default_profiles(&self) -> Vec<String> {
+        self.default_profiles.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exprs(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_accepts_profiles() {
+        let mut env = Environment::new();
+        // 未激活任何 profile 时 "default" 生效
+        assert!(env.accepts_profiles(&exprs(&["default"])));
+        assert!(env.accepts_profiles(&exprs(&["!prod"])));
+        assert!(!env.accepts_profiles(&exprs(&["dev"])));
+
+        env.set_active_profiles(exprs(&["dev", "cloud"]));
+        assert!(env.accepts_profiles(&exprs(&["dev"])));
+        assert!(env.accepts_profiles(&exprs(&["prod", "cloud"])));
+        assert!(!env.accepts_profiles(&exprs(&["default"])));
+        assert!(!env.accepts_profiles(&exprs(&["!dev"])));
+        assert!(env.accepts_profiles(&[]));
+    }
+
+    #[test]
+    fn test_precedence_and_origin() {
+        use crate::env::{CommandLinePropertySource, SystemEnvironmentPropertySource};
+
+        let file: HashMap<String, String> = [("server.port", "8080"), ("app.name", "demo"), ("app.max-connections", "10")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let mut env = Environment::new();
+        let sources = env.get_property_sources_mut();
+        sources.add_last(MapPropertySource::new("application.properties", file));
+        sources.add_first(SystemEnvironmentPropertySource::from_vars([
+            ("SERVER_PORT".to_string(), "8081".to_string()),
+            ("APP_MAX_CONNECTIONS".to_string(), "20".to_string()),
+        ]));
+        sources.add_first(CommandLinePropertySource::parse(["--server.port=9000"]));
+
+        assert_eq!(env.get_property("server.port"), Some("9000"));
+        assert_eq!(env.get_property_origin("server.port"), Some("commandLineArgs"));
+        assert_eq!(env.get_property_origin("app.max-connections"), Some("systemEnvironment"));
+        assert_eq!(env.get_property_origin("app.name"), Some("application.properties"));
+
+        let map = env.as_map();
+        assert_eq!(map["server.port"], "9000");
+        assert_eq!(map["app.max-connections"], "20");
+        assert_eq!(map["app.name"], "demo");
+
+        env.set_property("app.name", "override");
+        assert_eq!(env.as_map()["app.name"], "override");
+        assert_eq!(env.get_property_origin("app.name"), Some(PROGRAMMATIC_PROPERTY_SOURCE_NAME));
+    }
+
+    #[test]
+    fn test_resolve_nested_placeholders() {
+        let mut env = Environment::new();
+        env.set_property("host", "example.com");
+        env.set_property("url", "http://${host}:${port:80}/api");
+        env.set_property("broken", "x-${missing}");
+
+        assert_eq!(env.get_resolved_property("url").unwrap().as_deref(), Some("http://example.com:80/api"));
+        assert_eq!(env.get_resolved_property("absent"), Ok(None));
+        assert_eq!(env.resolve_placeholder("${url}#${missing:top}").as_deref(), Some("http://example.com:80/api#top"));
+        assert!(env.get_resolved_property("broken").is_err());
+        assert!(env.resolve_required_placeholders("${broken}").is_err());
+        assert_eq!(env.resolve_placeholders("${missing}").unwrap(), "${missing}");
+
+        env.set_ignore_unresolvable_nested_placeholders(true);
+        assert_eq!(env.get_resolved_property("broken").unwrap().as_deref(), Some("x-${missing}"));
+        assert_eq!(env.resolve_required_placeholders("${broken}").unwrap(), "x-${missing}");
+        assert!(env.resolve_required_placeholders("${missing}").is_err());
     }
 }
@@ -1,7 +1,24 @@
+pub mod bind;
+pub mod command_line_property_source;
+pub mod configurable_environment;
 pub mod environment;
+pub mod mutable_property_sources;
+pub mod property_placeholder_helper;
+pub mod property_resolver;
 pub mod property_source;
 pub mod properties_loader;
+pub mod system_environment_property_source;
+pub mod toml_loader;
+pub mod yaml_loader;
 
+pub use command_line_property_source::CommandLinePropertySource;
+pub use configurable_environment::ConfigurableEnvironment;
 pub use environment::Environment;
+pub use mutable_property_sources::MutablePropertySources;
+pub use property_placeholder_helper::{PlaceholderError, PropertyPlaceholderHelper};
+pub use property_resolver::{ConfigruablePropertyResolver, PropertyResolver};
 pub use property_source::{PropertySource, MapPropertySource};
 pub use properties_loader::PropertiesLoader;
+pub use system_environment_property_source::SystemEnvironmentPropertySource;
+pub use toml_loader::TomlLoader;
+pub use yaml_loader::YamlLoader;
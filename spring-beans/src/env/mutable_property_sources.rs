@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use super::property_source::PropertySource;
+
+/// Ordered list of property sources, mirroring Spring's `MutablePropertySources`.
+///
+/// Index 0 has the highest precedence: a lookup returns the value from the first
+/// source that contains the key. Adding a source whose name is already present
+/// replaces the old entry at the new position.
+#[derive(Clone, Default)]
+pub struct MutablePropertySources {
+    sources: Vec<Arc<dyn PropertySource>>,
+}
+
+impl MutablePropertySources {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add with the highest precedence.
+    pub fn add_first(&mut self, source: impl PropertySource + 'static) {
+        self.remove(source.get_name());
+        self.sources.insert(0, Arc::new(source));
+    }
+
+    /// Add with the lowest precedence.
+    pub fn add_last(&mut self, source: impl PropertySource + 'static) {
+        self.remove(source.get_name());
+        self.sources.push(Arc::new(source));
+    }
+
+    /// Add with precedence immediately higher than the source named `relative`.
+    ///
+    /// # Panics
+    /// If no source named `relative` exists, or `source` has the same name.
+    pub fn add_before(&mut self, relative: &str, source: impl PropertySource + 'static) {
+        self.assert_not_self(relative, &source);
+        self.remove(source.get_name());
+        let index = self.require_index(relative);
+        self.sources.insert(index, Arc::new(source));
+    }
+
+    /// Add with precedence immediately lower than the source named `relative`.
+    ///
+    /// # Panics
+    /// If no source named `relative` exists, or `source` has the same name.
+    pub fn add_after(&mut self, relative: &str, source: impl PropertySource + 'static) {
+        self.assert_not_self(relative, &source);
+        self.remove(source.get_name());
+        let index = self.require_index(relative);
+        self.sources.insert(index + 1, Arc::new(source));
+    }
+
+    /// Replace the source named `name`, keeping its position. Returns `false` if absent.
+    pub fn replace(&mut self, name: &str, source: impl PropertySource + 'static) -> bool {
+        match self.index_of(name) {
+            Some(index) => {
+                self.sources[index] = Arc::new(source);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Arc<dyn PropertySource>> {
+        self.index_of(name).map(|index| self.sources.remove(index))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn PropertySource> {
+        self.sources.iter().find(|s| s.get_name() == name).map(|s| s.as_ref())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.index_of(name).is_some()
+    }
+
+    /// 0 is the highest precedence.
+    pub fn precedence_of(&self, name: &str) -> Option<usize> {
+        self.index_of(name)
+    }
+
+    /// Sources from highest to lowest precedence.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PropertySource> {
+        self.sources.iter().map(|s| s.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.sources.iter().position(|s| s.get_name() == name)
+    }
+
+    fn require_index(&self, name: &str) -> usize {
+        self.index_of(name)
+            .unwrap_or_else(|| panic!("PropertySource named '{}' does not exist", name))
+    }
+
+    fn assert_not_self(&self, relative: &str, source: &dyn PropertySource) {
+        assert!(
+            source.get_name() != relative,
+            "PropertySource named '{}' cannot be added relative to itself",
+            relative
+        );
+    }
+}
+
+impl std::fmt::Debug for MutablePropertySources {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.sources.iter().map(|s| s.get_name())).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env::MapPropertySource;
+    use std::collections::HashMap;
+
+    fn source(name: &str) -> MapPropertySource {
+        MapPropertySource::new(name, HashMap::new())
+    }
+
+    fn names(sources: &MutablePropertySources) -> Vec<&str> {
+        sources.iter().map(|s| s.get_name()).collect()
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut sources = MutablePropertySources::new();
+        sources.add_last(source("file"));
+        sources.add_first(source("cli"));
+        sources.add_before("file", source("env"));
+        sources.add_after("file", source("defaults"));
+        assert_eq!(names(&sources), ["cli", "env", "file", "defaults"]);
+
+        // 同名 source 会被移动到新位置
+        sources.add_first(source("file"));
+        assert_eq!(names(&sources), ["file", "cli", "env", "defaults"]);
+        assert_eq!(sources.precedence_of("env"), Some(2));
+        assert!(sources.remove("cli").is_some());
+        assert!(!sources.replace("cli", source("cli")));
+        assert_eq!(names(&sources), ["file", "env", "defaults"]);
+    }
+}
@@ -0,0 +1,274 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// 占位符解析失败
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlaceholderError {
+    /// 找不到 `placeholder` 对应的属性，且没有缺省值
+    Unresolvable { placeholder: String, value: String },
+    /// 属性值之间循环引用，如 `a=${b}`、`b=${a}`
+    Circular { placeholder: String },
+}
+
+impl Display for PlaceholderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlaceholderError::Unresolvable { placeholder, value } => {
+                write!(f, "could not resolve placeholder '{}' in value \"{}\"", placeholder, value)
+            }
+            PlaceholderError::Circular { placeholder } => {
+                write!(f, "circular placeholder reference '{}' in property definitions", placeholder)
+            }
+        }
+    }
+}
+
+impl Error for PlaceholderError {}
+
+/// 替换文本中的 `${name}` / `${name:default}` 占位符，对标 Spring 的 `PropertyPlaceholderHelper`。
+///
+/// - 占位符可以嵌入任意文本：`http://${host}:${port}/api`
+/// - key 与缺省值中都可以再嵌套占位符：`${a:${b:c}}`、`${app.${env}.url}`
+/// - 解析出的属性值本身含有占位符时递归解析，循环引用返回 [`PlaceholderError::Circular`]
+/// - `\${` 表示字面量 `${`，不会被解析
+/// - 缺省值只在 key 无法解析时才会被求值
+///
+/// ```rust,ignore
+/// let helper = PropertyPlaceholderHelper::new(false);
+/// let url = helper.replace_placeholders("http://${host:localhost}:${port}/api", &|key| env.get(key).cloned())?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct PropertyPlaceholderHelper {
+    placeholder_prefix: String,
+    placeholder_suffix: String,
+    /// 形如 `{` 的前缀，用于在 `${a:{x}}` 这类缺省值中正确配对 `}`
+    simple_prefix: Option<String>,
+    value_separator: Option<String>,
+    escape_character: Option<char>,
+    ignore_unresolvable_placeholders: bool,
+    ignore_unresolvable_nested_placeholders: bool,
+}
+
+impl Default for PropertyPlaceholderHelper {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl PropertyPlaceholderHelper {
+    /// 使用 `${`、`}`、`:` 与转义字符 `\`；`ignore_unresolvable_placeholders` 为 true 时
+    /// 无法解析的占位符原样保留，否则返回 [`PlaceholderError::Unresolvable`]
+    pub fn new(ignore_unresolvable_placeholders: bool) -> Self {
+        Self::with_syntax("${", "}", Some(":"), Some('\\'), ignore_unresolvable_placeholders)
+    }
+
+    pub fn with_syntax(
+        placeholder_prefix: &str,
+        placeholder_suffix: &str,
+        value_separator: Option<&str>,
+        escape_character: Option<char>,
+        ignore_unresolvable_placeholders: bool,
+    ) -> Self {
+        let simple_prefix = match placeholder_suffix {
+            "}" => Some("{"),
+            "]" => Some("["),
+            ")" => Some("("),
+            _ => None,
+        }
+        .filter(|simple| placeholder_prefix.ends_with(simple))
+        .map(str::to_string);
+        Self {
+            placeholder_prefix: placeholder_prefix.to_string(),
+            placeholder_suffix: placeholder_suffix.to_string(),
+            simple_prefix,
+            value_separator: value_separator.map(str::to_string),
+            escape_character,
+            ignore_unresolvable_placeholders,
+            ignore_unresolvable_nested_placeholders: false,
+        }
+    }
+
+    /// 只对属性值中的占位符生效：为 true 时，解析出的属性值里无法解析的占位符原样保留
+    pub fn set_ignore_unresolvable_nested_placeholders(&mut self, ignore: bool) {
+        self.ignore_unresolvable_nested_placeholders = ignore;
+    }
+
+    /// 替换 `value` 中的全部占位符；`resolver` 返回 key 对应的原始属性值
+    pub fn replace_placeholders(
+        &self,
+        value: &str,
+        resolver: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<String, PlaceholderError> {
+        self.parse(value, resolver, &mut Vec::new(), false)
+    }
+
+    /// `visited` 是正在展开的 key 链，用于检测循环引用；`nested` 表示 `value` 是某个属性的值
+    fn parse(
+        &self,
+        value: &str,
+        resolver: &dyn Fn(&str) -> Option<String>,
+        visited: &mut Vec<String>,
+        nested: bool,
+    ) -> Result<String, PlaceholderError> {
+        let mut result = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(start) = rest.find(&self.placeholder_prefix) {
+            if let Some(escape) = self.escape_before(rest, start) {
+                result.push_str(&rest[..escape]);
+                result.push_str(&self.placeholder_prefix);
+                rest = &rest[start + self.placeholder_prefix.len()..];
+                continue;
+            }
+            result.push_str(&rest[..start]);
+            let body_start = start + self.placeholder_prefix.len();
+            let Some(end) = self.find_placeholder_end(rest, body_start) else {
+                // 没有配对的后缀，剩余部分按字面量保留
+                result.push_str(&rest[start..]);
+                return Ok(result);
+            };
+            let body = &rest[body_start..end];
+            let (raw_key, raw_default) = self.split_default(body);
+            let key = self.parse(raw_key, resolver, visited, nested)?;
+            if visited.contains(&key) {
+                return Err(PlaceholderError::Circular { placeholder: key });
+            }
+            match resolver(&key) {
+                Some(property) => {
+                    visited.push(key);
+                    let resolved = self.parse(&property, resolver, visited, true);
+                    visited.pop();
+                    result.push_str(&resolved?);
+                }
+                None => match raw_default {
+                    Some(default) => result.push_str(&self.parse(default, resolver, visited, nested)?),
+                    None if self.ignore_unresolvable_placeholders
+                        || (nested && self.ignore_unresolvable_nested_placeholders) =>
+                    {
+                        result.push_str(&rest[start..end + self.placeholder_suffix.len()]);
+                    }
+                    None => {
+                        return Err(PlaceholderError::Unresolvable { placeholder: key, value: value.to_string() });
+                    }
+                },
+            }
+            rest = &rest[end + self.placeholder_suffix.len()..];
+        }
+        result.push_str(rest);
+        Ok(result)
+    }
+
+    /// 前缀 `start` 前面紧跟转义字符时，返回转义字符的位置
+    fn escape_before(&self, text: &str, start: usize) -> Option<usize> {
+        let escape = self.escape_character?;
+        text[..start].strip_suffix(escape).map(str::len)
+    }
+
+    /// 与 `body_start` 之前的前缀配对的后缀位置；嵌套（包括转义）的前缀各自消耗一个后缀
+    fn find_placeholder_end(&self, text: &str, body_start: usize) -> Option<usize> {
+        let mut depth = 0usize;
+        let mut index = body_start;
+        while index < text.len() {
+            let tail = &text[index..];
+            if tail.starts_with(&self.placeholder_suffix) {
+                if depth == 0 {
+                    return Some(index);
+                }
+                depth -= 1;
+                index += self.placeholder_suffix.len();
+            } else if tail.starts_with(&self.placeholder_prefix) {
+                depth += 1;
+                index += self.placeholder_prefix.len();
+            } else if let Some(simple) = self.simple_prefix.as_deref().filter(|p| tail.starts_with(p)) {
+                depth += 1;
+                index += simple.len();
+            } else {
+                index += tail.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        None
+    }
+
+    /// 在嵌套层级之外的第一个分隔符处把 `key:default` 拆开
+    fn split_default<'b>(&self, body: &'b str) -> (&'b str, Option<&'b str>) {
+        let Some(separator) = self.value_separator.as_deref() else {
+            return (body, None);
+        };
+        let mut depth = 0usize;
+        let mut index = 0;
+        while index < body.len() {
+            let tail = &body[index..];
+            if tail.starts_with(&self.placeholder_prefix) {
+                depth += 1;
+                index += self.placeholder_prefix.len();
+            } else if depth > 0 && tail.starts_with(&self.placeholder_suffix) {
+                depth -= 1;
+                index += self.placeholder_suffix.len();
+            } else if depth == 0 && tail.starts_with(separator) {
+                return (&body[..index], Some(&body[index + separator.len()..]));
+            } else {
+                index += tail.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        (body, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn props(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_embedded_nested_and_recursive() {
+        let map = props(&[
+            ("host", "example.com"),
+            ("port", "8443"),
+            ("env", "prod"),
+            ("app.prod.url", "https://${host}:${port}/api"),
+            ("greeting", "hello ${user:${fallback-user:guest}}"),
+        ]);
+        let resolver = |key: &str| map.get(key).cloned();
+        let helper = PropertyPlaceholderHelper::default();
+        let resolve = |text: &str| helper.replace_placeholders(text, &resolver);
+
+        assert_eq!(resolve("http://${host}:${port}/api").unwrap(), "http://example.com:8443/api");
+        assert_eq!(resolve("${app.${env}.url}").unwrap(), "https://example.com:8443/api");
+        assert_eq!(resolve("${a:${b:c}}").unwrap(), "c");
+        assert_eq!(resolve("${greeting}!").unwrap(), "hello guest!");
+        assert_eq!(resolve("${gateway:localhost:8080}").unwrap(), "localhost:8080");
+        assert_eq!(resolve("${missing:{\"a\":1}}").unwrap(), "{\"a\":1}");
+        assert_eq!(resolve("price: \\${amount} at ${host}").unwrap(), "price: ${amount} at example.com");
+        assert_eq!(resolve("${missing:\\${host}}").unwrap(), "${host}");
+        assert_eq!(resolve("${unterminated").unwrap(), "${unterminated");
+        // 缺省值只在需要时求值
+        assert_eq!(resolve("${host:${missing}}").unwrap(), "example.com");
+        assert_eq!(
+            resolve("x-${missing}"),
+            Err(PlaceholderError::Unresolvable { placeholder: "missing".into(), value: "x-${missing}".into() })
+        );
+    }
+
+    #[test]
+    fn test_cycles_and_ignore_flags() {
+        let map = props(&[("a", "${b}"), ("b", "x-${a}"), ("self", "${self}"), ("url", "http://${host}/"), ("twice", "${port}${port}"), ("port", "1")]);
+        let resolver = |key: &str| map.get(key).cloned();
+        let strict = PropertyPlaceholderHelper::new(false);
+        assert_eq!(strict.replace_placeholders("${a}", &resolver), Err(PlaceholderError::Circular { placeholder: "a".into() }));
+        assert!(matches!(strict.replace_placeholders("${self}", &resolver), Err(PlaceholderError::Circular { .. })));
+        assert_eq!(strict.replace_placeholders("${twice}", &resolver).unwrap(), "11");
+        assert!(strict.replace_placeholders("${url}", &resolver).is_err());
+
+        let mut nested = PropertyPlaceholderHelper::new(false);
+        nested.set_ignore_unresolvable_nested_placeholders(true);
+        assert_eq!(nested.replace_placeholders("${url}", &resolver).unwrap(), "http://${host}/");
+        assert!(nested.replace_placeholders("${host}", &resolver).is_err());
+
+        let lenient = PropertyPlaceholderHelper::new(true);
+        assert_eq!(lenient.replace_placeholders("${host}-${port}", &resolver).unwrap(), "${host}-1");
+    }
+}
@@ -0,0 +1,24 @@
+use super::property_placeholder_helper::PlaceholderError;
+
+/// 解析属性与 `${...}` 占位符，对标 Spring 的 `PropertyResolver`
+pub trait PropertyResolver {
+    fn contains_property(&self, key: &str) -> bool;
+
+    /// 属性值，其中的占位符已递归解析
+    fn get_resolved_property(&self, key: &str) -> Result<Option<String>, PlaceholderError>;
+
+    /// 无法解析且没有缺省值的占位符原样保留
+    fn resolve_placeholders(&self, text: &str) -> Result<String, PlaceholderError>;
+
+    /// 无法解析且没有缺省值的占位符返回 [`PlaceholderError::Unresolvable`]
+    fn resolve_required_placeholders(&self, text: &str) -> Result<String, PlaceholderError>;
+}
+
+/// 可配置的 [`PropertyResolver`]
+pub trait ConfigruablePropertyResolver: PropertyResolver {
+    /// 为 true 时，属性值中嵌套的无法解析的占位符原样保留，而不是报错；
+    /// 直接写在 `#[Value]` 等表达式中的占位符不受影响
+    fn set_ignore_unresolvable_nested_placeholders(&mut self, ignore: bool);
+
+    fn is_ignore_unresolvable_nested_placeholders(&self) -> bool;
+}
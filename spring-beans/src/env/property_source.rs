@@ -1,14 +1,26 @@
 use std::collections::HashMap;
 
 /// Abstraction for a named property source (e.g., a `.properties` file, env vars).
-pub trait PropertySource {
+///
+/// `Send + Sync` so an `Environment` can be shared by the concurrent bean factory.
+pub trait PropertySource: Send + Sync {
     fn get_name(&self) -> &str;
     /// Iterate all key-value pairs in this source.
     fn get_properties(&self) -> Vec<(&str, &str)>;
     fn get_property(&self, key: &str) -> Option<&str>;
+
+    fn contains_property(&self, key: &str) -> bool {
+        self.get_property(key).is_some()
+    }
+
+    /// Property names this source can answer, in the canonical `a.b.c` form where possible.
+    fn get_property_names(&self) -> Vec<String> {
+        self.get_properties().into_iter().map(|(k, _)| k.to_string()).collect()
+    }
 }
 
 /// Simple in-memory `PropertySource` backed by a `HashMap`.
+#[derive(Debug, Clone)]
 pub struct MapPropertySource {
     name: String,
     properties: HashMap<String, String>,
@@ -21,6 +33,10 @@ impl MapPropertySource {
             properties,
         }
     }
+
+    pub fn get_source(&self) -> &HashMap<String, String> {
+        &self.properties
+    }
 }
 
 impl PropertySource for MapPropertySource {
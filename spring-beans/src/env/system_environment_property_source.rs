@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use super::property_source::PropertySource;
+
+/// Default name of the OS environment variable source.
+pub const SYSTEM_ENVIRONMENT_PROPERTY_SOURCE_NAME: &str = "systemEnvironment";
+
+/// OS environment variables with relaxed names, mirroring Spring's `SystemEnvironmentPropertySource`.
+///
+/// Environment variables cannot contain `.` or `-`, so a lookup of `server.port` also tries
+/// `server_port`, `SERVER_PORT`, etc.; `app.max-connections` matches `APP_MAX_CONNECTIONS`
+/// and `APP_MAXCONNECTIONS`.
+#[derive(Debug, Clone)]
+pub struct SystemEnvironmentPropertySource {
+    name: String,
+    variables: HashMap<String, String>,
+}
+
+impl SystemEnvironmentPropertySource {
+    /// Snapshot of the current process environment.
+    pub fn new() -> Self {
+        Self::from_vars(std::env::vars())
+    }
+
+    pub fn from_vars(vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self {
+            name: SYSTEM_ENVIRONMENT_PROPERTY_SOURCE_NAME.to_string(),
+            variables: vars.into_iter().collect(),
+        }
+    }
+
+    /// The variable name that answers `key`, if any.
+    pub fn resolve_variable_name(&self, key: &str) -> Option<&str> {
+        relaxed_candidates(key)
+            .into_iter()
+            .find_map(|candidate| self.variables.get_key_value(&candidate).map(|(k, _)| k.as_str()))
+    }
+}
+
+impl Default for SystemEnvironmentPropertySource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn relaxed_candidates(key: &str) -> Vec<String> {
+    let underscored = key.replace(['.', '-'], "_");
+    let dashless = key.replace('-', "").replace('.', "_");
+    let mut candidates = vec![
+        key.to_string(),
+        key.replace('.', "_"),
+        underscored.clone(),
+        key.to_uppercase(),
+        underscored.to_uppercase(),
+        dashless.to_uppercase(),
+    ];
+    candidates.dedup();
+    candidates
+}
+
+impl PropertySource for SystemEnvironmentPropertySource {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_properties(&self) -> Vec<(&str, &str)> {
+        self.variables.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect()
+    }
+
+    fn get_property(&self, key: &str) -> Option<&str> {
+        self.resolve_variable_name(key)
+            .and_then(|name| self.variables.get(name))
+            .map(|v| v.as_str())
+    }
+
+    /// `SERVER_PORT` is also exposed as `server.port`.
+    fn get_property_names(&self) -> Vec<String> {
+        self.variables
+            .keys()
+            .flat_map(|k| [k.clone(), k.to_lowercase().replace('_', ".")])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relaxed_names() {
+        let source = SystemEnvironmentPropertySource::from_vars([
+            ("SERVER_PORT".to_string(), "8081".to_string()),
+            ("APP_MAXCONNECTIONS".to_string(), "64".to_string()),
+            ("spring_profiles_active".to_string(), "dev".to_string()),
+        ]);
+        assert_eq!(source.get_property("server.port"), Some("8081"));
+        assert_eq!(source.get_property("SERVER_PORT"), Some("8081"));
+        assert_eq!(source.get_property("app.max-connections"), Some("64"));
+        assert_eq!(source.get_property("spring.profiles.active"), Some("dev"));
+        assert_eq!(source.get_property("server.host"), None);
+        assert!(source.get_property_names().contains(&"server.port".to_string()));
+    }
+}
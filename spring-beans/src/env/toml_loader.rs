@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use toml::{Table, Value};
+
+/// Loads an `application.toml` file into a flat `key → value` map.
+///
+/// Tables become dotted keys and arrays become indexed keys, the same shape
+/// [`super::YamlLoader`] produces: `[server] port = 8080` → `server.port=8080`,
+/// `hosts = ["a", "b"]` → `hosts[0]=a`, `hosts[1]=b`.
+pub struct TomlLoader;
+
+impl TomlLoader {
+    /// Load a TOML file from `path`.
+    /// Returns an `io::Error` if the file cannot be read or is not valid TOML.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<HashMap<String, String>> {
+        let content = fs::read_to_string(path)?;
+        Self::parse(&content)
+    }
+
+    /// Parse a TOML string directly.
+    pub fn parse(content: &str) -> io::Result<HashMap<String, String>> {
+        let table: Table = content
+            .parse()
+            .map_err(|e: toml::de::Error| io::Error::new(io::ErrorKind::InvalidData, e.message().to_string()))?;
+        let mut map = HashMap::new();
+        flatten_table(&table, "", &mut map);
+        Ok(map)
+    }
+}
+
+fn flatten_table(table: &Table, prefix: &str, out: &mut HashMap<String, String>) {
+    for (k, v) in table {
+        let key = if prefix.is_empty() { k.clone() } else { format!("{}.{}", prefix, k) };
+        flatten(v, key, out);
+    }
+}
+
+fn flatten(value: &Value, key: String, out: &mut HashMap<String, String>) {
+    match value {
+        Value::Table(table) => flatten_table(table, &key, out),
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                flatten(item, format!("{}[{}]", key, i), out);
+            }
+        }
+        Value::String(s) => {
+            out.insert(key, s.clone());
+        }
+        other => {
+            out.insert(key, other.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_table() {
+        let map = TomlLoader::parse(
+            "title = \"demo\"\n[server]\nport = 8080\nratio = 1.5\n[app]\nhosts = [\"a\", \"b\"]\n[[app.admins]]\nname = \"root\"\n",
+        )
+        .unwrap();
+        assert_eq!(map["title"], "demo");
+        assert_eq!(map["server.port"], "8080");
+        assert_eq!(map["server.ratio"], "1.5");
+        assert_eq!(map["app.hosts[0]"], "a");
+        assert_eq!(map["app.admins[0].name"], "root");
+        assert!(TomlLoader::parse("a = ").is_err());
+    }
+}
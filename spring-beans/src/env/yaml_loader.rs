@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use yaml_rust2::Yaml;
+
+/// Loads an `application.yml` file into flat `key → value` maps, one per YAML document.
+///
+/// Nested mappings become dotted keys and sequences become indexed keys:
+///
+/// ```yaml
+/// server:
+///   port: 8080
+/// app:
+///   hosts: [a, b]
+/// ---
+/// spring.config.activate.on-profile: dev
+/// server.port: 9090
+/// ```
+///
+/// yields `server.port=8080`, `app.hosts[0]=a`, `app.hosts[1]=b` for the first document
+/// and `server.port=9090` for the second. `null` values become empty strings.
+pub struct YamlLoader;
+
+impl YamlLoader {
+    /// Load a YAML file from `path`.
+    /// Returns an `io::Error` if the file cannot be read or is not valid YAML.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Vec<HashMap<String, String>>> {
+        let content = fs::read_to_string(path)?;
+        Self::parse(&content)
+    }
+
+    /// Parse a YAML string directly; empty documents are skipped.
+    pub fn parse(content: &str) -> io::Result<Vec<HashMap<String, String>>> {
+        let documents = yaml_rust2::YamlLoader::load_from_str(content)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        Ok(documents
+            .iter()
+            .filter(|doc| !matches!(doc, Yaml::Null | Yaml::BadValue))
+            .map(|doc| {
+                let mut map = HashMap::new();
+                flatten(doc, String::new(), &mut map);
+                map
+            })
+            .collect())
+    }
+}
+
+fn flatten(node: &Yaml, key: String, out: &mut HashMap<String, String>) {
+    match node {
+        Yaml::Hash(hash) => {
+            for (k, v) in hash {
+                let Some(k) = scalar(k) else { continue };
+                let child = if key.is_empty() { k } else { format!("{}.{}", key, k) };
+                flatten(v, child, out);
+            }
+        }
+        Yaml::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                flatten(item, format!("{}[{}]", key, i), out);
+            }
+        }
+        other => {
+            if let Some(value) = scalar(other) {
+                if !key.is_empty() {
+                    out.insert(key, value);
+                }
+            }
+        }
+    }
+}
+
+fn scalar(node: &Yaml) -> Option<String> {
+    match node {
+        Yaml::String(s) | Yaml::Real(s) => Some(s.clone()),
+        Yaml::Integer(i) => Some(i.to_string()),
+        Yaml::Boolean(b) => Some(b.to_string()),
+        Yaml::Null => Some(String::new()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_documents() {
+        let docs = YamlLoader::parse(
+            "server:\n  port: 8080\napp:\n  name: demo\n  ratio: 0.5\n  hosts: [a, b]\n  admins:\n    - name: root\n      enabled: true\n  empty: ~\n\
+             ---\nspring:\n  config:\n    activate:\n      on-profile: dev\nserver.port: 9090\n",
+        )
+        .unwrap();
+        assert_eq!(docs.len(), 2);
+        let base = &docs[0];
+        assert_eq!(base["server.port"], "8080");
+        assert_eq!(base["app.ratio"], "0.5");
+        assert_eq!(base["app.hosts[1]"], "b");
+        assert_eq!(base["app.admins[0].name"], "root");
+        assert_eq!(base["app.admins[0].enabled"], "true");
+        assert_eq!(base["app.empty"], "");
+        assert_eq!(docs[1]["spring.config.activate.on-profile"], "dev");
+        assert_eq!(docs[1]["server.port"], "9090");
+
+        assert!(YamlLoader::parse("a: [unclosed").is_err());
+    }
+}
@@ -0,0 +1,37 @@
+use super::beans_error::BeansError;
+use super::no_such_bean_error::NoSuchBeanError;
+
+// #[Component] / #[Bean] 生成的注入代码使用的错误构造函数，
+// 避免在每个宏展开里重复拼装 BeansError。
+impl BeansError {
+    /// `#[autowired]` 依赖没有出现在已解析依赖中
+    pub fn missing_dependency(bean_name: &str, dependency: &str) -> Self {
+        BeansError::UnsatisfiedDependency {
+            bean_name: bean_name.to_string(),
+            dependency: dependency.to_string(),
+            cause: Box::new(BeansError::NoSuchBean(NoSuchBeanError::NoBeanNamed {
+                name: dependency.to_string(),
+            })),
+        }
+    }
+
+    /// `#[autowired]` 依赖存在，但类型与字段不符
+    pub fn dependency_type_mismatch(bean_name: &str, field: &str, dependency: &str, required_type: &str) -> Self {
+        BeansError::TypeMismatch {
+            bean_name: bean_name.to_string(),
+            property: field.to_string(),
+            value: format!("bean '{}'", dependency),
+            required_type: required_type.to_string(),
+        }
+    }
+
+    /// `#[Value]` 的属性值无法解析为字段类型
+    pub fn value_type_mismatch(bean_name: &str, property: &str, value: &str, required_type: &str) -> Self {
+        BeansError::TypeMismatch {
+            bean_name: bean_name.to_string(),
+            property: property.to_string(),
+            value: value.to_string(),
+            required_type: required_type.to_string(),
+        }
+    }
+}
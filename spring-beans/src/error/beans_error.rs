@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use super::no_such_bean_error::NoSuchBeanError;
+
+/// 容器创建 / 获取 bean 过程中的错误，对标 Java 的 `BeansException` 体系。
+///
+/// 嵌套的失败通过 `cause` 串联，可用 [`Error::source`] 逐层遍历，
+/// 或用 [`BeansError::full_message`] 一次性打印整条链。
+#[derive(Debug)]
+pub enum BeansError {
+    /// 找不到 bean（按名称或按类型）
+    NoSuchBean(NoSuchBeanError),
+    /// 实例化失败（supplier 出错、bean 无法放入容器等）
+    BeanCreation {
+        bean_name: String,
+        message: String,
+        cause: Option<Box<dyn Error + Send + Sync>>,
+    },
+    /// 无法打破的循环依赖，`path` 为完整链路，如 `a -> b -> a`
+    CircularReference { path: Vec<String> },
+    /// 依赖的 bean 无法获取
+    UnsatisfiedDependency {
+        bean_name: String,
+        dependency: String,
+        cause: Box<BeansError>,
+    },
+    /// 属性值或依赖 bean 的类型与字段类型不符
+    TypeMismatch {
+        bean_name: String,
+        property: String,
+        value: String,
+        required_type: String,
+    },
+    /// bean 的作用域在当前线程不可用，如在请求之外获取 request 作用域的 bean
+    ScopeNotActive { bean_name: String, scope_name: String },
+    /// 配置文件无法读取或解析（如 `application.yml` 语法错误），启动前就会失败
+    ConfigDataLoad {
+        resource: String,
+        cause: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl BeansError {
+    /// 沿 cause 链找到最内层的错误
+    pub fn root_cause(&self) -> &BeansError {
+        match self {
+            BeansError::UnsatisfiedDependency { cause, .. } => cause.root_cause(),
+            BeansError::BeanCreation { cause: Some(cause), .. } => {
+                match cause.downcast_ref::<BeansError>() {
+                    Some(inner) => inner.root_cause(),
+                    None => self,
+                }
+            }
+            _ => self,
+        }
+    }
+
+    /// 当前错误及其所有 cause，每层一行
+    pub fn full_message(&self) -> String {
+        let mut message = self.to_string();
+        let mut source = self.source();
+        while let Some(cause) = source {
+            message.push_str("\n  caused by: ");
+            message.push_str(&cause.to_string());
+            source = cause.source();
+        }
+        message
+    }
+}
+
+impl Display for BeansError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BeansError::NoSuchBean(err) => write!(f, "{}", err),
+            BeansError::BeanCreation { bean_name, message, .. } => {
+                write!(f, "Error creating bean with name '{}': {}", bean_name, message)
+            }
+            BeansError::CircularReference { path } => write!(
+                f,
+                "Requested bean is currently in creation, unresolvable circular reference: {}",
+                path.join(" -> ")
+            ),
+            BeansError::UnsatisfiedDependency { bean_name, dependency, .. } => write!(
+                f,
+                "Error creating bean with name '{}': unsatisfied dependency on bean '{}'",
+                bean_name, dependency
+            ),
+            BeansError::TypeMismatch { bean_name, property, value, required_type } => write!(
+                f,
+                "Error creating bean with name '{}': failed to convert value '{}' of property '{}' to required type '{}'",
+                bean_name, value, property, required_type
+            ),
+            BeansError::ScopeNotActive { bean_name, scope_name } => write!(
+                f,
+                "Error creating bean with name '{}': scope '{}' is not active for the current thread",
+                bean_name, scope_name
+            ),
+            BeansError::ConfigDataLoad { resource, .. } => {
+                write!(f, "Failed to load property source from '{}'", resource)
+            }
+        }
+    }
+}
+
+impl Error for BeansError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BeansError::NoSuchBean(err) => Some(err),
+            BeansError::BeanCreation { cause, .. } => cause.as_deref().map(|c| c as &(dyn Error + 'static)),
+            BeansError::UnsatisfiedDependency { cause, .. } => Some(cause.as_ref()),
+            BeansError::ConfigDataLoad { cause, .. } => Some(cause.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<NoSuchBeanError> for BeansError {
+    fn from(err: NoSuchBeanError) -> Self {
+        BeansError::NoSuchBean(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cause_chain() {
+        let err = BeansError::UnsatisfiedDependency {
+            bean_name: "a".to_string(),
+            dependency: "b".to_string(),
+            cause: Box::new(BeansError::UnsatisfiedDependency {
+                bean_name: "b".to_string(),
+                dependency: "a".to_string(),
+                cause: Box::new(BeansError::CircularReference {
+                    path: vec!["a".to_string(), "b".to_string(), "a".to_string()],
+                }),
+            }),
+        };
+        assert!(matches!(err.root_cause(), BeansError::CircularReference { path } if path.len() == 3));
+        let message = err.full_message();
+        assert_eq!(message.lines().count(), 3);
+        assert!(message.ends_with("a -> b -> a"));
+    }
+}
@@ -1,2 +1,6 @@
 pub mod autowire_error;
+pub mod beans_error;
+pub mod no_such_bean_error;
 pub mod property_access_error;
+pub use beans_error::BeansError;
+pub use no_such_bean_error::NoSuchBeanError;
@@ -0,0 +1,46 @@
+use std::fmt::{Display, Formatter};
+
+/// 按名称或类型查找 bean 失败，对标 Java 的 `NoSuchBeanDefinitionException`
+/// 与 `NoUniqueBeanDefinitionException`。
+#[derive(Debug, Clone, PartialEq)]
+pub enum NoSuchBeanError {
+    /// 容器中没有该名称的 bean
+    NoBeanNamed { name: String },
+    /// 容器中没有该类型的 bean 定义
+    NoBeanOfType { type_name: String },
+    /// 该类型有多个候选 bean，无法确定注入哪一个
+    NoUniqueBean { type_name: String, candidates: Vec<String> },
+    /// 多个候选 bean 都标注了 `#[Primary]`
+    NoUniquePrimary { type_name: String, candidates: Vec<String> },
+    /// bean 定义存在，但实例尚未创建（lazy / prototype，需要先 do_create_bean）
+    NotCreated { name: String },
+}
+
+impl Display for NoSuchBeanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoSuchBeanError::NoBeanNamed { name } => write!(f, "No bean named '{}' available", name),
+            NoSuchBeanError::NoBeanOfType { type_name } => {
+                write!(f, "No qualifying bean of type '{}' available", type_name)
+            }
+            NoSuchBeanError::NoUniqueBean { type_name, candidates } => write!(
+                f,
+                "No qualifying bean of type '{}' available: expected single matching bean but found {}: {}",
+                type_name,
+                candidates.len(),
+                candidates.join(", ")
+            ),
+            NoSuchBeanError::NoUniquePrimary { type_name, candidates } => write!(
+                f,
+                "No qualifying bean of type '{}' available: more than one 'primary' bean found among candidates: {}",
+                type_name,
+                candidates.join(", ")
+            ),
+            NoSuchBeanError::NotCreated { name } => {
+                write!(f, "Bean '{}' has not been created yet (lazy or prototype)", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for NoSuchBeanError {}
@@ -1,6 +1,13 @@
+use crate::error::BeansError;
+
 pub trait BeanFactory {
     fn get_bean(&self, name: &str) -> Option<&dyn std::any::Any>;
     fn is_singleton(&self, name: &str) -> bool;
     fn contains_bean(&self, name: &str) -> bool;
-    fn do_create_bean(&mut self, name: &str) -> Option<&dyn std::any::Any>;
-}
\ No newline at end of file
+    /// 创建（或取出已缓存的）bean。Singleton 返回容器中的实例；
+    /// Prototype 每次都会新建，但不缓存，返回 `Ok(None)`。
+    fn do_create_bean(&mut self, name: &str) -> Result<Option<&dyn std::any::Any>, BeansError>;
+    /// 按 prototype 定义新建一个实例（依赖注入、`BeanPostProcessor`、初始化回调照常执行），
+    /// 所有权交给调用方；bean 不是 prototype 时返回错误
+    fn create_prototype(&mut self, name: &str) -> Result<Box<dyn std::any::Any>, BeansError>;
+}
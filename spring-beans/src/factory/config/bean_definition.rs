@@ -2,9 +2,32 @@
 pub enum BeanScope {
     Singleton,
     Prototype,
+    /// `request`、`session`、`thread` 或自定义作用域：实例由 bean factory 中按名称注册的
+    /// [`Scope`](super::scope::Scope) 保存
+    Custom(&'static str),
 }
 
-pub trait BeanDefinition {
+impl BeanScope {
+    /// `"singleton"` / `"prototype"` 对应内置作用域，其余名称都是自定义作用域
+    pub fn from_name(name: &'static str) -> Self {
+        match name {
+            "singleton" => BeanScope::Singleton,
+            "prototype" => BeanScope::Prototype,
+            other => BeanScope::Custom(other),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BeanScope::Singleton => "singleton",
+            BeanScope::Prototype => "prototype",
+            BeanScope::Custom(name) => name,
+        }
+    }
+}
+
+/// `Send + Sync` so definitions can be shared by the concurrent bean factory.
+pub trait BeanDefinition: Send + Sync {
     fn get_bean_class_name(&self) -> &str;
     fn set_scope(&mut self, scope: BeanScope);
     fn get_scope(&self) -> BeanScope;
@@ -12,12 +35,71 @@ pub trait BeanDefinition {
     fn set_lazy_init(&mut self, lazy: bool);
     fn get_type_id(&self) -> std::any::TypeId;
     fn has_annotation(&self, annotation: &str) -> bool;
-    fn create_instance(&self, resolved_deps: &std::collections::HashMap<String, Box<dyn std::any::Any>>, env: &std::collections::HashMap<String, String>) -> Box<dyn std::any::Any>;
+    fn create_instance(&self, resolved_deps: &super::root_bean_definition::ResolvedDependencies, env: &std::collections::HashMap<String, String>) -> Result<Box<dyn std::any::Any>, crate::error::BeansError>;
+    /// Put a freshly created instance behind the shared pointer the container stores.
+    fn wrap_instance(&self, instance: Box<dyn std::any::Any>) -> crate::bean::BeanWrapper {
+        crate::bean::BeanWrapper::local(instance)
+    }
+    /// 实例创建后、初始化回调之前调用：`#[ConfigurationProperties]` 在这里从 `Environment` 绑定字段
+    fn bind_properties(&self, _bean: &mut dyn std::any::Any, _env: &crate::env::Environment) -> Result<(), crate::error::BeansError> {
+        Ok(())
+    }
+    /// 依赖注入与 `BeanPostProcessor::before_initialization` 之后调用：
+    /// `InitializingBean::after_properties_set`、`#[PostConstruct]`、`init_method`
+    fn invoke_init_methods(&self, _bean: &mut dyn std::any::Any) -> Result<(), crate::error::BeansError> {
+        Ok(())
+    }
+    /// 销毁 singleton 时调用：`DisposableBean::destroy`、`#[PreDestroy]`、`destroy_method`
+    fn invoke_destroy_methods(&self, _bean: &dyn std::any::Any) -> crate::bean::lifecycle::LifecycleResult {
+        Ok(())
+    }
+    /// 交给自定义 [`Scope`](super::scope::Scope) 的销毁回调，作用域结束时对其中的实例调用
+    fn get_destruction_callback(&self) -> Option<crate::bean::lifecycle::DestroyFn> {
+        None
+    }
     fn get_dependencies(&self) -> Vec<String>;
+    /// 以 `Lazy<T>` 注入的依赖，可以在目标 bean 创建完成之前注入
+    fn get_lazy_dependencies(&self) -> Vec<String> {
+        Vec::new()
+    }
+    /// 以 `ObjectProvider<T>` / `LazyBean<T>` 注入的依赖：prototype 与尚未创建的 lazy singleton
+    /// 在获取时才创建
+    fn get_provider_dependencies(&self) -> Vec<String> {
+        Vec::new()
+    }
+    /// 以 `Option<..>` 注入的依赖：目标 bean 不存在时不注入
+    fn get_optional_dependencies(&self) -> Vec<String> {
+        Vec::new()
+    }
+    /// 以 `Vec<..>` / `HashMap<String, ..>` 注入的依赖：注入所有类型匹配的 bean
+    fn get_collection_dependencies(&self) -> Vec<String> {
+        Vec::new()
+    }
+    /// 按类型注入的依赖；不在其中的依赖名称就是 bean 名称
+    fn get_dependency_descriptors(&self) -> &[super::dependency_descriptor::DependencyDescriptor] {
+        &[]
+    }
+    /// `#[Primary]`：同一类型有多个候选时优先注入
+    fn is_primary(&self) -> bool {
+        false
+    }
+    /// `provides = "dyn X"` 声明的 trait 对象类型
+    fn get_provided_types(&self) -> &[super::provided_type::ProvidedType] {
+        &[]
+    }
+    /// `#[Order(n)]`：集合注入时按从小到大排列，`None` 排在最后
+    fn get_order(&self) -> Option<i32> {
+        None
+    }
 
     /// Returns the `(property_key, expected_value)` condition for this bean,
     /// or `None` if the bean is unconditional.
     fn get_condition(&self) -> Option<(&str, &str)> {
         None
     }
+
+    /// `#[Profile(...)]` 表达式（如 `"dev"`、`"!prod"`），任一匹配即注册；为空表示不受 profile 限制
+    fn get_profiles(&self) -> &[String] {
+        &[]
+    }
 }
@@ -1,7 +1,16 @@
+use std::sync::Arc;
+
 use crate::factory::BeanFactory;
+use super::scope::Scope;
 
 pub trait ConfigurableBeanFactory: BeanFactory {
     fn register_singleton(&mut self, _bean_name: &str, _singleton_object: Box<dyn std::any::Any>) {}
     fn destroy_singleton(&mut self, _bean_name: &str) {}
     fn destroy_singletons(&mut self) {}
+    /// 按名称注册作用域，供 `BeanScope::Custom(scope_name)` 的 bean 使用；
+    /// `"singleton"` 与 `"prototype"` 是内置作用域，不能替换
+    fn register_scope(&mut self, _scope_name: &str, _scope: Arc<dyn Scope>) {}
+    fn get_registered_scope(&self, _scope_name: &str) -> Option<Arc<dyn Scope>> {
+        None
+    }
 }
@@ -0,0 +1,69 @@
+use std::any::TypeId;
+use crate::error::NoSuchBeanError;
+
+/// 按类型注入的依赖，对标 Spring 的 `DependencyDescriptor`。
+///
+/// `#[autowired]` 字段、`#[Autowired]` 构造函数参数与 `#[Bean]` 参数没有标注 `#[Qualifier]` 时由宏生成，
+/// 实际注入的 bean 由 [`determine_autowire_candidate`] 在 `T` 类型的 bean 中选出。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyDescriptor {
+    /// 字段 / 参数名（camelCase），也是注入方从 [`ResolvedDependencies`](super::ResolvedDependencies)
+    /// 中读取依赖时使用的键；有多个候选且都不是 `#[Primary]` 时，选择与它同名的 bean
+    name: String,
+    type_id: TypeId,
+    type_name: &'static str,
+    /// 由类型推断的 bean 名称：容器中没有 `T` 类型的 bean 定义时按它查找（如手动注册的 singleton）
+    default_name: String,
+}
+
+impl DependencyDescriptor {
+    pub fn of<T: ?Sized + 'static>(name: impl Into<String>, default_name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            default_name: default_name.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    pub fn get_type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    pub fn default_name(&self) -> &str {
+        &self.default_name
+    }
+}
+
+/// 从同一类型的候选 bean 中选出要注入的一个：唯一候选直接返回；有多个时取唯一的 `#[Primary]`，
+/// 没有 primary 时取名称等于 `fallback_name` 的候选，仍无法确定则返回列出全部候选的错误
+pub fn determine_autowire_candidate(
+    type_name: &str,
+    mut candidates: Vec<String>,
+    is_primary: impl Fn(&str) -> bool,
+    fallback_name: Option<&str>,
+) -> Result<String, NoSuchBeanError> {
+    match candidates.len() {
+        0 => return Err(NoSuchBeanError::NoBeanOfType { type_name: type_name.to_string() }),
+        1 => return Ok(candidates.remove(0)),
+        _ => {}
+    }
+    let mut primaries: Vec<String> = candidates.iter().filter(|name| is_primary(name)).cloned().collect();
+    match primaries.len() {
+        0 => {}
+        1 => return Ok(primaries.remove(0)),
+        _ => return Err(NoSuchBeanError::NoUniquePrimary { type_name: type_name.to_string(), candidates: primaries }),
+    }
+    if let Some(name) = fallback_name.filter(|name| candidates.iter().any(|c| c == name)) {
+        return Ok(name.to_string());
+    }
+    Err(NoSuchBeanError::NoUniqueBean { type_name: type_name.to_string(), candidates })
+}
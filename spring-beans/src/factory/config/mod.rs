@@ -2,9 +2,15 @@ pub mod autowire_capable_bean_factory;
 pub mod bean_definition;
 pub mod configurable_bean_factory;
 pub mod configurable_listable_bean_factory;
+pub mod dependency_descriptor;
+pub mod provided_type;
 pub mod root_bean_definition;
+pub mod scope;
 pub use autowire_capable_bean_factory::AutowireCapableBeanFactory;
 pub use bean_definition::{BeanDefinition, BeanScope};
 pub use configurable_bean_factory::ConfigurableBeanFactory;
 pub use configurable_listable_bean_factory::ConfigurableListableBeanFactory;
-pub use root_bean_definition::RootBeanDefinition;
+pub use dependency_descriptor::{determine_autowire_candidate, DependencyDescriptor};
+pub use provided_type::ProvidedType;
+pub use root_bean_definition::{BeanSupplier, CollectionEntry, ResolvedDependencies, RootBeanDefinition};
+pub use scope::{ObjectFactory, Scope, ScopeRegistration, ScopedObjects};
@@ -0,0 +1,37 @@
+use std::any::TypeId;
+use crate::bean::bean_wrapper::{BeanWrapper, ViewFn};
+
+/// bean 通过 `provides = "dyn PaymentGateway"` 声明实现的 trait：集合注入
+/// `Vec<Arc<dyn PaymentGateway>>` / `HashMap<String, Arc<dyn PaymentGateway>>` 时按它查找候选，
+/// 并用 `view` 把容器中的实例转换为 trait 对象指针
+#[derive(Clone)]
+pub struct ProvidedType {
+    type_id: TypeId,
+    type_name: &'static str,
+    view: ViewFn,
+}
+
+impl ProvidedType {
+    pub fn of<V: ?Sized + 'static>(view: ViewFn) -> Self {
+        Self { type_id: TypeId::of::<V>(), type_name: std::any::type_name::<V>(), view }
+    }
+
+    pub fn get_type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    pub fn get_type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// `Arc<dyn X>`（`Send + Sync` 的 bean）或 `Rc<dyn X>`，装箱为 `Box<dyn Any>`
+    pub fn view(&self, bean: &BeanWrapper) -> Option<Box<dyn std::any::Any>> {
+        (self.view)(bean)
+    }
+}
+
+impl std::fmt::Debug for ProvidedType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProvidedType").field("type_name", &self.type_name).finish()
+    }
+}
@@ -1,19 +1,196 @@
 use std::any::{Any, TypeId};
-use spring_macro::{all_args_constructor, data};
+use std::collections::HashMap;
+use std::rc::Rc;
+use spring_macro::data;
+use crate::bean::bean_wrapper::{BeanWrapper, WrapFn};
+use crate::bean::constructor::ConstructorDependencies;
+use crate::bean::lazy::EarlyReference;
+use crate::bean::lifecycle::{DestroyFn, InitFn, LifecycleResult};
+use crate::bean::provider::ObjectSupplier;
+use crate::env::bind::BindFn;
+use crate::env::Environment;
+use crate::error::BeansError;
 use super::bean_definition::{BeanDefinition, BeanScope};
+use super::dependency_descriptor::DependencyDescriptor;
+use super::provided_type::ProvidedType;
+
+/// 已解析的依赖：bean name → 容器中共享的实例。
+/// `Lazy<T>` 依赖以 [`EarlyReference`] 形式提供，目标 bean 可能仍在创建中；
+/// `ObjectProvider<T>` 依赖以 [`ObjectSupplier`] 形式提供，由注入方按需获取；
+/// 集合依赖以按 `#[Order]` 排好序的 [`CollectionEntry`] 列表提供。
+#[derive(Clone, Default, Debug)]
+pub struct ResolvedDependencies {
+    beans: HashMap<String, BeanWrapper>,
+    early: HashMap<String, EarlyReference>,
+    providers: HashMap<String, ObjectSupplier>,
+    collections: HashMap<String, Vec<CollectionEntry>>,
+}
+
+/// 集合注入的一个元素
+#[derive(Clone, Debug)]
+pub struct CollectionEntry {
+    name: String,
+    bean: BeanWrapper,
+    /// 元素类型是 bean 通过 `provides` 声明的 trait 时，转换得到的 `Arc<dyn X>` / `Rc<dyn X>`
+    view: Option<Rc<dyn Any>>,
+}
+
+impl CollectionEntry {
+    pub fn new(name: impl Into<String>, bean: BeanWrapper, view: Option<Box<dyn Any>>) -> Self {
+        Self { name: name.into(), bean, view: view.map(Rc::from) }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 元素类型就是 bean 类型时使用
+    pub fn bean(&self) -> &BeanWrapper {
+        &self.bean
+    }
+
+    /// trait 对象指针（`Arc<dyn X>` / `Rc<dyn X>`）；bean 没有声明该 trait 或指针类型不符时为 `None`
+    pub fn view<P: Clone + 'static>(&self) -> Option<P> {
+        self.view.as_ref()?.downcast_ref::<P>().cloned()
+    }
+}
+
+impl ResolvedDependencies {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, bean: BeanWrapper) {
+        self.beans.insert(name.into(), bean);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&BeanWrapper> {
+        self.beans.get(name)
+    }
+
+    pub fn insert_early(&mut self, name: impl Into<String>, early: EarlyReference) {
+        self.early.insert(name.into(), early);
+    }
+
+    pub fn get_early(&self, name: &str) -> Option<&EarlyReference> {
+        self.early.get(name)
+    }
+
+    pub fn insert_provider(&mut self, name: impl Into<String>, supplier: ObjectSupplier) {
+        self.providers.insert(name.into(), supplier);
+    }
+
+    pub fn get_provider(&self, name: &str) -> Option<&ObjectSupplier> {
+        self.providers.get(name)
+    }
+
+    pub fn insert_collection(&mut self, name: impl Into<String>, entries: Vec<CollectionEntry>) {
+        self.collections.insert(name.into(), entries);
+    }
+
+    pub fn get_collection(&self, name: &str) -> Option<&[CollectionEntry]> {
+        self.collections.get(name).map(Vec::as_slice)
+    }
+}
+
+/// 创建 bean 实例的闭包：(已解析依赖, 环境属性) → 新实例
+pub type BeanSupplier = Box<dyn Fn(&ResolvedDependencies, &HashMap<String, String>) -> Result<Box<dyn Any>, BeansError> + Send + Sync>;
 
 #[data]
-#[all_args_constructor]
 pub struct RootBeanDefinition {
     name: String,
     type_id: TypeId,
     scope: BeanScope,
     is_lazy: bool,
     dependencies: Vec<String>,
-    supplier: Box<dyn Fn(&std::collections::HashMap<String, Box<dyn Any>>, &std::collections::HashMap<String, String>) -> Box<dyn Any>>,
+    supplier: BeanSupplier,
     /// Optional `(property_key, expected_value)` condition.
     /// Set by `#[ConditionalOnProperty("key", having = "value")]`.
     condition: Option<(String, String)>,
+    /// Wraps a new instance into `Arc` (`Send + Sync` types) or `Rc` (others).
+    wrap: WrapFn,
+    /// 以 `Lazy<T>` 注入的依赖：允许在目标仍在创建时注入，用于打破循环依赖
+    lazy_dependencies: Vec<String>,
+    /// 以 `ObjectProvider<T>` / `LazyBean<T>` 注入的依赖：注入的是获取函数，每次调用时才取出或新建目标
+    provider_dependencies: Vec<String>,
+    /// 以 `Option<..>` 注入的依赖：容器中没有目标 bean 时注入 `None`
+    optional_dependencies: Vec<String>,
+    /// 以 `Vec<..>` / `HashMap<String, ..>` 注入的依赖：注入所有类型匹配的 bean
+    collection_dependencies: Vec<String>,
+    /// 按类型注入的依赖，由 `#[autowired]` 字段与注入参数生成
+    dependency_descriptors: Vec<DependencyDescriptor>,
+    /// 由 `#[Primary]` 设置
+    primary: bool,
+    /// 由 `provides = "dyn X"` 设置
+    provided_types: Vec<ProvidedType>,
+    /// 由 `#[Order(n)]` 设置
+    order: Option<i32>,
+    /// 初始化回调，由 `#[Component]` / `#[Bean]` 生成
+    init_method: Option<InitFn>,
+    /// 销毁回调，由 `#[Component]` / `#[Bean]` 生成
+    destroy_method: Option<DestroyFn>,
+    /// 由 `#[Profile("dev")]` / `#[Profile("!prod")]` 设置
+    profiles: Vec<String>,
+    /// 由 `#[ConfigurationProperties(prefix = "...")]` 或带 `#[Value]` 字段的 `#[Component]` 设置
+    property_binder: Option<BindFn>,
+}
+
+impl RootBeanDefinition {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: String,
+        type_id: TypeId,
+        scope: BeanScope,
+        is_lazy: bool,
+        dependencies: Vec<String>,
+        supplier: BeanSupplier,
+        condition: Option<(String, String)>,
+        wrap: WrapFn,
+    ) -> Self {
+        Self {
+            name,
+            type_id,
+            scope,
+            is_lazy,
+            dependencies,
+            supplier,
+            condition,
+            wrap,
+            lazy_dependencies: Vec::new(),
+            provider_dependencies: Vec::new(),
+            optional_dependencies: Vec::new(),
+            collection_dependencies: Vec::new(),
+            dependency_descriptors: Vec::new(),
+            primary: false,
+            provided_types: Vec::new(),
+            order: None,
+            init_method: None,
+            destroy_method: None,
+            profiles: Vec::new(),
+            property_binder: None,
+        }
+    }
+
+    /// 合并 `#[Autowired]` 构造函数参数引用的 bean；与 `#[autowired]` 字段重复的名称只保留一份
+    pub fn add_constructor_dependencies(&mut self, constructor: ConstructorDependencies) {
+        let merge = |target: &mut Vec<String>, names: Vec<String>| {
+            for name in names {
+                if !target.contains(&name) {
+                    target.push(name);
+                }
+            }
+        };
+        merge(&mut self.dependencies, constructor.dependencies);
+        merge(&mut self.lazy_dependencies, constructor.lazy_dependencies);
+        merge(&mut self.provider_dependencies, constructor.provider_dependencies);
+        merge(&mut self.optional_dependencies, constructor.optional_dependencies);
+        merge(&mut self.collection_dependencies, constructor.collection_dependencies);
+        for descriptor in constructor.descriptors {
+            if !self.dependency_descriptors.iter().any(|d| d.name() == descriptor.name()) {
+                self.dependency_descriptors.push(descriptor);
+            }
+        }
+    }
 }
 
 
@@ -46,17 +223,86 @@ impl BeanDefinition for RootBeanDefinition {
         annotation == "RootBeanDefinition"
     }
 
-    fn create_instance(&self, resolved_deps: &std::collections::HashMap<String, Box<dyn Any>>, env: &std::collections::HashMap<String, String>) -> Box<dyn Any> {
+    fn create_instance(&self, resolved_deps: &ResolvedDependencies, env: &HashMap<String, String>) -> Result<Box<dyn Any>, BeansError> {
         (self.supplier)(resolved_deps, env)
     }
 
+    fn wrap_instance(&self, instance: Box<dyn Any>) -> BeanWrapper {
+        (self.wrap)(instance)
+    }
+
     fn get_dependencies(&self) -> Vec<String> {
         self.dependencies.clone()
     }
 
+    fn bind_properties(&self, bean: &mut dyn Any, env: &Environment) -> Result<(), BeansError> {
+        match self.property_binder {
+            Some(bind) => bind(bean, env),
+            None => Ok(()),
+        }
+    }
+
+    fn invoke_init_methods(&self, bean: &mut dyn Any) -> Result<(), BeansError> {
+        match self.init_method {
+            Some(init) => init(bean).map_err(|cause| BeansError::BeanCreation {
+                bean_name: self.name.clone(),
+                message: "invocation of init method failed".to_string(),
+                cause: Some(cause),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn invoke_destroy_methods(&self, bean: &dyn Any) -> LifecycleResult {
+        match self.destroy_method {
+            Some(destroy) => destroy(bean),
+            None => Ok(()),
+        }
+    }
+
+    fn get_destruction_callback(&self) -> Option<DestroyFn> {
+        self.destroy_method
+    }
+
+    fn get_lazy_dependencies(&self) -> Vec<String> {
+        self.lazy_dependencies.clone()
+    }
+
+    fn get_provider_dependencies(&self) -> Vec<String> {
+        self.provider_dependencies.clone()
+    }
+
+    fn get_optional_dependencies(&self) -> Vec<String> {
+        self.optional_dependencies.clone()
+    }
+
+    fn get_collection_dependencies(&self) -> Vec<String> {
+        self.collection_dependencies.clone()
+    }
+
+    fn get_dependency_descriptors(&self) -> &[DependencyDescriptor] {
+        &self.dependency_descriptors
+    }
+
+    fn is_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn get_provided_types(&self) -> &[ProvidedType] {
+        &self.provided_types
+    }
+
+    fn get_order(&self) -> Option<i32> {
+        self.order
+    }
+
     fn get_condition(&self) -> Option<(&str, &str)> {
         self.condition
             .as_ref()
             .map(|(k, v)| (k.as_str(), v.as_str()))
     }
+
+    fn get_profiles(&self) -> &[String] {
+        &self.profiles
+    }
 }
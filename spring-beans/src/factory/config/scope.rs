@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::bean::bean_wrapper::BeanWrapper;
+use crate::bean::lifecycle::DestroyFn;
+use crate::error::BeansError;
+
+/// 作用域中对象不存在时，由 bean factory 提供的创建函数
+pub type ObjectFactory<'a> = dyn FnMut() -> Result<BeanWrapper, BeansError> + 'a;
+
+/// 自定义作用域，对标 Spring 的 `org.springframework.beans.factory.config.Scope`。
+///
+/// 通过 [`ConfigurableBeanFactory::register_scope`](super::ConfigurableBeanFactory::register_scope)
+/// 按名称注册；`#[Scope("name")]` 的 bean 每次被获取时都交给它决定复用还是新建。
+///
+/// `object_factory` 会创建 bean 并可能重入调用 [`Scope::register_destruction_callback`]
+/// 或获取同一作用域中的其它 bean，因此 `get` 调用它时不能持有内部的锁或 `RefCell` 借用。
+pub trait Scope: Send + Sync {
+    /// 返回作用域中名为 `name` 的对象；不存在时调用 `object_factory` 创建并保存。
+    /// 作用域当前不可用（如没有正在处理的请求）时返回 [`BeansError::ScopeNotActive`]。
+    fn get(&self, name: &str, object_factory: &mut ObjectFactory<'_>) -> Result<BeanWrapper, BeansError>;
+
+    /// 从作用域中移除对象并返回，不调用销毁回调
+    fn remove(&self, name: &str) -> Option<BeanWrapper>;
+
+    /// 作用域结束时对名为 `name` 的对象调用 `callback`（`DisposableBean`、`#[PreDestroy]` 等）
+    fn register_destruction_callback(&self, name: &str, callback: DestroyFn);
+
+    /// 当前作用域的标识，如 session id；没有时返回 `None`
+    fn get_conversation_id(&self) -> Option<String> {
+        None
+    }
+}
+
+/// 启动时通过 inventory 收集并注册到容器的作用域：
+///
+/// ```rust,ignore
+/// inventory::submit! {
+///     ScopeRegistration { name: "tenant", scope: || Arc::new(TenantScope::default()) }
+/// }
+/// ```
+pub struct ScopeRegistration {
+    pub name: &'static str,
+    pub scope: fn() -> Arc<dyn Scope>,
+}
+
+inventory::collect!(ScopeRegistration);
+
+/// 从 `scope` 中取出 bean；新建时顺带登记定义中的销毁回调
+pub(crate) fn get_from_scope(
+    scope: &dyn Scope,
+    name: &str,
+    destruction_callback: Option<DestroyFn>,
+    create: &mut ObjectFactory<'_>,
+) -> Result<BeanWrapper, BeansError> {
+    scope.get(name, &mut || {
+        let wrapper = create()?;
+        if let Some(callback) = destruction_callback {
+            scope.register_destruction_callback(name, callback);
+        }
+        Ok(wrapper)
+    })
+}
+
+pub(crate) fn scope_not_registered(name: &str, scope_name: &str) -> BeansError {
+    BeansError::BeanCreation {
+        bean_name: name.to_string(),
+        message: format!("no Scope registered for scope name '{}'", scope_name),
+        cause: None,
+    }
+}
+
+/// 一个作用域实例（一次请求、一个线程……）中的对象与销毁回调，供 [`Scope`] 实现保存状态。
+///
+/// [`ScopedObjects::destroy`] 按登记的倒序调用回调，与 singleton 的销毁顺序一致。
+#[derive(Default)]
+pub struct ScopedObjects {
+    objects: HashMap<String, BeanWrapper>,
+    destruction_callbacks: Vec<(String, DestroyFn)>,
+}
+
+impl ScopedObjects {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<BeanWrapper> {
+        self.objects.get(name).cloned()
+    }
+
+    pub fn insert(&mut self, name: &str, object: BeanWrapper) {
+        self.objects.insert(name.to_string(), object);
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<BeanWrapper> {
+        self.destruction_callbacks.retain(|(n, _)| n != name);
+        self.objects.remove(name)
+    }
+
+    pub fn register_destruction_callback(&mut self, name: &str, callback: DestroyFn) {
+        self.destruction_callbacks.push((name.to_string(), callback));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    /// 调用全部销毁回调并清空；回调出错只打印，不影响其它对象的销毁
+    pub fn destroy(&mut self) {
+        while let Some((name, callback)) = self.destruction_callbacks.pop() {
+            if let Some(object) = self.objects.get(&name) {
+                if let Err(e) = callback(object.as_any()) {
+                    eprintln!("Destroy method on scoped bean with name '{}' threw an error: {}", name, e);
+                }
+            }
+        }
+        self.objects.clear();
+    }
+}
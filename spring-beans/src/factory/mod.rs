@@ -3,7 +3,7 @@ pub mod listable_bean_factory;
 pub mod support;
 pub mod bean_factory;
 pub use bean_factory::BeanFactory;
-pub use config::{AutowireCapableBeanFactory, BeanDefinition, BeanScope, ConfigurableBeanFactory, ConfigurableListableBeanFactory, RootBeanDefinition};
+pub use config::{AutowireCapableBeanFactory, BeanDefinition, BeanScope, ConfigurableBeanFactory, ConfigurableListableBeanFactory, RootBeanDefinition, Scope, ScopeRegistration};
 pub use listable_bean_factory::ListableBeanFactory;
-pub use support::{BeanDefinitionRegistry, BeanNameGenerator, DefaultListableBeanFactory};
+pub use support::{BeanDefinitionRegistry, BeanNameGenerator, ConcurrentListableBeanFactory, DefaultListableBeanFactory, SharedBean};
 
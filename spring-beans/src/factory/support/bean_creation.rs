@@ -0,0 +1,349 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use crate::bean::bean_post_processor_register::BeanPostProcessorRegistry;
+use crate::bean::bean_wrapper::BeanWrapper;
+use crate::bean::lazy::EarlyReference;
+use crate::bean::provider::ObjectSupplier;
+use crate::env::Environment;
+use crate::error::{BeansError, NoSuchBeanError};
+use crate::factory::config::scope::{get_from_scope, scope_not_registered};
+use crate::factory::config::{determine_autowire_candidate, BeanDefinition, BeanScope, CollectionEntry, ResolvedDependencies, Scope};
+
+/// 依赖解析完成之后的创建流程，两种 bean factory 与 [`ProviderCreator`] 共用
+pub(crate) fn instantiate(
+    name: &str,
+    definition: &dyn BeanDefinition,
+    deps_snapshot: &ResolvedDependencies,
+    environment: &Environment,
+    post_processors: &BeanPostProcessorRegistry,
+) -> Result<Box<dyn Any>, BeansError> {
+    let mut instance = definition.create_instance(deps_snapshot, &environment.as_map())?;
+    // #[ConfigurationProperties]
+    definition.bind_properties(instance.as_mut(), environment)?;
+    // BeanPostProcessor: before initialization
+    post_processors.apply_before_initialization(name, instance.as_mut());
+    // InitializingBean / #[PostConstruct] / init_method
+    definition.invoke_init_methods(instance.as_mut())?;
+    // BeanPostProcessor: after initialization
+    post_processors.apply_after_initialization(name, instance.as_mut());
+    Ok(instance)
+}
+
+/// `create_prototype` 只接受 prototype：其它作用域的实例由容器或 `Scope` 持有，不能交出所有权
+pub(crate) fn require_prototype(name: &str, definition: &dyn BeanDefinition) -> Result<(), BeansError> {
+    match definition.get_scope() {
+        BeanScope::Prototype => Ok(()),
+        other => Err(BeansError::BeanCreation {
+            bean_name: name.to_string(),
+            message: format!("bean is not a prototype (scope '{}')", other.name()),
+            cause: None,
+        }),
+    }
+}
+
+/// 注入点 `key` 实际引用的 bean 名称。没有 [`DependencyDescriptor`](crate::factory::config::DependencyDescriptor)
+/// 的依赖（`deps = [..]`、`#[Qualifier]`）按名称注入；其余按类型在已注册的定义中查找候选，
+/// 没有候选时退回由类型推断的名称（手动注册的 singleton 没有类型信息）
+pub(crate) fn autowire_target(
+    definition: &dyn BeanDefinition,
+    key: &str,
+    bean_definition_names: &[String],
+    bean_definition_map: &HashMap<String, Arc<dyn BeanDefinition>>,
+) -> Result<String, BeansError> {
+    let Some(descriptor) = definition.get_dependency_descriptors().iter().find(|d| d.name() == key) else {
+        return Ok(key.to_string());
+    };
+    let candidates: Vec<String> = bean_definition_names
+        .iter()
+        .filter(|name| bean_definition_map.get(*name).is_some_and(|bd| bd.get_type_id() == descriptor.get_type_id()))
+        .cloned()
+        .collect();
+    if candidates.is_empty() {
+        return Ok(descriptor.default_name().to_string());
+    }
+    let is_primary = |name: &str| bean_definition_map.get(name).is_some_and(|bd| bd.is_primary());
+    Ok(determine_autowire_candidate(descriptor.get_type_name(), candidates, is_primary, Some(descriptor.name()))?)
+}
+
+/// 集合依赖的一个候选：(bean 名称, 定义)
+pub(crate) type Candidate = (String, Arc<dyn BeanDefinition>);
+
+/// 集合依赖 `key` 的候选：类型就是元素类型、或通过 `provides` 声明了元素类型的 bean，
+/// 按 `#[Order]` 从小到大排列（未标注的排在最后，同序保持注册顺序）；注入方自己不在其中
+pub(crate) fn collection_candidates(
+    owner: &str,
+    definition: &dyn BeanDefinition,
+    key: &str,
+    bean_definition_names: &[String],
+    bean_definition_map: &HashMap<String, Arc<dyn BeanDefinition>>,
+) -> Vec<Candidate> {
+    let Some(descriptor) = definition.get_dependency_descriptors().iter().find(|d| d.name() == key) else {
+        return Vec::new();
+    };
+    let mut candidates: Vec<Candidate> = bean_definition_names
+        .iter()
+        .filter(|name| name.as_str() != owner)
+        .filter_map(|name| bean_definition_map.get(name).map(|bd| (name.clone(), Arc::clone(bd))))
+        .filter(|(_, bd)| {
+            bd.get_type_id() == descriptor.get_type_id()
+                || bd.get_provided_types().iter().any(|provided| provided.get_type_id() == descriptor.get_type_id())
+        })
+        .collect();
+    candidates.sort_by_key(|(_, bd)| bd.get_order().unwrap_or(i32::MAX));
+    candidates
+}
+
+/// 把候选 bean 包装为集合元素：元素类型是 `provides` 声明的 trait 时同时生成 trait 对象指针
+pub(crate) fn collection_entry(
+    definition: &dyn BeanDefinition,
+    key: &str,
+    name: &str,
+    candidate: &dyn BeanDefinition,
+    bean: BeanWrapper,
+) -> CollectionEntry {
+    let type_id = definition.get_dependency_descriptors().iter().find(|d| d.name() == key).map(|d| d.get_type_id());
+    let view = candidate
+        .get_provided_types()
+        .iter()
+        .find(|provided| Some(provided.get_type_id()) == type_id)
+        .and_then(|provided| provided.view(&bean));
+    CollectionEntry::new(name, bean, view)
+}
+
+/// 由 [`ProviderCreator`] 在首次获取时创建的 lazy singleton。
+///
+/// 容器与它发出的所有创建器共享同一份：注入 `ObjectProvider<T>` / `LazyBean<T>` 时尚未创建的
+/// `#[Lazy]` singleton 不会因此被提前创建，而是由先获取它的一方创建并登记在这里；
+/// 容器之后解析该 bean 时接管同一个实例，容器自己创建的 lazy singleton 也登记在这里供创建器使用。
+#[derive(Clone, Default)]
+pub struct DeferredSingletons {
+    beans: Arc<Mutex<HashMap<String, Arc<dyn Any + Send + Sync>>>>,
+}
+
+impl DeferredSingletons {
+    pub(crate) fn get(&self, name: &str) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.lock().get(name).cloned()
+    }
+
+    /// 登记新建的实例并返回最终使用的实例：两方同时创建时先登记的生效
+    pub(crate) fn publish(&self, name: &str, bean: Arc<dyn Any + Send + Sync>) -> Arc<dyn Any + Send + Sync> {
+        self.lock().entry(name.to_string()).or_insert(bean).clone()
+    }
+
+    /// 全部已登记的实例，供容器在销毁前接管
+    pub(crate) fn snapshot(&self) -> Vec<(String, Arc<dyn Any + Send + Sync>)> {
+        self.lock().iter().map(|(name, bean)| (name.clone(), bean.clone())).collect()
+    }
+
+    pub(crate) fn remove(&self, name: &str) {
+        self.lock().remove(name);
+    }
+
+    pub(crate) fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<dyn Any + Send + Sync>>> {
+        self.beans.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// `root` 的传递依赖中需要由 [`ProviderCreator`] 自己创建的定义（prototype 与自定义作用域）、
+/// 这些定义的注入点所引用的 bean 名称与集合候选，以及由容器创建、只能引用的 singleton 名称。
+/// `is_singleton` 返回 `false` 的 singleton（尚未创建的 lazy singleton）同样放入定义中，由创建器延迟创建。
+/// 不存在的 bean 被忽略，获取时再报错。
+pub(crate) struct ProviderClosure {
+    pub definitions: HashMap<String, Arc<dyn BeanDefinition>>,
+    pub targets: HashMap<String, HashMap<String, String>>,
+    pub collections: HashMap<String, HashMap<String, Vec<Candidate>>>,
+    pub singletons: Vec<String>,
+}
+
+pub(crate) fn provider_closure(
+    root: &str,
+    bean_definition_names: &[String],
+    bean_definition_map: &HashMap<String, Arc<dyn BeanDefinition>>,
+    is_singleton: impl Fn(&str) -> bool,
+) -> Result<ProviderClosure, BeansError> {
+    let mut closure = ProviderClosure {
+        definitions: HashMap::new(),
+        targets: HashMap::new(),
+        collections: HashMap::new(),
+        singletons: Vec::new(),
+    };
+    let mut queue = vec![root.to_string()];
+    while let Some(name) = queue.pop() {
+        if closure.definitions.contains_key(&name) || closure.singletons.contains(&name) {
+            continue;
+        }
+        if is_singleton(&name) {
+            closure.singletons.push(name);
+            continue;
+        }
+        let Some(definition) = bean_definition_map.get(&name) else { continue };
+        let mut targets = HashMap::new();
+        let keys = definition
+            .get_dependencies()
+            .into_iter()
+            .chain(definition.get_lazy_dependencies())
+            .chain(definition.get_provider_dependencies())
+            .chain(definition.get_optional_dependencies());
+        for key in keys {
+            let target = autowire_target(definition.as_ref(), &key, bean_definition_names, bean_definition_map)
+                .map_err(|cause| BeansError::UnsatisfiedDependency {
+                    bean_name: name.clone(),
+                    dependency: key.clone(),
+                    cause: Box::new(cause),
+                })?;
+            queue.push(target.clone());
+            targets.insert(key, target);
+        }
+        let mut collections = HashMap::new();
+        for key in definition.get_collection_dependencies() {
+            let candidates = collection_candidates(&name, definition.as_ref(), &key, bean_definition_names, bean_definition_map);
+            queue.extend(candidates.iter().map(|(candidate, _)| candidate.clone()));
+            collections.insert(key, candidates);
+        }
+        closure.targets.insert(name.clone(), targets);
+        closure.collections.insert(name.clone(), collections);
+        closure.definitions.insert(name, Arc::clone(definition));
+    }
+    Ok(closure)
+}
+
+/// `ObjectProvider<T>` 背后的创建器：持有目标及其传递依赖的定义快照，
+/// singleton 依赖以提前引用持有，因此注入完成之后可以脱离 bean factory 随时创建新实例。
+/// 定义快照中的 singleton 只创建一次，登记在与容器共享的 [`DeferredSingletons`] 中。
+pub(crate) struct ProviderCreator {
+    definitions: HashMap<String, Arc<dyn BeanDefinition>>,
+    targets: HashMap<String, HashMap<String, String>>,
+    collections: HashMap<String, HashMap<String, Vec<Candidate>>>,
+    singletons: HashMap<String, EarlyReference>,
+    deferred: DeferredSingletons,
+    scopes: HashMap<String, Arc<dyn Scope>>,
+    post_processors: BeanPostProcessorRegistry,
+    environment: Environment,
+}
+
+impl ProviderCreator {
+    pub(crate) fn new(
+        closure: ProviderClosure,
+        singletons: HashMap<String, EarlyReference>,
+        deferred: DeferredSingletons,
+        scopes: HashMap<String, Arc<dyn Scope>>,
+        post_processors: BeanPostProcessorRegistry,
+        environment: Environment,
+    ) -> Arc<Self> {
+        let ProviderClosure { definitions, targets, collections, .. } = closure;
+        Arc::new(Self { definitions, targets, collections, singletons, deferred, scopes, post_processors, environment })
+    }
+
+    pub(crate) fn supplier(self: &Arc<Self>, name: &str) -> ObjectSupplier {
+        let creator = Arc::clone(self);
+        let target = name.to_string();
+        ObjectSupplier::new(name, move || creator.get_bean(&target, &mut Vec::new()))
+    }
+
+    fn get_bean(self: &Arc<Self>, name: &str, creating: &mut Vec<String>) -> Result<BeanWrapper, BeansError> {
+        if let Some(early) = self.singletons.get(name) {
+            return early.upgrade().map(BeanWrapper::Shared).ok_or_else(|| BeansError::BeanCreation {
+                bean_name: name.to_string(),
+                message: "singleton has been destroyed or its type is not Send + Sync".to_string(),
+                cause: None,
+            });
+        }
+        let definition = self.definitions.get(name).ok_or_else(|| {
+            NoSuchBeanError::NoBeanNamed { name: name.to_string() }
+        })?;
+        if creating.iter().any(|n| n == name) {
+            let mut path = creating.clone();
+            path.push(name.to_string());
+            return Err(BeansError::CircularReference { path });
+        }
+        match definition.get_scope() {
+            BeanScope::Singleton => {
+                if let Some(bean) = self.deferred.get(name) {
+                    return Ok(BeanWrapper::Shared(bean));
+                }
+                match self.create(name, definition.as_ref(), creating)? {
+                    BeanWrapper::Shared(bean) => Ok(BeanWrapper::Shared(self.deferred.publish(name, bean))),
+                    BeanWrapper::Local(_) => Err(BeansError::BeanCreation {
+                        bean_name: name.to_string(),
+                        message: "lazy singleton obtained through ObjectProvider<T> or LazyBean<T> must be Send + Sync".to_string(),
+                        cause: None,
+                    }),
+                }
+            }
+            BeanScope::Custom(scope_name) => {
+                let scope = self.scopes.get(scope_name).ok_or_else(|| scope_not_registered(name, scope_name))?;
+                get_from_scope(scope.as_ref(), name, definition.get_destruction_callback(), &mut || {
+                    self.create(name, definition.as_ref(), creating)
+                })
+            }
+            BeanScope::Prototype => self.create(name, definition.as_ref(), creating),
+        }
+    }
+
+    /// `Option<..>` 依赖的目标是否存在
+    fn contains(&self, name: &str) -> bool {
+        self.singletons.contains_key(name) || self.definitions.contains_key(name)
+    }
+
+    fn create(self: &Arc<Self>, name: &str, definition: &dyn BeanDefinition, creating: &mut Vec<String>) -> Result<BeanWrapper, BeansError> {
+        creating.push(name.to_string());
+        let resolved = self.resolve_dependencies(name, definition, creating);
+        creating.pop();
+        let instance = instantiate(name, definition, &resolved?, &self.environment, &self.post_processors)?;
+        Ok(definition.wrap_instance(instance))
+    }
+
+    fn resolve_dependencies(self: &Arc<Self>, name: &str, definition: &dyn BeanDefinition, creating: &mut Vec<String>) -> Result<ResolvedDependencies, BeansError> {
+        let unsatisfied = |dep: &String| {
+            let bean_name = name.to_string();
+            let dependency = dep.clone();
+            move |cause| BeansError::UnsatisfiedDependency { bean_name, dependency, cause: Box::new(cause) }
+        };
+        let targets = self.targets.get(name);
+        let target = |dep: &String| targets.and_then(|t| t.get(dep)).unwrap_or(dep).clone();
+        let mut deps_snapshot = ResolvedDependencies::new();
+        for dep in definition.get_dependencies() {
+            let wrapper = self.get_bean(&target(&dep), creating).map_err(unsatisfied(&dep))?;
+            deps_snapshot.insert(dep, wrapper);
+        }
+        for dep in definition.get_optional_dependencies() {
+            let target = target(&dep);
+            if self.contains(&target) {
+                let wrapper = self.get_bean(&target, creating).map_err(unsatisfied(&dep))?;
+                deps_snapshot.insert(dep, wrapper);
+            }
+        }
+        for dep in definition.get_lazy_dependencies() {
+            let target = target(&dep);
+            let early = match self.singletons.get(&target) {
+                Some(early) => Ok(early.clone()),
+                // 延迟创建的 singleton 没有提前引用，先创建再引用
+                None if self.definitions.contains_key(&target) => {
+                    self.get_bean(&target, creating).map(|bean| EarlyReference::resolved(&bean))
+                }
+                None => Err(NoSuchBeanError::NoBeanNamed { name: target }.into()),
+            }
+            .map_err(unsatisfied(&dep))?;
+            deps_snapshot.insert_early(dep, early);
+        }
+        for dep in definition.get_provider_dependencies() {
+            let supplier = self.supplier(&target(&dep));
+            deps_snapshot.insert_provider(dep, supplier);
+        }
+        for dep in definition.get_collection_dependencies() {
+            let candidates = self.collections.get(name).and_then(|c| c.get(&dep)).map(Vec::as_slice).unwrap_or_default();
+            let mut entries = Vec::with_capacity(candidates.len());
+            for (candidate, candidate_definition) in candidates {
+                let bean = self.get_bean(candidate, creating).map_err(unsatisfied(&dep))?;
+                entries.push(collection_entry(definition, &dep, candidate, candidate_definition.as_ref(), bean));
+            }
+            deps_snapshot.insert_collection(dep, entries);
+        }
+        Ok(deps_snapshot)
+    }
+}
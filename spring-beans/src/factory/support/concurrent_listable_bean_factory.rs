@@ -0,0 +1,681 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::thread::{self, ThreadId};
+use crate::bean::bean_post_processor::BeanPostProcessor;
+use crate::bean::bean_post_processor_register::BeanPostProcessorRegistry;
+use crate::bean::bean_wrapper::BeanWrapper;
+use crate::bean::lazy::EarlyReference;
+use crate::bean::provider::ObjectSupplier;
+use crate::env::Environment;
+use crate::error::{BeansError, NoSuchBeanError};
+use crate::factory::BeanDefinitionRegistry;
+use crate::factory::config::{BeanDefinition, BeanScope, ConfigurableBeanFactory, ResolvedDependencies, Scope};
+use crate::factory::config::scope::{get_from_scope, scope_not_registered};
+use crate::factory::support::bean_creation::{
+    autowire_target, collection_candidates, collection_entry, instantiate, provider_closure, require_prototype, DeferredSingletons,
+    ProviderCreator,
+};
+use crate::factory::BeanFactory;
+use crate::factory::listable_bean_factory::ListableBeanFactory;
+
+/// 线程安全的单例：所有线程拿到的都是同一个 `Arc`
+pub type SharedBean = Arc<dyn Any + Send + Sync>;
+
+/// 每个 bean 一个槽位：创建锁 + 只写一次的实例 + 供 `Lazy<T>` 使用的提前引用。
+/// 槽位在注册 BeanDefinition 时建立，之后只读，因此 `get_bean` 可以直接返回引用。
+#[derive(Default)]
+struct SingletonSlot {
+    creation_lock: Mutex<()>,
+    instance: OnceLock<SharedBean>,
+    early_reference: EarlyReference,
+}
+
+/// 线程间的等待关系：哪个线程持有某个 bean 的创建锁、每个线程正在等待哪个 bean 的创建锁。
+/// 在创建锁上阻塞之前沿这两张表查找，回到当前线程说明线程间循环依赖（否则会死锁）
+#[derive(Default)]
+struct CreationGraph {
+    owners: HashMap<String, ThreadId>,
+    waiting: HashMap<ThreadId, String>,
+}
+
+/// 持有 bean 的创建锁；释放时先撤销 `CreationGraph` 中的登记
+struct CreationGuard<'a> {
+    factory: &'a ConcurrentListableBeanFactory,
+    name: &'a str,
+    _lock: MutexGuard<'a, ()>,
+}
+
+impl Drop for CreationGuard<'_> {
+    fn drop(&mut self) {
+        self.factory.creation_graph().owners.remove(self.name);
+    }
+}
+
+/// `DefaultListableBeanFactory` 的并发版本。
+///
+/// - 单例存为 `Arc<dyn Any + Send + Sync>`，bean 类型必须是 `Send + Sync`
+/// - 注册阶段（`&mut self`）与单线程版本一致；创建阶段只需 `&self`，
+///   可以把工厂放进 `Arc` 后在多个线程中并行获取 bean
+/// - 同一个 bean 的创建由它自己的锁保护，不同 bean 可以并行创建
+pub struct ConcurrentListableBeanFactory {
+    bean_definition_map: HashMap<String, Arc<dyn BeanDefinition>>,
+    bean_definition_names: Vec<String>,
+    singleton_slots: HashMap<String, SingletonSlot>,
+    creation_graph: Mutex<CreationGraph>,
+    /// singleton 创建完成的顺序，销毁时倒序进行
+    registered_singletons: Mutex<Vec<String>>,
+    /// 由 `ObjectProvider<T>` / `LazyBean<T>` 首次获取时创建的 lazy singleton，解析时接管
+    deferred_singletons: DeferredSingletons,
+    post_processor_registry: BeanPostProcessorRegistry,
+    environment: Environment,
+    /// 按名称注册的自定义作用域（request、session、thread……）
+    scopes: HashMap<String, Arc<dyn Scope>>,
+}
+
+impl ConcurrentListableBeanFactory {
+    pub fn new() -> Self {
+        Self {
+            bean_definition_map: HashMap::new(),
+            bean_definition_names: Vec::new(),
+            singleton_slots: HashMap::new(),
+            creation_graph: Mutex::new(CreationGraph::default()),
+            registered_singletons: Mutex::new(Vec::new()),
+            deferred_singletons: DeferredSingletons::default(),
+            post_processor_registry: BeanPostProcessorRegistry::new(),
+            environment: Environment::new(),
+            scopes: HashMap::new(),
+        }
+    }
+
+    pub fn register_post_processor(&mut self, processor: Box<dyn BeanPostProcessor>) {
+        self.post_processor_registry.register(processor);
+    }
+
+    pub fn set_environment(&mut self, environment: Environment) {
+        self.environment = environment;
+    }
+
+    pub fn get_environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    /// 获取 bean，必要时创建（lazy 单例、prototype）。可在任意线程中调用。
+    ///
+    /// Singleton 只会被创建一次：并发调用者会在该 bean 的创建锁上等待，
+    /// 然后拿到同一个 `Arc`。Prototype 每次调用都返回新实例；
+    /// 自定义作用域的 bean 由注册的 [`Scope`] 决定复用还是新建。
+    pub fn get_or_create_bean(&self, name: &str) -> Result<SharedBean, BeansError> {
+        let mut pending = Vec::new();
+        let bean = self.resolve_bean(name, &mut Vec::new(), &mut pending)?;
+        self.create_pending(pending)?;
+        Ok(bean)
+    }
+
+    /// 在不持有任何创建锁时再创建被 `Lazy<T>` / `ObjectProvider<T>` 引用的 bean，避免线程间互相等待
+    fn create_pending(&self, mut pending: Vec<String>) -> Result<(), BeansError> {
+        while let Some(dep) = pending.pop() {
+            self.resolve_bean(&dep, &mut Vec::new(), &mut pending)?;
+        }
+        Ok(())
+    }
+
+    /// 同 [`BeanFactory::create_prototype`]，只需 `&self`
+    pub fn get_prototype(&self, name: &str) -> Result<Box<dyn Any>, BeansError> {
+        let definition = self.bean_definition_map.get(name).ok_or_else(|| {
+            NoSuchBeanError::NoBeanNamed { name: name.to_string() }
+        })?;
+        require_prototype(name, definition.as_ref())?;
+        let mut pending = Vec::new();
+        let instance = self.instantiate(name, definition.as_ref(), &mut vec![name.to_string()], &mut pending)?;
+        self.create_pending(pending)?;
+        Ok(instance)
+    }
+
+    /// 已创建的 singleton（包括由 `ObjectProvider<T>` / `LazyBean<T>` 创建的 lazy singleton）；不会触发创建
+    pub fn get_singleton(&self, name: &str) -> Option<SharedBean> {
+        let slot = self.singleton_slots.get(name)?;
+        if let Some(instance) = slot.instance.get() {
+            return Some(instance.clone());
+        }
+        let deferred = self.deferred_singletons.get(name)?;
+        Some(self.adopt_singleton(name, slot, deferred))
+    }
+
+    /// 注册一个已经创建好的单例；类型约束保证它能跨线程共享
+    pub fn register_shared_singleton<T: Any + Send + Sync>(&mut self, bean_name: &str, singleton: T) {
+        self.register_shared_instance(bean_name, Arc::new(singleton));
+    }
+
+    /// 与 [`register_shared_singleton`](Self::register_shared_singleton) 相同，但接收已经包装好的 [`SharedBean`]
+    pub fn register_shared_instance(&mut self, bean_name: &str, singleton: SharedBean) {
+        let slot = self.singleton_slots.entry(bean_name.to_string()).or_default();
+        slot.early_reference.resolve(&BeanWrapper::Shared(singleton.clone()));
+        slot.instance = OnceLock::from(singleton);
+        self.registered_singletons().push(bean_name.to_string());
+    }
+
+    /// [`ConfigurableBeanFactory::register_singleton`] 的可失败版本：`Box<dyn Any>` 无法证明值是
+    /// `Send + Sync`，只接受 `Box<SharedBean>` 或 `Box<BeanWrapper::Shared>`，其他类型返回
+    /// [`BeansError::BeanCreation`] 且不注册。
+    pub fn try_register_singleton(&mut self, bean_name: &str, singleton_object: Box<dyn Any>) -> Result<(), BeansError> {
+        let shared = match singleton_object.downcast::<SharedBean>() {
+            Ok(shared) => *shared,
+            Err(other) => match other.downcast::<BeanWrapper>().map(|wrapper| *wrapper) {
+                Ok(BeanWrapper::Shared(shared)) => shared,
+                _ => {
+                    return Err(BeansError::BeanCreation {
+                        bean_name: bean_name.to_string(),
+                        message: "a concurrent bean factory needs a Box<Arc<dyn Any + Send + Sync>> or \
+                                  Box<BeanWrapper::Shared>; use register_shared_singleton for a plain value"
+                            .to_string(),
+                        cause: None,
+                    })
+                }
+            },
+        };
+        self.register_shared_instance(bean_name, shared);
+        Ok(())
+    }
+
+    /// `creating` 记录当前线程正在创建的 bean 链，用于发现循环依赖；跨线程的循环由
+    /// [`lock_for_creation`](Self::lock_for_creation) 发现。`pending` 收集尚未创建的 `Lazy<T>` 目标。
+    fn resolve_bean(&self, name: &str, creating: &mut Vec<String>, pending: &mut Vec<String>) -> Result<SharedBean, BeansError> {
+        let no_such_bean = || NoSuchBeanError::NoBeanNamed { name: name.to_string() };
+        let slot = self.singleton_slots.get(name).ok_or_else(no_such_bean)?;
+        if let Some(instance) = slot.instance.get() {
+            return Ok(instance.clone());
+        }
+        let definition = self.bean_definition_map.get(name).ok_or_else(no_such_bean)?;
+        if creating.iter().any(|n| n == name) {
+            let mut path = creating.clone();
+            path.push(name.to_string());
+            return Err(BeansError::CircularReference { path });
+        }
+        match definition.get_scope() {
+            BeanScope::Singleton => {
+                let _guard = self.lock_for_creation(name, slot, creating)?;
+                // 等锁期间可能已被其他线程创建
+                if let Some(instance) = slot.instance.get() {
+                    return Ok(instance.clone());
+                }
+                if let Some(instance) = self.deferred_singletons.get(name) {
+                    return Ok(self.adopt_singleton(name, slot, instance));
+                }
+                let mut instance = self.create_bean(name, definition.as_ref(), creating, pending)?;
+                if definition.is_lazy_init() {
+                    instance = self.deferred_singletons.publish(name, instance);
+                }
+                Ok(self.adopt_singleton(name, slot, instance))
+            }
+            BeanScope::Prototype => self.create_bean(name, definition.as_ref(), creating, pending),
+            BeanScope::Custom(scope_name) => {
+                let scope = self.scopes.get(scope_name).ok_or_else(|| scope_not_registered(name, scope_name))?;
+                let wrapper = get_from_scope(scope.as_ref(), name, definition.get_destruction_callback(), &mut || {
+                    self.create_bean(name, definition.as_ref(), creating, pending).map(BeanWrapper::Shared)
+                })?;
+                match wrapper {
+                    BeanWrapper::Shared(shared) => Ok(shared),
+                    BeanWrapper::Local(_) => Err(BeansError::BeanCreation {
+                        bean_name: name.to_string(),
+                        message: format!("scope '{}' returned an object that is not Send + Sync", scope_name),
+                        cause: None,
+                    }),
+                }
+            }
+        }
+    }
+
+    /// 获取 `name` 的创建锁。持有锁的线程（直接或经其他线程）正在等待当前线程持有的锁时，
+    /// 阻塞会造成死锁：此时返回 `CircularReference`，路径为当前线程的创建链加上跨线程的等待链
+    fn lock_for_creation<'a>(&'a self, name: &'a str, slot: &'a SingletonSlot, creating: &[String]) -> Result<CreationGuard<'a>, BeansError> {
+        let me = thread::current().id();
+        {
+            let mut graph = self.creation_graph();
+            let mut path = creating.to_vec();
+            path.push(name.to_string());
+            let mut next = name.to_string();
+            // 沿“持有者 → 它等待的 bean”前进，每个等待中的线程最多经过一次
+            for _ in 0..=graph.waiting.len() {
+                let Some(&owner) = graph.owners.get(&next) else { break };
+                if owner == me {
+                    return Err(BeansError::CircularReference { path });
+                }
+                let Some(waited) = graph.waiting.get(&owner) else { break };
+                next = waited.clone();
+                path.push(next.clone());
+            }
+            graph.waiting.insert(me, name.to_string());
+        }
+        let lock = slot.creation_lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut graph = self.creation_graph();
+        graph.waiting.remove(&me);
+        graph.owners.insert(name.to_string(), me);
+        Ok(CreationGuard { factory: self, name, _lock: lock })
+    }
+
+    fn creation_graph(&self) -> MutexGuard<'_, CreationGraph> {
+        self.creation_graph.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// 放入槽位并登记销毁顺序，提前引用随之生效
+    fn adopt_singleton(&self, name: &str, slot: &SingletonSlot, instance: SharedBean) -> SharedBean {
+        let mut adopted = false;
+        let instance = slot
+            .instance
+            .get_or_init(|| {
+                adopted = true;
+                instance
+            })
+            .clone();
+        if adopted {
+            self.registered_singletons().push(name.to_string());
+            slot.early_reference.resolve(&BeanWrapper::Shared(instance.clone()));
+        }
+        instance
+    }
+
+    /// 尚未开始创建的 `#[Lazy]` singleton：注入 `ObjectProvider<T>` / `LazyBean<T>` 时不提前创建，
+    /// 留给首次获取。`creating` 中的 bean 正在当前线程创建，只能引用
+    fn is_deferrable(&self, name: &str, creating: &[String]) -> bool {
+        self.bean_definition_map
+            .get(name)
+            .is_some_and(|definition| definition.get_scope() == BeanScope::Singleton && definition.is_lazy_init())
+            && self.get_singleton(name).is_none()
+            && !creating.iter().any(|n| n == name)
+    }
+
+    /// `Lazy<T>` 依赖直接使用槽位上的提前引用，目标创建完成时自动生效
+    fn resolve_early(&self, name: &str, pending: &mut Vec<String>) -> Result<EarlyReference, BeansError> {
+        let no_such_bean = || NoSuchBeanError::NoBeanNamed { name: name.to_string() };
+        let slot = self.singleton_slots.get(name).ok_or_else(no_such_bean)?;
+        let definition = self.bean_definition_map.get(name).ok_or_else(no_such_bean)?;
+        if definition.get_scope() != BeanScope::Singleton {
+            return Err(BeansError::BeanCreation {
+                bean_name: name.to_string(),
+                message: "only singleton beans can be injected as Lazy<T>".to_string(),
+                cause: None,
+            });
+        }
+        if slot.instance.get().is_none() {
+            pending.push(name.to_string());
+        }
+        Ok(slot.early_reference.clone())
+    }
+
+    fn create_bean(&self, name: &str, definition: &dyn BeanDefinition, creating: &mut Vec<String>, pending: &mut Vec<String>) -> Result<SharedBean, BeansError> {
+        creating.push(name.to_string());
+        let created = self.instantiate(name, definition, creating, pending);
+        creating.pop();
+        match definition.wrap_instance(created?) {
+            BeanWrapper::Shared(shared) => Ok(shared),
+            BeanWrapper::Local(_) => Err(BeansError::BeanCreation {
+                bean_name: name.to_string(),
+                message: "bean type is not Send + Sync and cannot be managed by a concurrent bean factory".to_string(),
+                cause: None,
+            }),
+        }
+    }
+
+    fn instantiate(&self, name: &str, definition: &dyn BeanDefinition, creating: &mut Vec<String>, pending: &mut Vec<String>) -> Result<Box<dyn Any>, BeansError> {
+        let unsatisfied = |dep: &String| {
+            let bean_name = name.to_string();
+            let dependency = dep.clone();
+            move |cause| BeansError::UnsatisfiedDependency { bean_name, dependency, cause: Box::new(cause) }
+        };
+        let target = |dep_name: &str| autowire_target(definition, dep_name, &self.bean_definition_names, &self.bean_definition_map);
+        let mut deps_snapshot = ResolvedDependencies::new();
+        for dep_name in definition.get_dependencies() {
+            let dep = target(&dep_name)
+                .and_then(|target| self.resolve_bean(&target, creating, pending))
+                .map_err(unsatisfied(&dep_name))?;
+            deps_snapshot.insert(dep_name, BeanWrapper::Shared(dep));
+        }
+        for dep_name in definition.get_optional_dependencies() {
+            let target = target(&dep_name).map_err(unsatisfied(&dep_name))?;
+            if self.contains_bean(&target) {
+                let dep = self.resolve_bean(&target, creating, pending).map_err(unsatisfied(&dep_name))?;
+                deps_snapshot.insert(dep_name, BeanWrapper::Shared(dep));
+            }
+        }
+        for dep_name in definition.get_lazy_dependencies() {
+            let early = target(&dep_name)
+                .and_then(|target| self.resolve_early(&target, pending))
+                .map_err(unsatisfied(&dep_name))?;
+            deps_snapshot.insert_early(dep_name, early);
+        }
+        for dep_name in definition.get_provider_dependencies() {
+            let supplier = target(&dep_name)
+                .and_then(|target| self.provider_supplier(&target, creating, pending))
+                .map_err(unsatisfied(&dep_name))?;
+            deps_snapshot.insert_provider(dep_name, supplier);
+        }
+        for dep_name in definition.get_collection_dependencies() {
+            let candidates = collection_candidates(name, definition, &dep_name, &self.bean_definition_names, &self.bean_definition_map);
+            let mut entries = Vec::with_capacity(candidates.len());
+            for (candidate, candidate_definition) in candidates {
+                let bean = self.resolve_bean(&candidate, creating, pending).map_err(unsatisfied(&dep_name))?;
+                entries.push(collection_entry(definition, &dep_name, &candidate, candidate_definition.as_ref(), BeanWrapper::Shared(bean)));
+            }
+            deps_snapshot.insert_collection(dep_name, entries);
+        }
+        instantiate(name, definition, &deps_snapshot, &self.environment, &self.post_processor_registry)
+    }
+
+    /// `ObjectProvider<T>` / `LazyBean<T>` 依赖：其中的 singleton 使用槽位上的提前引用，尚未创建的放入 `pending`；
+    /// 尚未创建的 lazy singleton 在获取时才创建
+    fn provider_supplier(&self, name: &str, creating: &[String], pending: &mut Vec<String>) -> Result<ObjectSupplier, BeansError> {
+        let closure = provider_closure(name, &self.bean_definition_names, &self.bean_definition_map, |n| {
+            self.is_singleton(n) && !self.is_deferrable(n, creating)
+        })?;
+        let mut singletons = HashMap::new();
+        for singleton in &closure.singletons {
+            let slot = self.singleton_slots.get(singleton).ok_or_else(|| {
+                NoSuchBeanError::NoBeanNamed { name: singleton.clone() }
+            })?;
+            if slot.instance.get().is_none() {
+                pending.push(singleton.clone());
+            }
+            singletons.insert(singleton.clone(), slot.early_reference.clone());
+        }
+        let creator = ProviderCreator::new(
+            closure,
+            singletons,
+            self.deferred_singletons.clone(),
+            self.scopes.clone(),
+            self.post_processor_registry.clone(),
+            self.environment.clone(),
+        );
+        Ok(creator.supplier(name))
+    }
+
+    fn registered_singletons(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        self.registered_singletons.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn destroy_slot(&mut self, name: &str) {
+        let Some(slot) = self.singleton_slots.get_mut(name) else { return };
+        slot.early_reference = EarlyReference::new();
+        let Some(instance) = slot.instance.take() else { return };
+        if let Some(definition) = self.bean_definition_map.get(name) {
+            if let Err(e) = definition.invoke_destroy_methods(instance.as_ref()) {
+                eprintln!("Destroy method on bean with name '{}' threw an error: {}", name, e);
+            }
+        }
+    }
+}
+
+impl Default for ConcurrentListableBeanFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BeanDefinitionRegistry for ConcurrentListableBeanFactory {
+    fn register_bean_definition(&mut self, bean_name: &str, bean_definition: Box<dyn BeanDefinition>) {
+        self.singleton_slots.entry(bean_name.to_string()).or_default();
+        if self.bean_definition_map.insert(bean_name.to_string(), Arc::from(bean_definition)).is_none() {
+            self.bean_definition_names.push(bean_name.to_string());
+        }
+    }
+
+    fn remove_bean_definition(&mut self, bean_name: &str) {
+        self.bean_definition_map.remove(bean_name);
+        self.singleton_slots.remove(bean_name);
+        self.bean_definition_names.retain(|n| n != bean_name);
+    }
+
+    fn contains_bean_definition(&self, bean_name: &str) -> bool {
+        self.bean_definition_map.contains_key(bean_name)
+    }
+
+    fn get_bean_definition(&self, bean_name: &str) -> Option<&dyn BeanDefinition> {
+        self.bean_definition_map.get(bean_name).map(|bd| bd.as_ref())
+    }
+
+    fn get_bean_definition_names(&self) -> &Vec<String> {
+        &self.bean_definition_names
+    }
+
+    fn get_bean_definition_count(&self) -> usize {
+        self.bean_definition_map.len()
+    }
+
+    fn is_bean_name_in_use(&self, bean_name: &str) -> bool {
+        self.singleton_slots.contains_key(bean_name)
+    }
+}
+
+impl BeanFactory for ConcurrentListableBeanFactory {
+    fn get_bean(&self, name: &str) -> Option<&dyn Any> {
+        self.get_singleton(name)?;
+        self.singleton_slots
+            .get(name)
+            .and_then(|slot| slot.instance.get())
+            .map(|instance| instance.as_ref() as &dyn Any)
+    }
+
+    fn is_singleton(&self, name: &str) -> bool {
+        self.bean_definition_map
+            .get(name)
+            .map(|definition| definition.get_scope() == BeanScope::Singleton)
+            .unwrap_or_else(|| self.singleton_slots.contains_key(name))
+    }
+
+    fn contains_bean(&self, name: &str) -> bool {
+        self.singleton_slots.contains_key(name)
+    }
+
+    fn do_create_bean(&mut self, name: &str) -> Result<Option<&dyn Any>, BeansError> {
+        self.get_or_create_bean(name)?;
+        Ok(self.get_bean(name))
+    }
+
+    fn create_prototype(&mut self, name: &str) -> Result<Box<dyn Any>, BeansError> {
+        self.get_prototype(name)
+    }
+}
+
+impl ConfigurableBeanFactory for ConcurrentListableBeanFactory {
+    /// 手动注册的单例必须能跨线程共享：传入 `Box<SharedBean>` 或 `Box<BeanWrapper::Shared>`，
+    /// 具体类型用 [`register_shared_singleton`](ConcurrentListableBeanFactory::register_shared_singleton)。
+    ///
+    /// 其他类型会被拒绝（输出到 stderr，不注册）；需要拿到错误时用
+    /// [`try_register_singleton`](ConcurrentListableBeanFactory::try_register_singleton)。
+    fn register_singleton(&mut self, bean_name: &str, singleton_object: Box<dyn Any>) {
+        if let Err(e) = self.try_register_singleton(bean_name, singleton_object) {
+            eprintln!("{}", e);
+        }
+    }
+
+    fn destroy_singleton(&mut self, bean_name: &str) {
+        self.deferred_singletons.remove(bean_name);
+        self.registered_singletons().retain(|n| n != bean_name);
+        self.destroy_slot(bean_name);
+    }
+
+    fn destroy_singletons(&mut self) {
+        // 创建器创建、容器尚未接管的 lazy singleton 同样需要销毁回调
+        for (name, instance) in self.deferred_singletons.snapshot() {
+            if let Some(slot) = self.singleton_slots.get(&name) {
+                self.adopt_singleton(&name, slot, instance);
+            }
+        }
+        self.deferred_singletons.clear();
+        // 按创建顺序倒序销毁：使用方先于它的依赖销毁
+        let order = std::mem::take(&mut *self.registered_singletons());
+        for name in order.iter().rev() {
+            self.destroy_slot(name);
+        }
+    }
+
+    fn register_scope(&mut self, scope_name: &str, scope: Arc<dyn Scope>) {
+        assert!(
+            scope_name != "singleton" && scope_name != "prototype",
+            "cannot replace existing scopes 'singleton' and 'prototype'"
+        );
+        self.scopes.insert(scope_name.to_string(), scope);
+    }
+
+    fn get_registered_scope(&self, scope_name: &str) -> Option<Arc<dyn Scope>> {
+        self.scopes.get(scope_name).cloned()
+    }
+}
+
+impl ListableBeanFactory for ConcurrentListableBeanFactory {
+    fn contains_bean_definition(&self, name: &str) -> bool {
+        self.bean_definition_map.contains_key(name)
+    }
+
+    fn get_bean_definition_count(&self) -> usize {
+        self.bean_definition_map.len()
+    }
+
+    fn get_bean_definition_names(&self) -> Vec<String> {
+        self.bean_definition_names.clone()
+    }
+
+    fn get_bean_names_for_type<T>(&self, type_id: std::any::TypeId) -> Vec<String> {
+        self.bean_definition_map.iter()
+            .filter(|(_, bd)| bd.get_type_id() == type_id)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    fn get_beans_of_type<T: 'static>(&self) -> Vec<&T> {
+        self.singleton_slots
+            .values()
+            .filter_map(|slot| slot.instance.get())
+            .filter_map(|instance| instance.downcast_ref::<T>())
+            .collect()
+    }
+
+    fn get_bean_definition_names_for_annotation(&self, annotation: &str) -> Vec<String> {
+        self.bean_definition_map.iter()
+            .filter(|(_, bd)| bd.has_annotation(annotation))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bean::bean_wrapper::{WrapProbe, WrapShared as _};
+    use crate::factory::config::RootBeanDefinition;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Barrier;
+
+    struct Counter {
+        hits: AtomicUsize,
+    }
+
+    static CREATED: AtomicUsize = AtomicUsize::new(0);
+
+    fn counter_definition() -> RootBeanDefinition {
+        RootBeanDefinition::new(
+            "counter".to_string(),
+            std::any::TypeId::of::<Counter>(),
+            BeanScope::Singleton,
+            true,
+            vec![],
+            Box::new(|_, _| {
+                CREATED.fetch_add(1, Ordering::SeqCst);
+                thread::sleep(std::time::Duration::from_millis(20));
+                Ok(Box::new(Counter { hits: AtomicUsize::new(0) }) as Box<dyn Any>)
+            }),
+            None,
+            WrapProbe::<Counter>::new().wrap_fn(),
+        )
+    }
+
+    #[test]
+    fn test_lazy_singleton_created_once_across_threads() {
+        let mut factory = ConcurrentListableBeanFactory::new();
+        factory.register_bean_definition("counter", Box::new(counter_definition()));
+        let factory = Arc::new(factory);
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let factory = Arc::clone(&factory);
+                thread::spawn(move || {
+                    let bean = factory.get_or_create_bean("counter").unwrap();
+                    bean.downcast_ref::<Counter>().unwrap().hits.fetch_add(1, Ordering::SeqCst);
+                    bean
+                })
+            })
+            .collect();
+        let beans: Vec<SharedBean> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+        assert_eq!(CREATED.load(Ordering::SeqCst), 1);
+        assert!(beans.iter().all(|b| Arc::ptr_eq(b, &beans[0])));
+        let counter = factory.get_bean("counter").unwrap().downcast_ref::<Counter>().unwrap();
+        assert_eq!(counter.hits.load(Ordering::SeqCst), 8);
+    }
+
+    fn waiting_definition(name: &str, deps: Vec<String>, barrier: Arc<Barrier>) -> RootBeanDefinition {
+        RootBeanDefinition::new(
+            name.to_string(),
+            std::any::TypeId::of::<Counter>(),
+            BeanScope::Singleton,
+            false,
+            deps,
+            Box::new(move |_, _| {
+                barrier.wait();
+                Ok(Box::new(Counter { hits: AtomicUsize::new(0) }) as Box<dyn Any>)
+            }),
+            None,
+            WrapProbe::<Counter>::new().wrap_fn(),
+        )
+    }
+
+    #[test]
+    fn test_cycle_across_threads_fails_instead_of_deadlocking() {
+        // a -> gate_a -> b，b -> gate_b -> a：两个 gate 在 barrier 处会合，
+        // 保证两个线程分别持有 a、b 的创建锁之后才去获取对方
+        let barrier = Arc::new(Barrier::new(2));
+        let mut factory = ConcurrentListableBeanFactory::new();
+        factory.register_bean_definition("a", Box::new(waiting_definition("a", vec!["gate_a".into(), "b".into()], Arc::new(Barrier::new(1)))));
+        factory.register_bean_definition("b", Box::new(waiting_definition("b", vec!["gate_b".into(), "a".into()], Arc::new(Barrier::new(1)))));
+        factory.register_bean_definition("gate_a", Box::new(waiting_definition("gate_a", vec![], barrier.clone())));
+        factory.register_bean_definition("gate_b", Box::new(waiting_definition("gate_b", vec![], barrier)));
+        let factory = Arc::new(factory);
+
+        let handles: Vec<_> = ["a", "b"]
+            .into_iter()
+            .map(|name| {
+                let factory = Arc::clone(&factory);
+                thread::spawn(move || factory.get_or_create_bean(name).map(|_| ()))
+            })
+            .collect();
+        let errors: Vec<BeansError> = handles.into_iter().map(|h| h.join().unwrap().unwrap_err()).collect();
+        assert!(errors.iter().any(|e| matches!(
+            e.root_cause(),
+            BeansError::CircularReference { path } if path.first() == path.last() && path.len() == 3
+        )));
+    }
+
+    #[test]
+    fn test_register_singleton_requires_shared_value() {
+        let mut factory = ConcurrentListableBeanFactory::new();
+        let shared: SharedBean = Arc::new(Counter { hits: AtomicUsize::new(0) });
+        factory.register_singleton("shared", Box::new(shared));
+        assert!(factory.get_bean("shared").is_some());
+
+        factory.register_shared_singleton("typed", Counter { hits: AtomicUsize::new(0) });
+        assert!(factory.get_bean("typed").unwrap().downcast_ref::<Counter>().is_some());
+
+        let err = factory
+            .try_register_singleton("plain", Box::new(Counter { hits: AtomicUsize::new(0) }))
+            .unwrap_err();
+        assert!(matches!(err, BeansError::BeanCreation { ref bean_name, .. } if bean_name == "plain"));
+        factory.register_singleton("plain", Box::new(Counter { hits: AtomicUsize::new(0) }));
+        assert!(factory.get_bean("plain").is_none());
+    }
+
+    #[test]
+    fn test_factory_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ConcurrentListableBeanFactory>();
+    }
+}
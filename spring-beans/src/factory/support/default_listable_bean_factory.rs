@@ -1,23 +1,45 @@
 use std::any::Any;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
+use std::sync::Arc;
 use crate::bean::bean_post_processor_register::BeanPostProcessorRegistry;
+use crate::bean::bean_wrapper::BeanWrapper;
+use crate::bean::lazy::EarlyReference;
+use crate::bean::provider::ObjectSupplier;
 use crate::env::Environment;
+use crate::error::{BeansError, NoSuchBeanError};
 use spring_macro::data;
 use crate::factory::BeanDefinitionRegistry;
-use crate::factory::config::{BeanDefinition, BeanScope, ConfigurableBeanFactory};
+use crate::factory::config::{BeanDefinition, BeanScope, ConfigurableBeanFactory, ResolvedDependencies, Scope};
+use crate::factory::config::scope::{get_from_scope, scope_not_registered};
+use crate::factory::support::bean_creation::{
+    autowire_target, collection_candidates, collection_entry, instantiate, provider_closure, require_prototype, DeferredSingletons,
+    ProviderClosure, ProviderCreator,
+};
 use crate::factory::BeanFactory;
 use crate::factory::listable_bean_factory::ListableBeanFactory;
 
 #[data]
 pub struct DefaultListableBeanFactory {
-    bean_definition_map: HashMap<String, Box<dyn BeanDefinition>>,
+    bean_definition_map: HashMap<String, Arc<dyn BeanDefinition>>,
     bean_definition_names: Vec<String>,
-    singleton_objects: HashMap<String, Box<dyn Any>>,
-    early_singleton_objects: HashMap<String, Box<dyn Any>>,
-    singleton_factories: HashMap<String, Box<dyn Fn() -> Box<dyn Any>>>,
-    currently_in_creation: HashSet<String>,
+    /// 单例缓存：每个 bean 只存一份，注入方拿到的是同一个 `Arc` / `Rc` 的克隆
+    singleton_objects: HashMap<String, BeanWrapper>,
+    /// 二级缓存：已发给 `Lazy<T>` 依赖、但目标尚未创建完成的提前引用；目标创建完成时绑定到最终实例。
+    /// 只有 `Lazy<T>` 能打破循环依赖：直接注入 `&T` / `Arc<T>` 的循环仍然报 `CircularReference`
+    early_singleton_objects: HashMap<String, EarlyReference>,
+    /// 三级缓存：正在创建的 singleton 的提前引用工厂，经过 `BeanPostProcessor::get_early_bean_reference`，
+    /// 第一次被 `Lazy<T>` 引用时调用并移入二级缓存
+    singleton_factories: HashMap<String, Box<dyn Fn() -> EarlyReference>>,
+    /// 正在创建的 bean 链（按进入顺序），用于检测并报告循环依赖
+    currently_in_creation: Vec<String>,
+    /// 由 `ObjectProvider<T>` / `LazyBean<T>` 首次获取时创建的 lazy singleton，解析时接管
+    deferred_singletons: DeferredSingletons,
+    /// singleton 放入缓存的顺序；依赖总是先于使用方完成，销毁时倒序进行
+    registered_singletons: Vec<String>,
     post_processor_registry: BeanPostProcessorRegistry,
     environment: Environment,
+    /// 按名称注册的自定义作用域（request、session、thread……）
+    scopes: HashMap<String, Arc<dyn Scope>>,
 }
 
 impl BeanDefinitionRegistry for DefaultListableBeanFactory {
@@ -25,8 +47,8 @@ impl BeanDefinitionRegistry for DefaultListableBeanFactory {
         self.bean_definition_map.contains_key(bean_name)
     }
 
-    fn get_bean_definition(&self, bean_name: &str) -> Option<&Box<dyn BeanDefinition>> {
-        self.bean_definition_map.get(bean_name)
+    fn get_bean_definition(&self, bean_name: &str) -> Option<&dyn BeanDefinition> {
+        self.bean_definition_map.get(bean_name).map(|bd| bd.as_ref())
     }
 
     fn get_bean_definition_count(&self) -> usize {
@@ -41,11 +63,11 @@ impl BeanDefinitionRegistry for DefaultListableBeanFactory {
         BeanDefinitionRegistry::contains_bean_definition(self, bean_name)
             || self.singleton_objects.contains_key(bean_name)
             || self.singleton_factories.contains_key(bean_name)
-            || self.currently_in_creation.contains(bean_name)
+            || self.currently_in_creation.iter().any(|n| n == bean_name)
     }
 
     fn register_bean_definition(&mut self, bean_name: &str, bean_definition: Box<dyn BeanDefinition>) {
-        self.bean_definition_map.insert(bean_name.to_string(), bean_definition);
+        self.bean_definition_map.insert(bean_name.to_string(), Arc::from(bean_definition));
         self.bean_definition_names.push(bean_name.to_string());
     }
 
@@ -60,7 +82,7 @@ impl BeanFactory for DefaultListableBeanFactory {
     fn get_bean(&self, name: &str) -> Option<&dyn Any> {
         self.singleton_objects
             .get(name)
-            .map(|boxed| boxed.as_ref())
+            .map(|wrapper| wrapper.as_any())
     }
 
     fn is_singleton(&self, name: &str) -> bool {
@@ -75,75 +97,76 @@ impl BeanFactory for DefaultListableBeanFactory {
             || self.singleton_objects.contains_key(name)
     }
 
-    fn do_create_bean(&mut self, name: &str) -> Option<&dyn std::any::Any> {
-        let (dependencies, scope) = {
-            let definition = self.bean_definition_map.get(name)?;
-            (definition.get_dependencies(), definition.get_scope())
-        };
-        // 如果是 Singleton 且已创建，直接返回缓存
-        if scope == BeanScope::Singleton {
-            if self.singleton_objects.contains_key(name) {
-                return self.singleton_objects.get(name).map(|b| b.as_ref());
-            }
-        }
-        // 先递归创建所有依赖
-        for dep in &dependencies {
-            if self.currently_in_creation.contains(dep) {
-                panic!("Circular dependency detected: {} -> {}", name, dep);
-            }
-            self.do_create_bean(dep);
-        }
-        // 从 singleton_objects 收集依赖快照（拷贝其中的引用信息，实际指针仔然存于容器）
-        // 注意： supplier 闭包通过 &HashMap 读取依赖，调用 downcast_ref + clone 进行字段注入
-        let mut instance: Box<dyn Any> = {
-            // 临时收集依赖的引用 map，内容仅包含该 bean 声明的依赖项
-            let deps_snapshot: std::collections::HashMap<String, Box<dyn Any>> = dependencies
-                .iter()
-                .filter_map(|dep_name| {
-                    // 将依赖从容器暂时移出，供 supplier 闭包使用
-                    self.singleton_objects.remove(dep_name).map(|b| (dep_name.clone(), b))
-                })
-                .collect();
-            let definition = self.bean_definition_map.get(name)?;
-            let inst = definition.create_instance(&deps_snapshot, &self.environment.as_map());
-            // 将依赖放回容器
-            for (dep_name, dep_bean) in deps_snapshot {
-                self.singleton_objects.insert(dep_name, dep_bean);
-            }
-            inst
-        };
-        // BeanPostProcessor: before initialization
-        self.post_processor_registry.apply_before_initialization(name, instance.as_mut());
-        // BeanPostProcessor: after initialization
-        self.post_processor_registry.apply_after_initialization(name, instance.as_mut());
-        match scope {
-            BeanScope::Singleton => {
-                self.singleton_objects.insert(name.to_string(), instance);
-                self.singleton_objects.get(name).map(|b| b.as_ref())
-            }
-            BeanScope::Prototype => {
-                // Prototype 不缓存，每次调用都创建新实例
-                None
-            }
+    fn do_create_bean(&mut self, name: &str) -> Result<Option<&dyn Any>, BeansError> {
+        self.resolve_bean(name)?;
+        self.create_pending_lazy_targets()?;
+        // Prototype 不缓存，每次调用都创建新实例，因此这里取不到
+        Ok(self.singleton_objects.get(name).map(|b| b.as_any()))
+    }
+
+    fn create_prototype(&mut self, name: &str) -> Result<Box<dyn Any>, BeansError> {
+        let definition = self.required_definition(name)?;
+        require_prototype(name, definition.as_ref())?;
+        if self.currently_in_creation.iter().any(|n| n == name) {
+            let mut path = self.currently_in_creation.clone();
+            path.push(name.to_string());
+            return Err(BeansError::CircularReference { path });
         }
+        self.currently_in_creation.push(name.to_string());
+        let created = self.create_instance(name, definition.as_ref());
+        self.currently_in_creation.pop();
+        let instance = created?;
+        self.create_pending_lazy_targets()?;
+        Ok(instance)
     }
 }  // impl BeanFactory
 impl ConfigurableBeanFactory for DefaultListableBeanFactory {
     fn register_singleton(&mut self, bean_name: &str, singleton_object: Box<dyn Any>) {
         self.singleton_objects
-            .insert(bean_name.to_string(), singleton_object);
+            .insert(bean_name.to_string(), BeanWrapper::local(singleton_object));
+        self.registered_singletons.push(bean_name.to_string());
     }
 
     fn destroy_singleton(&mut self, bean_name: &str) {
-        self.singleton_objects.remove(bean_name);
+        self.deferred_singletons.remove(bean_name);
+        self.registered_singletons.retain(|n| n != bean_name);
+        if let Some(wrapper) = self.singleton_objects.remove(bean_name) {
+            self.invoke_destroy_methods(bean_name, &wrapper);
+        }
     }
 
+    /// 按创建顺序倒序销毁：使用方先于它的依赖销毁
     fn destroy_singletons(&mut self) {
+        // 创建器创建、容器尚未接管的 lazy singleton 同样需要销毁回调
+        for (name, bean) in self.deferred_singletons.snapshot() {
+            if !self.singleton_objects.contains_key(&name) {
+                self.singleton_objects.insert(name.clone(), BeanWrapper::Shared(bean));
+                self.registered_singletons.push(name);
+            }
+        }
+        self.deferred_singletons.clear();
+        while let Some(name) = self.registered_singletons.pop() {
+            if let Some(wrapper) = self.singleton_objects.remove(&name) {
+                self.invoke_destroy_methods(&name, &wrapper);
+            }
+        }
         self.singleton_objects.clear();
         self.early_singleton_objects.clear();
         self.singleton_factories.clear();
         self.currently_in_creation.clear();
     }
+
+    fn register_scope(&mut self, scope_name: &str, scope: Arc<dyn Scope>) {
+        assert!(
+            scope_name != "singleton" && scope_name != "prototype",
+            "cannot replace existing scopes 'singleton' and 'prototype'"
+        );
+        self.scopes.insert(scope_name.to_string(), scope);
+    }
+
+    fn get_registered_scope(&self, scope_name: &str) -> Option<Arc<dyn Scope>> {
+        self.scopes.get(scope_name).cloned()
+    }
 }
 
 
@@ -169,8 +192,8 @@ impl ListableBeanFactory for DefaultListableBeanFactory {
 
     fn get_beans_of_type<T: 'static>(&self) -> Vec<&T> {
         self.singleton_objects
-            .iter()
-            .filter_map(|(_, obj)| obj.as_ref().downcast_ref::<T>())
+            .values()
+            .filter_map(|obj| obj.as_any().downcast_ref::<T>())
             .collect::<Vec<_>>()
     }
 
@@ -190,12 +213,347 @@ impl DefaultListableBeanFactory {
             singleton_objects: HashMap::new(),
             early_singleton_objects: HashMap::new(),
             singleton_factories: HashMap::new(),
-            currently_in_creation: HashSet::new(),
+            currently_in_creation: Vec::new(),
+            deferred_singletons: DeferredSingletons::default(),
+            registered_singletons: Vec::new(),
             post_processor_registry: BeanPostProcessorRegistry::new(),
             environment: Environment::new(),
+            scopes: HashMap::new(),
         }
 }
 
+    /// 取出缓存的单例，或按定义创建。Prototype 返回新实例但不缓存。
+    fn resolve_bean(&mut self, name: &str) -> Result<BeanWrapper, BeansError> {
+        if let Some(wrapper) = self.singleton_objects.get(name) {
+            return Ok(wrapper.clone());
+        }
+        let definition = self.required_definition(name)?;
+        let scope = definition.get_scope();
+        if scope == BeanScope::Singleton {
+            if let Some(bean) = self.deferred_singletons.get(name) {
+                return Ok(self.adopt_singleton(name, BeanWrapper::Shared(bean)));
+            }
+        }
+        if self.currently_in_creation.iter().any(|n| n == name) {
+            let mut path = self.currently_in_creation.clone();
+            path.push(name.to_string());
+            return Err(BeansError::CircularReference { path });
+        }
+        self.currently_in_creation.push(name.to_string());
+        if scope == BeanScope::Singleton {
+            let factory = self.singleton_factory(name);
+            self.singleton_factories.insert(name.to_string(), factory);
+        }
+        let created = match scope {
+            BeanScope::Custom(scope_name) => self.required_scope(name, scope_name).and_then(|scope| {
+                get_from_scope(scope.as_ref(), name, definition.get_destruction_callback(), &mut || {
+                    self.create_bean(name, definition.as_ref())
+                })
+            }),
+            _ => self.create_bean(name, definition.as_ref()),
+        };
+        self.currently_in_creation.pop();
+        self.singleton_factories.remove(name);
+        let early = self.early_singleton_objects.remove(name);
+        let mut wrapper = created?;
+        if scope == BeanScope::Singleton {
+            // 创建完成：把已发出的提前引用指向最终实例，再放入一级缓存
+            if let Some(early) = early {
+                if !early.resolve(&wrapper) {
+                    return Err(BeansError::BeanCreation {
+                        bean_name: name.to_string(),
+                        message: "bean is injected as Lazy<T> or ObjectProvider<T> but its type is not Send + Sync".to_string(),
+                        cause: None,
+                    });
+                }
+            }
+            if let (true, BeanWrapper::Shared(bean)) = (definition.is_lazy_init(), &wrapper) {
+                wrapper = BeanWrapper::Shared(self.deferred_singletons.publish(name, bean.clone()));
+            }
+            return Ok(self.adopt_singleton(name, wrapper));
+        }
+        Ok(wrapper)
+    }
+
+    /// 放入一级缓存并登记销毁顺序；未创建时发出的提前引用一并指向它
+    fn adopt_singleton(&mut self, name: &str, wrapper: BeanWrapper) -> BeanWrapper {
+        if let Some(early) = self.early_singleton_objects.remove(name) {
+            early.resolve(&wrapper);
+        }
+        self.singleton_objects.insert(name.to_string(), wrapper.clone());
+        self.registered_singletons.push(name.to_string());
+        wrapper
+    }
+
+    /// 尚未开始创建的 `#[Lazy]` singleton：注入 `ObjectProvider<T>` / `LazyBean<T>` 时不提前创建，
+    /// 留给首次获取
+    fn is_deferrable(&self, name: &str) -> bool {
+        self.bean_definition_map
+            .get(name)
+            .is_some_and(|definition| definition.get_scope() == BeanScope::Singleton && definition.is_lazy_init())
+            && !self.singleton_objects.contains_key(name)
+            && !self.currently_in_creation.iter().any(|n| n == name)
+    }
+
+    /// `Lazy<T>` 依赖：依次查一级缓存（已完成）、二级缓存（已发出的提前引用）、
+    /// 三级缓存（正在创建，由 singleton factory 生成提前引用）；都没有则登记一个待创建的提前引用。
+    /// 未绑定的提前引用在目标创建完成时由 `resolve_bean` 绑定。
+    fn resolve_early(&mut self, name: &str) -> Result<EarlyReference, BeansError> {
+        if let Some(wrapper) = self.singleton_objects.get(name) {
+            return Ok(EarlyReference::resolved(wrapper));
+        }
+        let definition = self.bean_definition_map.get(name).ok_or_else(|| {
+            NoSuchBeanError::NoBeanNamed { name: name.to_string() }
+        })?;
+        if definition.get_scope() != BeanScope::Singleton {
+            return Err(BeansError::BeanCreation {
+                bean_name: name.to_string(),
+                message: "only singleton beans can be injected as Lazy<T>".to_string(),
+                cause: None,
+            });
+        }
+        if let Some(early) = self.early_singleton_objects.get(name) {
+            return Ok(early.clone());
+        }
+        let early = match self.singleton_factories.remove(name) {
+            Some(factory) => factory(),
+            None => self.singleton_factory(name)(),
+        };
+        self.early_singleton_objects.insert(name.to_string(), early.clone());
+        Ok(early)
+    }
+
+    /// 生成提前引用的 singleton factory：新建未绑定的引用，交给后置处理器（如 AOP 代理）加工
+    fn singleton_factory(&self, name: &str) -> Box<dyn Fn() -> EarlyReference> {
+        let registry = self.post_processor_registry.clone();
+        let name = name.to_string();
+        Box::new(move || registry.apply_early_bean_reference(&name, EarlyReference::new()))
+    }
+
+    /// 创建被 `Lazy<T>` 引用、但还没有开始创建的 singleton
+    fn create_pending_lazy_targets(&mut self) -> Result<(), BeansError> {
+        while let Some(name) = self.early_singleton_objects.keys().next().cloned() {
+            if let Err(e) = self.resolve_bean(&name) {
+                self.early_singleton_objects.remove(&name);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    fn create_bean(&mut self, name: &str, definition: &dyn BeanDefinition) -> Result<BeanWrapper, BeansError> {
+        let instance = self.create_instance(name, definition)?;
+        Ok(definition.wrap_instance(instance))
+    }
+
+    fn create_instance(&mut self, name: &str, definition: &dyn BeanDefinition) -> Result<Box<dyn Any>, BeansError> {
+        let unsatisfied = |dep: &String| {
+            let bean_name = name.to_string();
+            let dependency = dep.clone();
+            move |cause| BeansError::UnsatisfiedDependency { bean_name, dependency, cause: Box::new(cause) }
+        };
+        // 先递归创建所有依赖，收集依赖快照：克隆共享指针，注入方与容器持有同一个实例
+        let mut deps_snapshot = ResolvedDependencies::new();
+        for dep in definition.get_dependencies() {
+            let wrapper = self
+                .autowire_target(definition, &dep)
+                .and_then(|target| self.resolve_bean(&target))
+                .map_err(unsatisfied(&dep))?;
+            deps_snapshot.insert(dep, wrapper);
+        }
+        for dep in definition.get_optional_dependencies() {
+            let target = self.autowire_target(definition, &dep).map_err(unsatisfied(&dep))?;
+            if self.contains_bean(&target) {
+                let wrapper = self.resolve_bean(&target).map_err(unsatisfied(&dep))?;
+                deps_snapshot.insert(dep, wrapper);
+            }
+        }
+        for dep in definition.get_lazy_dependencies() {
+            let early = self
+                .autowire_target(definition, &dep)
+                .and_then(|target| self.resolve_early(&target))
+                .map_err(unsatisfied(&dep))?;
+            deps_snapshot.insert_early(dep, early);
+        }
+        for dep in definition.get_provider_dependencies() {
+            let supplier = self
+                .autowire_target(definition, &dep)
+                .and_then(|target| self.provider_supplier(&target))
+                .map_err(unsatisfied(&dep))?;
+            deps_snapshot.insert_provider(dep, supplier);
+        }
+        for dep in definition.get_collection_dependencies() {
+            let candidates = collection_candidates(name, definition, &dep, &self.bean_definition_names, &self.bean_definition_map);
+            let mut entries = Vec::with_capacity(candidates.len());
+            for (candidate, candidate_definition) in candidates {
+                let bean = self.resolve_bean(&candidate).map_err(unsatisfied(&dep))?;
+                entries.push(collection_entry(definition, &dep, &candidate, candidate_definition.as_ref(), bean));
+            }
+            deps_snapshot.insert_collection(dep, entries);
+        }
+        instantiate(name, definition, &deps_snapshot, &self.environment, &self.post_processor_registry)
+    }
+
+    /// `ObjectProvider<T>` / `LazyBean<T>` 依赖：其中的 singleton 以提前引用持有，尚未创建的随后由
+    /// [`Self::create_pending_lazy_targets`] 创建；尚未创建的 lazy singleton 与 prototype 在获取时才创建
+    fn provider_supplier(&mut self, name: &str) -> Result<ObjectSupplier, BeansError> {
+        let closure = self.provider_closure(name)?;
+        let mut singletons = HashMap::new();
+        for singleton in &closure.singletons {
+            let early = self.resolve_early(singleton)?;
+            singletons.insert(singleton.clone(), early);
+        }
+        Ok(self.provider_creator(closure, singletons).supplier(name))
+    }
+
+    /// `#[autowired]` 依赖实际注入的 bean：按类型注入时在候选中选择
+    fn autowire_target(&self, definition: &dyn BeanDefinition, key: &str) -> Result<String, BeansError> {
+        autowire_target(definition, key, &self.bean_definition_names, &self.bean_definition_map)
+    }
+
+    fn provider_closure(&self, name: &str) -> Result<ProviderClosure, BeansError> {
+        provider_closure(name, &self.bean_definition_names, &self.bean_definition_map, |n| {
+            self.is_singleton(n) && !self.is_deferrable(n)
+        })
+    }
+
+    fn provider_creator(&self, closure: ProviderClosure, singletons: HashMap<String, EarlyReference>) -> Arc<ProviderCreator> {
+        ProviderCreator::new(
+            closure,
+            singletons,
+            self.deferred_singletons.clone(),
+            self.scopes.clone(),
+            self.post_processor_registry.clone(),
+            self.environment.clone(),
+        )
+    }
+
+    /// 只需 `&self` 的获取方式，供处理 web 请求等不能修改容器的场景使用：
+    /// singleton 必须已经创建，prototype 与自定义作用域的 bean 按需创建，它们的依赖同样由此解析。
+    pub fn get_bean_wrapper(&self, name: &str) -> Result<BeanWrapper, BeansError> {
+        self.resolve_scoped(name, &mut Vec::new())
+    }
+
+    fn resolve_scoped(&self, name: &str, creating: &mut Vec<String>) -> Result<BeanWrapper, BeansError> {
+        if let Some(wrapper) = self.singleton_objects.get(name) {
+            return Ok(wrapper.clone());
+        }
+        if let Some(bean) = self.deferred_singletons.get(name) {
+            return Ok(BeanWrapper::Shared(bean));
+        }
+        let definition = self.required_definition(name)?;
+        if creating.iter().any(|n| n == name) {
+            let mut path = creating.clone();
+            path.push(name.to_string());
+            return Err(BeansError::CircularReference { path });
+        }
+        match definition.get_scope() {
+            BeanScope::Singleton => Err(NoSuchBeanError::NotCreated { name: name.to_string() }.into()),
+            BeanScope::Prototype => self.create_scoped(name, definition.as_ref(), creating),
+            BeanScope::Custom(scope_name) => {
+                let scope = self.required_scope(name, scope_name)?;
+                get_from_scope(scope.as_ref(), name, definition.get_destruction_callback(), &mut || {
+                    self.create_scoped(name, definition.as_ref(), creating)
+                })
+            }
+        }
+    }
+
+    fn create_scoped(&self, name: &str, definition: &dyn BeanDefinition, creating: &mut Vec<String>) -> Result<BeanWrapper, BeansError> {
+        creating.push(name.to_string());
+        let resolved = self.resolve_scoped_dependencies(name, definition, creating);
+        creating.pop();
+        let instance = instantiate(name, definition, &resolved?, &self.environment, &self.post_processor_registry)?;
+        Ok(definition.wrap_instance(instance))
+    }
+
+    fn resolve_scoped_dependencies(&self, name: &str, definition: &dyn BeanDefinition, creating: &mut Vec<String>) -> Result<ResolvedDependencies, BeansError> {
+        let unsatisfied = |dep: &String| {
+            let bean_name = name.to_string();
+            let dependency = dep.clone();
+            move |cause| BeansError::UnsatisfiedDependency { bean_name, dependency, cause: Box::new(cause) }
+        };
+        let mut deps_snapshot = ResolvedDependencies::new();
+        for dep in definition.get_dependencies() {
+            let wrapper = self
+                .autowire_target(definition, &dep)
+                .and_then(|target| self.resolve_scoped(&target, creating))
+                .map_err(unsatisfied(&dep))?;
+            deps_snapshot.insert(dep, wrapper);
+        }
+        for dep in definition.get_optional_dependencies() {
+            let target = self.autowire_target(definition, &dep).map_err(unsatisfied(&dep))?;
+            if self.contains_bean(&target) {
+                let wrapper = self.resolve_scoped(&target, creating).map_err(unsatisfied(&dep))?;
+                deps_snapshot.insert(dep, wrapper);
+            }
+        }
+        for dep in definition.get_lazy_dependencies() {
+            let early = self
+                .autowire_target(definition, &dep)
+                .and_then(|target| match self.singleton_objects.get(&target) {
+                    Some(wrapper) => Ok(EarlyReference::resolved(wrapper)),
+                    None => Err(NoSuchBeanError::NotCreated { name: target }.into()),
+                })
+                .map_err(unsatisfied(&dep))?;
+            deps_snapshot.insert_early(dep, early);
+        }
+        for dep in definition.get_provider_dependencies() {
+            let supplier = self
+                .autowire_target(definition, &dep)
+                .and_then(|target| self.scoped_provider_supplier(&target))
+                .map_err(unsatisfied(&dep))?;
+            deps_snapshot.insert_provider(dep, supplier);
+        }
+        for dep in definition.get_collection_dependencies() {
+            let candidates = collection_candidates(name, definition, &dep, &self.bean_definition_names, &self.bean_definition_map);
+            let mut entries = Vec::with_capacity(candidates.len());
+            for (candidate, candidate_definition) in candidates {
+                let bean = self.resolve_scoped(&candidate, creating).map_err(unsatisfied(&dep))?;
+                entries.push(collection_entry(definition, &dep, &candidate, candidate_definition.as_ref(), bean));
+            }
+            deps_snapshot.insert_collection(dep, entries);
+        }
+        Ok(deps_snapshot)
+    }
+
+    /// 同 [`Self::provider_supplier`]，但其中的 singleton 必须已经创建
+    fn scoped_provider_supplier(&self, name: &str) -> Result<ObjectSupplier, BeansError> {
+        let closure = self.provider_closure(name)?;
+        let mut singletons = HashMap::new();
+        for singleton in &closure.singletons {
+            let wrapper = self
+                .singleton_objects
+                .get(singleton)
+                .ok_or_else(|| NoSuchBeanError::NotCreated { name: singleton.clone() })?;
+            singletons.insert(singleton.clone(), EarlyReference::resolved(wrapper));
+        }
+        Ok(self.provider_creator(closure, singletons).supplier(name))
+    }
+
+    fn required_definition(&self, name: &str) -> Result<Arc<dyn BeanDefinition>, BeansError> {
+        self.bean_definition_map.get(name).cloned().ok_or_else(|| {
+            NoSuchBeanError::NoBeanNamed { name: name.to_string() }.into()
+        })
+    }
+
+    fn required_scope(&self, name: &str, scope_name: &str) -> Result<Arc<dyn Scope>, BeansError> {
+        self.scopes.get(scope_name).cloned().ok_or_else(|| scope_not_registered(name, scope_name))
+    }
+
+    fn invoke_destroy_methods(&self, name: &str, wrapper: &BeanWrapper) {
+        if let Some(definition) = self.bean_definition_map.get(name) {
+            if let Err(e) = definition.invoke_destroy_methods(wrapper.as_any()) {
+                eprintln!("Destroy method on bean with name '{}' threw an error: {}", name, e);
+            }
+        }
+    }
+
+    /// 以共享指针形式获取单例，便于在容器外长期持有。
+    pub fn get_singleton_wrapper(&self, name: &str) -> Option<&BeanWrapper> {
+        self.singleton_objects.get(name)
+    }
+
     pub fn register_post_processor(&mut self, processor: Box<dyn crate::bean::bean_post_processor::BeanPostProcessor>) {
         self.post_processor_registry.register(processor);
     }
@@ -206,3 +564,468 @@ impl Default for DefaultListableBeanFactory {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bean::bean_wrapper::{ViewProbe, ViewShared as _, WrapProbe, WrapShared as _};
+    use crate::bean::lazy::{Lazy, LazyBean};
+    use crate::bean::provider::ObjectProvider;
+    use crate::factory::config::{DependencyDescriptor, ProvidedType, RootBeanDefinition};
+    use std::any::TypeId;
+    use std::sync::Arc;
+
+    struct Node;
+
+    fn node(name: &str, deps: &[&str]) -> Box<RootBeanDefinition> {
+        Box::new(RootBeanDefinition::new(
+            name.to_string(),
+            TypeId::of::<Node>(),
+            BeanScope::Singleton,
+            false,
+            deps.iter().map(|d| d.to_string()).collect(),
+            Box::new(|_, _| Ok(Box::new(Node) as Box<dyn Any>)),
+            None,
+            WrapProbe::<Node>::new().wrap_fn(),
+        ))
+    }
+
+    #[test]
+    fn test_circular_reference_reports_full_path() {
+        let mut factory = DefaultListableBeanFactory::new();
+        factory.register_bean_definition("a", node("a", &["b"]));
+        factory.register_bean_definition("b", node("b", &["c"]));
+        factory.register_bean_definition("c", node("c", &["a"]));
+
+        let err = factory.do_create_bean("a").err().unwrap();
+        assert!(matches!(err, BeansError::UnsatisfiedDependency { ref dependency, .. } if dependency == "b"));
+        match err.root_cause() {
+            BeansError::CircularReference { path } => assert_eq!(path, &["a", "b", "c", "a"]),
+            other => panic!("unexpected root cause: {}", other),
+        }
+        assert!(factory.get_currently_in_creation().is_empty());
+    }
+
+    struct Service {
+        repo: Arc<Repo>,
+    }
+
+    struct Repo {
+        service: Lazy<Service>,
+    }
+
+    /// service -> repo -> Lazy<service>
+    fn register_cycle(factory: &mut DefaultListableBeanFactory) {
+        let mut service = RootBeanDefinition::new(
+            "service".to_string(),
+            TypeId::of::<Service>(),
+            BeanScope::Singleton,
+            false,
+            vec!["repo".to_string()],
+            Box::new(|deps, _| {
+                let repo = deps.get("repo").and_then(|b| b.downcast_arc::<Repo>()).unwrap();
+                Ok(Box::new(Service { repo }) as Box<dyn Any>)
+            }),
+            None,
+            WrapProbe::<Service>::new().wrap_fn(),
+        );
+        service.set_is_lazy(true);
+        let mut repo = RootBeanDefinition::new(
+            "repo".to_string(),
+            TypeId::of::<Repo>(),
+            BeanScope::Singleton,
+            false,
+            vec![],
+            Box::new(|deps, _| {
+                let service = Lazy::from_early(deps.get_early("service").unwrap().clone());
+                Ok(Box::new(Repo { service }) as Box<dyn Any>)
+            }),
+            None,
+            WrapProbe::<Repo>::new().wrap_fn(),
+        );
+        repo.set_lazy_dependencies(vec!["service".to_string()]);
+        factory.register_bean_definition("service", Box::new(service));
+        factory.register_bean_definition("repo", Box::new(repo));
+    }
+
+    #[test]
+    fn test_lazy_reference_breaks_cycle() {
+        // 两种创建顺序都应成功：先创建持有 Lazy 的一方时，目标随后自动创建
+        for first in ["service", "repo"] {
+            let mut factory = DefaultListableBeanFactory::new();
+            register_cycle(&mut factory);
+            factory.do_create_bean(first).unwrap();
+
+            let service = factory.get_singleton_wrapper("service").unwrap().downcast_arc::<Service>().unwrap();
+            let back = service.repo.service.get().unwrap();
+            assert!(Arc::ptr_eq(&service, &back));
+            assert!(factory.get_early_singleton_objects().is_empty());
+            assert!(factory.get_singleton_factories().is_empty());
+        }
+    }
+
+    struct EarlyReferenceRecorder(Arc<std::sync::Mutex<Vec<String>>>);
+
+    impl crate::bean::BeanPostProcessor for EarlyReferenceRecorder {
+        fn post_process_before_initialization(&self, _bean_name: &str, _bean: &mut dyn Any) {}
+
+        fn post_process_after_initialization(&self, _bean_name: &str, _bean: &mut dyn Any) {}
+
+        fn get_early_bean_reference(&self, bean_name: &str, early: EarlyReference) -> EarlyReference {
+            self.0.lock().unwrap().push(bean_name.to_string());
+            early
+        }
+    }
+
+    #[test]
+    fn test_early_reference_goes_through_singleton_factory() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut factory = DefaultListableBeanFactory::new();
+        factory.register_post_processor(Box::new(EarlyReferenceRecorder(seen.clone())));
+        register_cycle(&mut factory);
+        factory.do_create_bean("service").unwrap();
+
+        // repo 创建时 service 正在创建：提前引用来自三级缓存，只生成一次
+        assert_eq!(*seen.lock().unwrap(), vec!["service".to_string()]);
+        let service = factory.get_singleton_wrapper("service").unwrap().downcast_arc::<Service>().unwrap();
+        assert!(Arc::ptr_eq(&service, &service.repo.service.get().unwrap()));
+        assert!(factory.get_singleton_factories().is_empty());
+    }
+
+    #[test]
+    fn test_missing_bean_and_dependency() {
+        let mut factory = DefaultListableBeanFactory::new();
+        factory.register_bean_definition("a", node("a", &["ghost"]));
+
+        assert!(matches!(
+            factory.do_create_bean("nothing"),
+            Err(BeansError::NoSuchBean(NoSuchBeanError::NoBeanNamed { .. }))
+        ));
+        let err = factory.do_create_bean("a").err().unwrap();
+        assert!(matches!(
+            err.root_cause(),
+            BeansError::NoSuchBean(NoSuchBeanError::NoBeanNamed { name }) if name == "ghost"
+        ));
+    }
+
+    thread_local! {
+        static EVENTS: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    struct Tracked(String);
+
+    fn tracked(name: &str, deps: &[&str]) -> Box<RootBeanDefinition> {
+        let owned = name.to_string();
+        let mut definition = RootBeanDefinition::new(
+            name.to_string(),
+            TypeId::of::<Tracked>(),
+            BeanScope::Singleton,
+            false,
+            deps.iter().map(|d| d.to_string()).collect(),
+            Box::new(move |_, _| Ok(Box::new(Tracked(owned.clone())) as Box<dyn Any>)),
+            None,
+            WrapProbe::<Tracked>::new().wrap_fn(),
+        );
+        definition.set_init_method(Some(|bean| {
+            let bean = bean.downcast_mut::<Tracked>().unwrap();
+            EVENTS.with(|e| e.borrow_mut().push(format!("init:{}", bean.0)));
+            Ok(())
+        }));
+        definition.set_destroy_method(Some(|bean| {
+            let bean = bean.downcast_ref::<Tracked>().unwrap();
+            EVENTS.with(|e| e.borrow_mut().push(format!("destroy:{}", bean.0)));
+            Ok(())
+        }));
+        Box::new(definition)
+    }
+
+    #[test]
+    fn test_init_after_dependencies_and_destroy_in_reverse() {
+        let mut factory = DefaultListableBeanFactory::new();
+        factory.register_bean_definition("web", tracked("web", &["service"]));
+        factory.register_bean_definition("service", tracked("service", &["repo"]));
+        factory.register_bean_definition("repo", tracked("repo", &[]));
+
+        factory.do_create_bean("web").unwrap();
+        factory.destroy_singletons();
+
+        let events = EVENTS.with(|e| e.take());
+        assert_eq!(
+            events,
+            ["init:repo", "init:service", "init:web", "destroy:web", "destroy:service", "destroy:repo"]
+        );
+        assert!(factory.get_singleton_objects().is_empty());
+    }
+
+    struct Job(usize);
+
+    struct Scheduler {
+        jobs: ObjectProvider<Job>,
+    }
+
+    /// 给每个 Job 编号，验证 provider 创建的实例同样经过后置处理器
+    struct JobNumbering(std::sync::atomic::AtomicUsize);
+
+    impl crate::bean::BeanPostProcessor for JobNumbering {
+        fn post_process_before_initialization(&self, _bean_name: &str, _bean: &mut dyn Any) {}
+
+        fn post_process_after_initialization(&self, _bean_name: &str, bean: &mut dyn Any) {
+            if let Some(job) = bean.downcast_mut::<Job>() {
+                job.0 = self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            }
+        }
+    }
+
+    fn register_jobs(factory: &mut DefaultListableBeanFactory) {
+        let job = RootBeanDefinition::new(
+            "job".to_string(),
+            TypeId::of::<Job>(),
+            BeanScope::Prototype,
+            false,
+            vec![],
+            Box::new(|_, _| Ok(Box::new(Job(0)) as Box<dyn Any>)),
+            None,
+            WrapProbe::<Job>::new().wrap_fn(),
+        );
+        let mut scheduler = RootBeanDefinition::new(
+            "scheduler".to_string(),
+            TypeId::of::<Scheduler>(),
+            BeanScope::Singleton,
+            false,
+            vec![],
+            Box::new(|deps, _| {
+                let jobs = ObjectProvider::new(deps.get_provider("job").unwrap().clone());
+                Ok(Box::new(Scheduler { jobs }) as Box<dyn Any>)
+            }),
+            None,
+            WrapProbe::<Scheduler>::new().wrap_fn(),
+        );
+        scheduler.set_provider_dependencies(vec!["job".to_string()]);
+        factory.register_bean_definition("job", Box::new(job));
+        factory.register_bean_definition("scheduler", Box::new(scheduler));
+        factory.register_post_processor(Box::new(JobNumbering(std::sync::atomic::AtomicUsize::new(1))));
+    }
+
+    #[test]
+    fn test_prototypes_are_created_fresh_and_post_processed() {
+        let mut factory = DefaultListableBeanFactory::new();
+        register_jobs(&mut factory);
+
+        let owned = factory.create_prototype("job").unwrap().downcast::<Job>().unwrap();
+        assert_eq!(owned.0, 1);
+        assert!(factory.get_singleton_wrapper("job").is_none());
+
+        factory.do_create_bean("scheduler").unwrap();
+        let scheduler = factory.get_singleton_wrapper("scheduler").unwrap().downcast_arc::<Scheduler>().unwrap();
+        let first = scheduler.jobs.get_object().unwrap();
+        let second = scheduler.jobs.get_object().unwrap();
+        assert_eq!((first.0, second.0), (2, 3));
+        assert!(!Arc::ptr_eq(&first, &second));
+
+        let err = factory.create_prototype("scheduler").err().unwrap();
+        assert!(err.to_string().contains("bean is not a prototype (scope 'singleton')"), "{}", err);
+    }
+
+    struct Store(&'static str);
+
+    struct Client(Arc<Store>);
+
+    fn store(name: &'static str, primary: bool) -> Box<RootBeanDefinition> {
+        let mut definition = RootBeanDefinition::new(
+            name.to_string(),
+            TypeId::of::<Store>(),
+            BeanScope::Singleton,
+            false,
+            vec![],
+            Box::new(move |_, _| Ok(Box::new(Store(name)) as Box<dyn Any>)),
+            None,
+            WrapProbe::<Store>::new().wrap_fn(),
+        );
+        definition.set_primary(primary);
+        Box::new(definition)
+    }
+
+    /// 相当于 `#[autowired] <field>: Arc<Store>`
+    fn client(field: &str) -> Box<RootBeanDefinition> {
+        let key = field.to_string();
+        let mut definition = RootBeanDefinition::new(
+            "client".to_string(),
+            TypeId::of::<Client>(),
+            BeanScope::Singleton,
+            false,
+            vec![key.clone()],
+            Box::new(move |deps, _| {
+                let store = deps.get(&key).and_then(|b| b.downcast_arc::<Store>()).unwrap();
+                Ok(Box::new(Client(store)) as Box<dyn Any>)
+            }),
+            None,
+            WrapProbe::<Client>::new().wrap_fn(),
+        );
+        definition.set_dependency_descriptors(vec![DependencyDescriptor::of::<Store>(field, "store")]);
+        Box::new(definition)
+    }
+
+    fn injected_store(factory: &mut DefaultListableBeanFactory) -> Result<&'static str, BeansError> {
+        factory.do_create_bean("client")?;
+        Ok(factory.get_singleton_wrapper("client").unwrap().downcast_arc::<Client>().unwrap().0 .0)
+    }
+
+    #[test]
+    fn test_autowire_by_type_with_primary_and_field_name() {
+        // 唯一候选：bean 名称与字段名无关
+        let mut factory = DefaultListableBeanFactory::new();
+        factory.register_bean_definition("mysql", store("mysql", false));
+        factory.register_bean_definition("client", client("dataSource"));
+        assert_eq!(injected_store(&mut factory).unwrap(), "mysql");
+
+        // 多个候选时 #[Primary] 优先
+        let mut factory = DefaultListableBeanFactory::new();
+        factory.register_bean_definition("mysql", store("mysql", false));
+        factory.register_bean_definition("postgres", store("postgres", true));
+        factory.register_bean_definition("client", client("dataSource"));
+        assert_eq!(injected_store(&mut factory).unwrap(), "postgres");
+
+        // 没有 primary 时取与字段同名的 bean
+        let mut factory = DefaultListableBeanFactory::new();
+        factory.register_bean_definition("mysql", store("mysql", false));
+        factory.register_bean_definition("postgres", store("postgres", false));
+        factory.register_bean_definition("client", client("postgres"));
+        assert_eq!(injected_store(&mut factory).unwrap(), "postgres");
+    }
+
+    #[test]
+    fn test_ambiguous_autowire_lists_candidates() {
+        let mut factory = DefaultListableBeanFactory::new();
+        factory.register_bean_definition("mysql", store("mysql", false));
+        factory.register_bean_definition("postgres", store("postgres", false));
+        factory.register_bean_definition("client", client("dataSource"));
+        let err = injected_store(&mut factory).err().unwrap();
+        assert!(matches!(err, BeansError::UnsatisfiedDependency { ref dependency, .. } if dependency == "dataSource"));
+        assert_eq!(
+            err.root_cause().to_string(),
+            format!(
+                "No qualifying bean of type '{}' available: expected single matching bean but found 2: mysql, postgres",
+                std::any::type_name::<Store>()
+            )
+        );
+
+        let mut factory = DefaultListableBeanFactory::new();
+        factory.register_bean_definition("mysql", store("mysql", true));
+        factory.register_bean_definition("postgres", store("postgres", true));
+        factory.register_bean_definition("client", client("dataSource"));
+        assert!(matches!(
+            injected_store(&mut factory).err().unwrap().root_cause(),
+            BeansError::NoSuchBean(NoSuchBeanError::NoUniquePrimary { candidates, .. }) if candidates == &["mysql", "postgres"]
+        ));
+    }
+
+    trait Labeled: Send + Sync {
+        fn label(&self) -> &'static str;
+    }
+
+    impl Labeled for Store {
+        fn label(&self) -> &'static str {
+            self.0
+        }
+    }
+
+    /// 相当于 `#[Component(provides = "dyn Labeled")] #[Order(order)]`
+    fn labeled_store(name: &'static str, order: Option<i32>) -> Box<RootBeanDefinition> {
+        let mut definition = store(name, false);
+        definition.set_order(order);
+        definition.set_provided_types(vec![ProvidedType::of::<dyn Labeled>(
+            ViewProbe::<Store>::new().view_fn::<dyn Labeled>(|bean| bean, |bean| bean),
+        )]);
+        definition
+    }
+
+    #[test]
+    fn test_collection_autowire_orders_trait_views() {
+        struct Labels(Vec<&'static str>);
+
+        let mut definition = RootBeanDefinition::new(
+            "labels".to_string(),
+            TypeId::of::<Labels>(),
+            BeanScope::Singleton,
+            false,
+            vec![],
+            Box::new(|deps, _| {
+                let labels = deps
+                    .get_collection("all")
+                    .unwrap()
+                    .iter()
+                    .map(|entry| entry.view::<Arc<dyn Labeled>>().unwrap().label())
+                    .collect();
+                Ok(Box::new(Labels(labels)) as Box<dyn Any>)
+            }),
+            None,
+            WrapProbe::<Labels>::new().wrap_fn(),
+        );
+        definition.set_collection_dependencies(vec!["all".to_string()]);
+        definition.set_dependency_descriptors(vec![DependencyDescriptor::of::<dyn Labeled>("all", "all")]);
+
+        let mut factory = DefaultListableBeanFactory::new();
+        factory.register_bean_definition("last", labeled_store("last", None));
+        factory.register_bean_definition("second", labeled_store("second", Some(2)));
+        factory.register_bean_definition("first", labeled_store("first", Some(1)));
+        // 没有声明 provides 的同类 bean 不会注入到 trait 集合中
+        factory.register_bean_definition("plain", store("plain", false));
+        factory.register_bean_definition("labels", Box::new(definition));
+        factory.do_create_bean("labels").unwrap();
+        let labels = factory.get_singleton_wrapper("labels").unwrap().downcast_arc::<Labels>().unwrap();
+        assert_eq!(labels.0, ["first", "second", "last"]);
+    }
+
+    struct Dashboard {
+        store: Option<Arc<Store>>,
+        report: LazyBean<Store>,
+    }
+
+    /// 相当于 `#[autowired] store: Option<Arc<Store>>` 与 `#[autowired] report: LazyBean<Store>`
+    fn dashboard() -> Box<RootBeanDefinition> {
+        let mut definition = RootBeanDefinition::new(
+            "dashboard".to_string(),
+            TypeId::of::<Dashboard>(),
+            BeanScope::Singleton,
+            false,
+            vec![],
+            Box::new(|deps, _| {
+                let store = deps.get("store").map(|b| b.downcast_arc::<Store>().unwrap());
+                let report = LazyBean::new(deps.get_provider("report").unwrap().clone());
+                Ok(Box::new(Dashboard { store, report }) as Box<dyn Any>)
+            }),
+            None,
+            WrapProbe::<Dashboard>::new().wrap_fn(),
+        );
+        definition.set_optional_dependencies(vec!["store".to_string()]);
+        definition.set_provider_dependencies(vec!["report".to_string()]);
+        Box::new(definition)
+    }
+
+    #[test]
+    fn test_optional_and_lazy_bean_dependencies() {
+        let mut factory = DefaultListableBeanFactory::new();
+        let mut report = store("report", false);
+        report.set_is_lazy(true);
+        factory.register_bean_definition("report", report);
+        factory.register_bean_definition("dashboard", dashboard());
+        factory.do_create_bean("dashboard").unwrap();
+
+        let board = factory.get_singleton_wrapper("dashboard").unwrap().downcast_arc::<Dashboard>().unwrap();
+        assert!(board.store.is_none());
+        // 注入 LazyBean 不会创建 lazy singleton，第一次访问时才创建，之后由容器接管同一个实例
+        assert!(factory.get_singleton_wrapper("report").is_none());
+        let report = board.report.get();
+        assert_eq!(report.0, "report");
+        factory.do_create_bean("report").unwrap();
+        let adopted = factory.get_singleton_wrapper("report").unwrap().downcast_arc::<Store>().unwrap();
+        assert!(Arc::ptr_eq(&report, &adopted));
+
+        factory.register_bean_definition("store", store("mysql", false));
+        factory.register_bean_definition("dashboard", dashboard());
+        factory.destroy_singletons();
+        factory.do_create_bean("dashboard").unwrap();
+        let board = factory.get_singleton_wrapper("dashboard").unwrap().downcast_arc::<Dashboard>().unwrap();
+        assert_eq!(board.store.as_ref().unwrap().0, "mysql");
+    }
+}
@@ -1,6 +1,9 @@
 pub mod bean_definition_registry;
 pub mod bean_name_generator;
+pub mod concurrent_listable_bean_factory;
 pub mod default_listable_bean_factory;
+mod bean_creation;
 pub use bean_definition_registry::BeanDefinitionRegistry;
 pub use bean_name_generator::BeanNameGenerator;
+pub use concurrent_listable_bean_factory::{ConcurrentListableBeanFactory, SharedBean};
 pub use default_listable_bean_factory::DefaultListableBeanFactory;
@@ -3,5 +3,6 @@ pub mod factory;
 pub mod error;
 pub mod registry;
 pub mod env;
+pub mod convert;
 
 
@@ -1,28 +1,132 @@
-use spring_beans::factory::BeanDefinitionRegistry;
-use spring_beans::env::{Environment, PropertiesLoader, MapPropertySource};
-use spring_context::context::support::AbstractApplicationContext;
+use spring_beans::error::BeansError;
+use spring_beans::factory::{BeanDefinitionRegistry, ScopeRegistration};
+use spring_beans::env::{CommandLinePropertySource, ConfigurableEnvironment, Environment, SystemEnvironmentPropertySource};
+use spring_beans::env::configurable_environment::{parse_profiles, ACTIVE_PROFILES_PROPERTY_NAME};
+use spring_context::context::support::{AbstractApplicationContext, ConcurrentApplicationContext};
 use spring_context::context::ConfigurableApplicationContext;
 use spring_beans::bean::bean_post_processor::DefaultBeanPostProcessor;
 use spring_aop::initialize_aop;
 
+use crate::config_data::load_config_documents;
+
 /// Spring Boot 应用入口，对标 Java 的 SpringApplication。
 pub struct Application;
 
 impl Application {
     /// 自动扫描所有 #[Component] bean，注册到容器，refresh 后返回。
     /// 对标 Java 的 SpringApplication.run()。
+    ///
+    /// 任一 bean 创建失败时打印完整的错误链并 panic；需要自行处理错误时用 [`Application::try_run`]。
     pub fn run() -> AbstractApplicationContext {
+        Self::try_run().unwrap_or_else(|e| Self::startup_failed(e))
+    }
+
+    /// 与 [`Application::run`] 相同，但把启动失败作为 `BeansError` 返回。
+    pub fn try_run() -> Result<AbstractApplicationContext, BeansError> {
         let mut context = AbstractApplicationContext::default();
 
         // 先加载环境，供条件过滤使用
+        let environment = Self::load_environment()?;
+        Self::register_definitions(&mut context, &environment);
+
+        context.set_environment(environment);
+
+        // 注册默认的 BeanPostProcessor
+        context.register_post_processor(Box::new(DefaultBeanPostProcessor {}));
+
+        // 注册通过 inventory 提交的作用域（request、session、thread 与自定义作用域）
+        for registration in inventory::iter::<ScopeRegistration> {
+            context.register_scope(registration.name, (registration.scope)());
+        }
+
+        // 初始化 AOP：将所有 inventory 提交的 AspectRegistration 转为 Advisor
+        initialize_aop();
+
+        context.refresh()?;
+        Ok(context)
+    }
+
+    /// 与 [`Application::run`] 相同，但返回线程安全的 [`ConcurrentApplicationContext`]。
+    ///
+    /// 所有 bean 必须是 `Send + Sync`；返回的上下文可以放进 `Arc` 在多个线程间共享。
+    pub fn run_concurrent() -> ConcurrentApplicationContext {
+        Self::try_run_concurrent().unwrap_or_else(|e| Self::startup_failed(e))
+    }
+
+    /// 与 [`Application::run_concurrent`] 相同，但把启动失败作为 `BeansError` 返回。
+    pub fn try_run_concurrent() -> Result<ConcurrentApplicationContext, BeansError> {
+        let mut context = ConcurrentApplicationContext::default();
+
+        let environment = Self::load_environment()?;
+        Self::register_definitions(&mut context, &environment);
+
+        context.set_environment(environment);
+        context.register_post_processor(Box::new(DefaultBeanPostProcessor {}));
+        for registration in inventory::iter::<ScopeRegistration> {
+            context.register_scope(registration.name, (registration.scope)());
+        }
+        initialize_aop();
+
+        context.refresh()?;
+        Ok(context)
+    }
+
+    fn startup_failed(error: BeansError) -> ! {
+        panic!("\n\nAPPLICATION FAILED TO START\n\n{}\n", error.full_message())
+    }
+
+    /// 按 Spring Boot 的优先级组装 property source（从高到低）：
+    ///
+    /// 1. 命令行参数 `--key=value`
+    /// 2. 系统环境变量（`SERVER_PORT` 可以覆盖 `server.port`）
+    /// 3. `application-{profile}.{properties,yml,yaml,toml}`（后激活的 profile 优先）
+    /// 4. `application.yml` 中声明了 `spring.config.activate.on-profile` 且匹配的文档
+    /// 5. `application.{properties,yml,yaml,toml}`
+    ///
+    /// `spring.profiles.active` 本身也按这个顺序解析，例如可以用
+    /// `--spring.profiles.active=dev` 或 `SPRING_PROFILES_ACTIVE=dev` 指定。
+    fn load_environment() -> Result<Environment, BeansError> {
         let mut environment = Environment::new();
-        if let Ok(props) = PropertiesLoader::load("application.properties") {
-            let source = MapPropertySource::new("application.properties", props);
-            environment.merge_from(&source);
+        let sources = environment.get_property_sources_mut();
+        sources.add_last(CommandLinePropertySource::new());
+        sources.add_last(SystemEnvironmentPropertySource::new());
+
+        let (base, conditional): (Vec<_>, Vec<_>) = load_config_documents("application")?
+            .into_iter()
+            .partition(|doc| doc.profiles.is_empty());
+        let highest_base = base.first().map(|doc| doc.name.clone());
+        for doc in base {
+            environment.get_property_sources_mut().add_last(doc.into_property_source());
+        }
+
+        let profiles = environment
+            .get_property(ACTIVE_PROFILES_PROPERTY_NAME)
+            .map(parse_profiles)
+            .unwrap_or_default();
+        environment.set_active_profiles(profiles.clone());
+
+        // 从高到低收集 profile 相关的文档，依次插到基础配置之上
+        let mut profile_documents = Vec::new();
+        for profile in profiles.iter().rev() {
+            profile_documents.extend(load_config_documents(&format!("application-{}", profile))?);
+        }
+        profile_documents.extend(conditional);
+        for doc in profile_documents {
+            if !environment.accepts_profiles(&doc.profiles) {
+                continue;
+            }
+            let sources = environment.get_property_sources_mut();
+            match &highest_base {
+                Some(base) => sources.add_before(base, doc.into_property_source()),
+                None => sources.add_last(doc.into_property_source()),
+            }
         }
+        Ok(environment)
+    }
 
-        // 遍历所有通过 inventory::submit! 注册的 BeanRegistration
-        // 按条件过滤后再注册
+    /// 遍历所有通过 inventory::submit! 注册的 BeanRegistration，
+    /// 按条件过滤后再注册
+    fn register_definitions(registry: &mut dyn BeanDefinitionRegistry, environment: &Environment) {
         for registration in inventory::iter::<spring_beans::registry::BeanRegistration> {
             let definition = (registration.definition)();
 
@@ -34,19 +138,13 @@ impl Application {
                 }
             }
 
+            // 检查 #[Profile] 条件
+            if !environment.accepts_profiles(definition.get_profiles()) {
+                continue;
+            }
+
             let name = definition.get_name().to_string();
-            context.register_bean_definition(&name, Box::new(definition));
+            registry.register_bean_definition(&name, Box::new(definition));
         }
-
-        context.set_environment(environment);
-
-        // 注册默认的 BeanPostProcessor
-        context.register_post_processor(Box::new(DefaultBeanPostProcessor {}));
-
-        // 初始化 AOP：将所有 inventory 提交的 AspectRegistration 转为 Advisor
-        initialize_aop();
-
-        context.refresh();
-        context
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+use spring_beans::env::configurable_environment::parse_profiles;
+use spring_beans::env::{MapPropertySource, PropertiesLoader, TomlLoader, YamlLoader};
+use spring_beans::error::BeansError;
+
+/// 文档级 profile 条件的属性名（Spring Boot 2.4+）
+const ON_PROFILE_PROPERTY: &str = "spring.config.activate.on-profile";
+/// 旧写法，同样支持
+const LEGACY_PROFILES_PROPERTY: &str = "spring.profiles";
+
+/// 一个配置文档：`.properties` / `.toml` 文件，或多文档 YAML 中的一段。
+pub(crate) struct ConfigDocument {
+    pub name: String,
+    /// 文档声明的 profile 条件；为空表示总是生效
+    pub profiles: Vec<String>,
+    pub properties: HashMap<String, String>,
+}
+
+impl ConfigDocument {
+    fn new(name: String, properties: HashMap<String, String>) -> Self {
+        let profiles = [ON_PROFILE_PROPERTY, LEGACY_PROFILES_PROPERTY]
+            .iter()
+            .flat_map(|key| {
+                let indexed = format!("{}[", key);
+                properties
+                    .iter()
+                    .filter(move |(k, _)| k.as_str() == *key || k.starts_with(&indexed))
+                    .flat_map(|(_, v)| parse_profiles(v))
+            })
+            .collect();
+        ConfigDocument { name, profiles, properties }
+    }
+
+    pub fn into_property_source(self) -> MapPropertySource {
+        MapPropertySource::new(self.name, self.properties)
+    }
+}
+
+/// 加载 `{basename}.properties`、`{basename}.yml` / `.yaml`、`{basename}.toml`，
+/// 按优先级从高到低返回：同一位置 `.properties` 优先于 YAML，YAML 优先于 TOML；
+/// 多文档 YAML 中靠后的文档优先。文件不存在时跳过，无法解析时返回错误。
+pub(crate) fn load_config_documents(basename: &str) -> Result<Vec<ConfigDocument>, BeansError> {
+    let mut documents = Vec::new();
+
+    let path = format!("{}.properties", basename);
+    if let Some(properties) = read(&path, |p| PropertiesLoader::load(p))? {
+        documents.push(ConfigDocument::new(path, properties));
+    }
+
+    for extension in ["yml", "yaml"] {
+        let path = format!("{}.{}", basename, extension);
+        if let Some(docs) = read(&path, |p| YamlLoader::load(p))? {
+            let count = docs.len();
+            for (i, properties) in docs.into_iter().enumerate().rev() {
+                let name = if count > 1 { format!("{} (document #{})", path, i + 1) } else { path.clone() };
+                documents.push(ConfigDocument::new(name, properties));
+            }
+        }
+    }
+
+    let path = format!("{}.toml", basename);
+    if let Some(properties) = read(&path, |p| TomlLoader::load(p))? {
+        documents.push(ConfigDocument::new(path, properties));
+    }
+
+    Ok(documents)
+}
+
+fn read<T>(path: &str, loader: impl FnOnce(&str) -> io::Result<T>) -> Result<Option<T>, BeansError> {
+    if !Path::new(path).exists() {
+        return Ok(None);
+    }
+    loader(path).map(Some).map_err(|e| BeansError::ConfigDataLoad {
+        resource: path.to_string(),
+        cause: Box::new(e),
+    })
+}
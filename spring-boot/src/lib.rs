@@ -1,9 +1,12 @@
 pub mod application;
+mod config_data;
 
 pub use application::Application;
 
 // Re-export all proc-macros so users only need `spring-boot` as a dependency.
-pub use spring_macro::{Bean, Component, Lazy, Scope, Value, Aspect, Before, After, Around, AopMethods, ConditionalOnProperty};
+pub use spring_macro::{Autowired, Bean, Component, Lazy, Scope, Value, Aspect, Before, After, Around, AopMethods, ConditionalOnProperty};
+pub use spring_macro::{EventListener, Order, PostConstruct, PreDestroy, Primary, Profile, Qualifier};
+pub use spring_macro::{Bindable, ConfigurationProperties, Validate};
 
 // Re-export AOP interceptor so users can call AopProxyRegistry::fire_before / fire_after
 pub use spring_aop::{AopGuard, AopProxyRegistry, JoinPoint, AspectRegistration, AdviceKind};
@@ -12,6 +15,65 @@ pub use spring_aop::{AopGuard, AopProxyRegistry, JoinPoint, AspectRegistration,
 // without importing spring_context directly.
 pub use spring_context::context::application_context::ApplicationContext;
 
+// Re-export ConfigurableApplicationContext so users can call close() to run destroy callbacks.
+pub use spring_context::context::configurable_application_context::ConfigurableApplicationContext;
+
+// Re-export the Lifecycle trait (start / stop publish ContextStartedEvent / ContextStoppedEvent).
+pub use spring_context::context::lifecycle::Lifecycle;
+
+// Re-export the thread-safe context returned by Application::run_concurrent().
+pub use spring_context::context::support::ConcurrentApplicationContext;
+
+// Re-export the Lazy<T> handle used to break circular #[autowired] dependencies.
+// (Shares its name with the #[Lazy] attribute, which lives in the macro namespace.)
+pub use spring_beans::bean::Lazy;
+
+// Re-export the LazyBean<T> handle: resolves (and creates a #[Lazy] bean) on first access.
+pub use spring_beans::bean::LazyBean;
+
+// Re-export the ObjectProvider<T> / Provider<T> handles: #[autowired] providers hand out
+// a fresh instance of a prototype bean on every get_object() call.
+pub use spring_beans::bean::{ObjectProvider, Provider};
+
+// Re-export the lifecycle callback traits (counterparts of #[PostConstruct] / #[PreDestroy]).
+pub use spring_beans::bean::{DisposableBean, InitializingBean};
+
+// Re-export the scope SPI behind #[Scope("request")] / #[Scope("session")] / #[Scope("thread")].
+// Custom scopes are registered through inventory::submit! { ScopeRegistration { .. } }.
+// (The Scope trait shares its name with the #[Scope] attribute, which lives in the macro namespace.)
+pub use spring_beans::factory::config::{BeanScope, ObjectFactory, Scope, ScopeRegistration, ScopedObjects};
+pub use spring_context::context::support::ThreadScope;
+
+// Re-export the configuration binding API used by #[ConfigurationProperties] / #[derive(Bindable)].
+// (The Bindable trait shares its name with the derive macro, which lives in the macro namespace.)
+pub use spring_beans::env::bind::{Bind, Bindable, BindErrors, Binder, DataSize};
+
+// Re-export the type conversion API used by #[Value], configuration binding and
+// HttpRequest::path_param_as. Custom converters are registered through
+// inventory::submit! { ConverterRegistration { .. } }.
+pub mod convert {
+    pub use spring_beans::convert::{
+        ConversionError, ConversionService, Converter, ConverterRegistration, GenericConversionService,
+    };
+}
+pub use spring_beans::convert::{ConversionError, ConversionService, ConverterRegistration, GenericConversionService};
+
+// Re-export bean validation so #[derive(Validate)] generated code can reference
+// spring_boot::validation::*. (The Validate trait shares its name with the derive macro.)
+pub use spring_context::validation;
+pub use spring_context::validation::{ConstraintViolations, Validate};
+
+// Re-export the event API so #[EventListener] generated code can reference
+// spring_boot::event::* and users can publish their own events.
+pub mod event {
+    pub use spring_context::context::event::{
+        ApplicationEvent, ApplicationEventPublisher, ApplicationListener, ContextClosedEvent,
+        ContextRefreshedEvent, ContextStartedEvent, ContextStoppedEvent, EventListenerRegistration,
+        SimpleApplicationEventMulticaster,
+    };
+}
+pub use spring_context::context::event::{ApplicationEvent, ApplicationEventPublisher};
+
 // Re-export SpEL evaluator so proc-macro generated code can use spring_boot::spel::eval
 // without requiring users to add spring-expression as a direct dependency.
 pub mod spel {
@@ -31,11 +93,20 @@ pub use spring_macro::Repository;
 // spring_boot::web::* and users only need spring-boot as a dependency.
 pub mod web {
     pub use spring_web::{
-        BeanHandlerFn, Handler, HttpMethod, HttpRequest, HttpResponse,
-        HttpServer, PlainHandlerFn, RouteRegistration, Router, StatusCode,
+        AsyncBeanHandlerFn, AsyncPlainHandlerFn, BeanHandlerFn, BodyWriter, FromRequest, Handler,
+        HttpMethod, HttpRequest, HttpResponse, HttpServer, HttpSessions, IntoResponse,
+        PlainHandlerFn, RequestAttributes, RequestContextHolder, RequestLimits, RequestParseError,
+        RequestScope, ResponseError, ResponseFuture, RouteRegistration, Router, ServerProperties, SessionScope, ShutdownHandle,
+        StatusCode,
     };
+    #[cfg(feature = "async")]
+    pub use spring_web::AsyncHttpServer;
+    #[cfg(feature = "serde")]
+    pub use spring_web::Json;
 }
 
 // Re-export web macros and HttpServer at top level for ergonomic use.
 pub use spring_macro::{DeleteMapping, GetMapping, PatchMapping, PostMapping, PutMapping, RestController};
-pub use spring_web::HttpServer;
+pub use spring_web::{HttpServer, ServerProperties, ShutdownHandle};
+#[cfg(feature = "async")]
+pub use spring_web::AsyncHttpServer;
@@ -1,6 +1,51 @@
+use std::any::{Any, TypeId};
+use spring_beans::bean::BeanWrapper;
+use spring_beans::error::{BeansError, NoSuchBeanError};
+use spring_beans::factory::config::determine_autowire_candidate;
+
 pub trait ApplicationContext {
     fn get_bean(&self, name: &str) -> Option<&dyn std::any::Any>;
     fn is_singleton(&self, name: &str) -> bool;
     fn contains_bean(&self, name: &str) -> bool;
-    fn do_create_bean(&mut self, name: &str) -> Option<&dyn std::any::Any>;
-}
\ No newline at end of file
+    /// 创建（或取出已缓存的）bean；Prototype 不缓存，返回 `Ok(None)`
+    fn do_create_bean(&mut self, name: &str) -> Result<Option<&dyn std::any::Any>, BeansError>;
+    /// 新建一个 prototype 实例并交出所有权；bean 不是 prototype 时返回错误
+    fn create_prototype(&mut self, name: &str) -> Result<Box<dyn std::any::Any>, BeansError>;
+    /// 只需 `&self` 的获取方式：singleton 返回已创建的实例；prototype 新建；
+    /// request / session 等自定义作用域的 bean 从对应的 `Scope` 中取出或创建
+    fn get_bean_wrapper(&self, name: &str) -> Result<BeanWrapper, BeansError>;
+    /// 按注册顺序返回 `type_id` 与 BeanDefinition 中记录的类型相同的 bean 名称
+    fn get_bean_names_for_type(&self, type_id: TypeId) -> Vec<String>;
+    /// bean 定义是否标注了 `#[Primary]`
+    fn is_primary(&self, name: &str) -> bool;
+
+    /// 按名称获取 bean 并转换为 `T`；名称不存在、尚未创建或类型不符时返回 `None`
+    fn get_bean_typed<T: Any>(&self, name: &str) -> Option<&T>
+    where
+        Self: Sized,
+    {
+        self.get_bean(name)?.downcast_ref::<T>()
+    }
+
+    /// 按类型获取唯一的 bean；有多个候选时取 `#[Primary]` 的那个，没有或无法确定时返回错误
+    fn get_bean_of_type<T: Any>(&self) -> Result<&T, NoSuchBeanError>
+    where
+        Self: Sized,
+    {
+        let names = self.get_bean_names_for_type(TypeId::of::<T>());
+        let name = determine_autowire_candidate(std::any::type_name::<T>(), names, |n| self.is_primary(n), None)?;
+        self.get_bean_typed::<T>(&name)
+            .ok_or(NoSuchBeanError::NotCreated { name })
+    }
+
+    /// 获取所有已创建的 `T` 类型 bean，按注册顺序返回 (名称, bean)
+    fn get_beans_of_type<T: Any>(&self) -> Vec<(String, &T)>
+    where
+        Self: Sized,
+    {
+        self.get_bean_names_for_type(TypeId::of::<T>())
+            .into_iter()
+            .filter_map(|name| self.get_bean_typed::<T>(&name).map(|bean| (name, bean)))
+            .collect()
+    }
+}
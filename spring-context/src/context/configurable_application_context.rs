@@ -1,8 +1,13 @@
 use super::application_context::ApplicationContext;
 use super::lifecycle::Lifecycle;
+use spring_beans::env::Environment;
+use spring_beans::error::BeansError;
 
 pub trait ConfigurableApplicationContext: ApplicationContext + Lifecycle {
-    fn refresh(&mut self);
+    /// 创建所有非 lazy 的 singleton；任一 bean 创建失败即返回错误
+    fn refresh(&mut self) -> Result<(), BeansError>;
     fn close(&mut self);
     fn is_active(&self) -> bool;
+    /// 容器使用的环境（property source 与 profile），对标 Java 的 `getEnvironment()`
+    fn get_environment(&self) -> &Environment;
 }
\ No newline at end of file
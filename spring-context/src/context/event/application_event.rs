@@ -0,0 +1,19 @@
+use std::any::Any;
+
+/// 对标 Java 的 `ApplicationEvent`：所有可以发布的事件都实现这个 trait。
+///
+/// 事件会在发布者和（异步）监听器线程之间共享，因此必须是 `Send + Sync + 'static`。
+/// 监听器按事件的具体类型过滤，`&dyn ApplicationEvent` 可以直接转成 `&dyn Any` 再 downcast。
+///
+/// ```rust,ignore
+/// #[derive(Debug)]
+/// struct OrderPlaced { order_id: u64 }
+///
+/// impl ApplicationEvent for OrderPlaced {}
+/// ```
+pub trait ApplicationEvent: Any + Send + Sync {
+    /// 事件名称，用于日志
+    fn event_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+}
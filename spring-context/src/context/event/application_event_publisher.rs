@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use super::application_event::ApplicationEvent;
+
+/// 对标 Java 的 `ApplicationEventPublisher`，由应用上下文实现。
+pub trait ApplicationEventPublisher {
+    /// 把事件分发给所有监听该类型的监听器。
+    ///
+    /// 同步监听器在当前线程按注册顺序执行，返回时已经全部执行完毕；
+    /// 标记为 async 的监听器提交到线程池后立即返回。
+    fn publish_event(&self, event: Arc<dyn ApplicationEvent>);
+
+    /// [`ApplicationEventPublisher::publish_event`] 的便捷形式
+    fn publish<E: ApplicationEvent>(&self, event: E)
+    where
+        Self: Sized,
+    {
+        self.publish_event(Arc::new(event));
+    }
+}
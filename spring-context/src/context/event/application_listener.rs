@@ -0,0 +1,31 @@
+use std::any::{Any, TypeId};
+
+use super::application_event::ApplicationEvent;
+
+/// 对标 Java 的 `ApplicationListener<E>`：只接收类型为 `E` 的事件。
+///
+/// 闭包 `Fn(&E)` 自动实现此 trait，可以直接传给 `add_application_listener`。
+pub trait ApplicationListener<E: ApplicationEvent>: Send + Sync {
+    fn on_application_event(&self, event: &E);
+}
+
+impl<E: ApplicationEvent, F: Fn(&E) + Send + Sync> ApplicationListener<E> for F {
+    fn on_application_event(&self, event: &E) {
+        self(event)
+    }
+}
+
+/// `#[EventListener]` 为每个监听方法提交一条记录（通过 `inventory::submit!`），
+/// 应用上下文发布事件时按 bean 类型找到对应的 bean 实例再调用 `handler`。
+pub struct EventListenerRegistration {
+    /// 监听方法所在 bean 的类型
+    pub bean_type: fn() -> TypeId,
+    /// 监听的事件类型，只有该类型的事件会分发给此方法
+    pub event_type: fn() -> TypeId,
+    /// 为 true 时在线程池中执行，发布方不等待
+    pub is_async: bool,
+    /// 调用监听方法；bean 或事件类型不匹配时什么都不做
+    pub handler: fn(&dyn Any, &dyn ApplicationEvent),
+}
+
+inventory::collect!(EventListenerRegistration);
@@ -0,0 +1,51 @@
+use std::time::SystemTime;
+
+use super::application_event::ApplicationEvent;
+
+// 应用上下文自身发布的生命周期事件，对标 Java 的 ContextRefreshedEvent 等。
+
+/// `refresh()` 创建完所有非 lazy 的 singleton 后发布
+#[derive(Debug, Clone)]
+pub struct ContextRefreshedEvent {
+    pub timestamp: SystemTime,
+}
+
+/// `start()` 时发布
+#[derive(Debug, Clone)]
+pub struct ContextStartedEvent {
+    pub timestamp: SystemTime,
+}
+
+/// `stop()` 时发布
+#[derive(Debug, Clone)]
+pub struct ContextStoppedEvent {
+    pub timestamp: SystemTime,
+}
+
+/// `close()` 时、销毁 singleton 之前发布，监听器仍然可以使用其它 bean
+#[derive(Debug, Clone)]
+pub struct ContextClosedEvent {
+    pub timestamp: SystemTime,
+}
+
+macro_rules! context_event {
+    ($($event:ident),*) => {
+        $(
+            impl $event {
+                pub fn new() -> Self {
+                    $event { timestamp: SystemTime::now() }
+                }
+            }
+
+            impl Default for $event {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl ApplicationEvent for $event {}
+        )*
+    };
+}
+
+context_event!(ContextRefreshedEvent, ContextStartedEvent, ContextStoppedEvent, ContextClosedEvent);
@@ -0,0 +1,11 @@
+pub mod application_event;
+pub mod application_event_publisher;
+pub mod application_listener;
+pub mod context_events;
+pub mod simple_application_event_multicaster;
+
+pub use application_event::ApplicationEvent;
+pub use application_event_publisher::ApplicationEventPublisher;
+pub use application_listener::{ApplicationListener, EventListenerRegistration};
+pub use context_events::{ContextClosedEvent, ContextRefreshedEvent, ContextStartedEvent, ContextStoppedEvent};
+pub use simple_application_event_multicaster::SimpleApplicationEventMulticaster;
@@ -0,0 +1,109 @@
+use std::any::{Any, TypeId};
+use std::sync::{Arc, OnceLock};
+
+use spring_beans::bean::BeanWrapper;
+use spring_core::task::{TaskExecutor, ThreadPoolTaskExecutor};
+
+use super::application_event::ApplicationEvent;
+use super::application_listener::{ApplicationListener, EventListenerRegistration};
+
+type ErasedListener = Box<dyn Fn(&dyn ApplicationEvent) + Send + Sync>;
+
+/// 对标 Java 的 `SimpleApplicationEventMulticaster`：保存监听器并负责把事件分发出去。
+///
+/// 监听器有两种来源：
+/// - 通过 [`SimpleApplicationEventMulticaster::add_application_listener`] 手动注册的，总是同步执行；
+/// - `#[EventListener]` 提交到 inventory 的 bean 方法，标记为 async 的在线程池中执行。
+///
+/// 线程池在第一次异步分发时才创建（默认按 CPU 核数），也可以用 `set_task_executor` 替换；
+/// 上下文关闭时通过 [`SimpleApplicationEventMulticaster::shutdown`] 等待它执行完。
+#[derive(Default)]
+pub struct SimpleApplicationEventMulticaster {
+    listeners: Vec<ErasedListener>,
+    task_executor: OnceLock<Arc<dyn TaskExecutor>>,
+}
+
+impl SimpleApplicationEventMulticaster {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册只接收 `E` 类型事件的监听器
+    pub fn add_application_listener<E, L>(&mut self, listener: L)
+    where
+        E: ApplicationEvent,
+        L: ApplicationListener<E> + 'static,
+    {
+        self.listeners.push(Box::new(move |event: &dyn ApplicationEvent| {
+            let event: &dyn Any = event;
+            if let Some(event) = event.downcast_ref::<E>() {
+                listener.on_application_event(event);
+            }
+        }));
+    }
+
+    /// 指定异步监听器使用的执行器
+    pub fn set_task_executor(&mut self, executor: Arc<dyn TaskExecutor>) {
+        self.task_executor = OnceLock::from(executor);
+    }
+
+    /// 分发事件。`beans_of_type` 返回某个类型下所有已创建的 bean，用于找到 `#[EventListener]` 方法的接收者。
+    pub fn multicast_event(
+        &self,
+        event: Arc<dyn ApplicationEvent>,
+        beans_of_type: &dyn Fn(TypeId) -> Vec<BeanWrapper>,
+    ) {
+        for listener in &self.listeners {
+            listener(event.as_ref());
+        }
+
+        let event_type = {
+            let any: &dyn Any = event.as_ref();
+            any.type_id()
+        };
+        for registration in inventory::iter::<EventListenerRegistration> {
+            if (registration.event_type)() != event_type {
+                continue;
+            }
+            for bean in beans_of_type((registration.bean_type)()) {
+                match bean {
+                    BeanWrapper::Shared(bean) if registration.is_async => {
+                        let event = Arc::clone(&event);
+                        let handler = registration.handler;
+                        let task = Box::new(move || {
+                            let bean: &dyn Any = bean.as_ref();
+                            handler(bean, event.as_ref());
+                        });
+                        // 执行器已关闭（上下文关闭之后再发布事件）时退回到当前线程执行
+                        if let Err(rejected) = self.task_executor().execute(task) {
+                            rejected.into_task()();
+                        }
+                    }
+                    // #[EventListener(async)] 要求 bean 是 Send + Sync，所以 Local 只会出现在同步监听器上
+                    bean => (registration.handler)(bean.as_any(), event.as_ref()),
+                }
+            }
+        }
+    }
+
+    /// 关闭异步执行器并等待已提交的监听器执行完毕。之后的异步监听器在发布事件的线程上执行。
+    pub fn shutdown(&self) {
+        if let Some(executor) = self.task_executor.get() {
+            executor.shutdown();
+        }
+    }
+
+    fn task_executor(&self) -> &Arc<dyn TaskExecutor> {
+        self.task_executor
+            .get_or_init(|| Arc::new(ThreadPoolTaskExecutor::with_available_parallelism("event")))
+    }
+}
+
+impl std::fmt::Debug for SimpleApplicationEventMulticaster {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SimpleApplicationEventMulticaster")
+            .field("listeners", &self.listeners.len())
+            .field("has_task_executor", &self.task_executor.get().is_some())
+            .finish()
+    }
+}
@@ -3,4 +3,5 @@ pub mod web_application_context;
 pub mod support;
 pub mod lifecycle;
 pub mod configurable_application_context;
+pub mod event;
 pub use configurable_application_context::ConfigurableApplicationContext;
@@ -1,46 +1,76 @@
 use crate::context::configurable_application_context::ConfigurableApplicationContext;
 use spring_beans::factory::{BeanFactory, BeanDefinitionRegistry, DefaultListableBeanFactory};
-use spring_beans::factory::config::{BeanDefinition, BeanScope, ConfigurableBeanFactory};
+use spring_beans::factory::config::{BeanDefinition, BeanScope, ConfigurableBeanFactory, Scope};
 use crate::context::application_context::ApplicationContext;
 use crate::context::lifecycle::{Lifecycle};
+use crate::context::event::{
+    ApplicationEvent, ApplicationEventPublisher, ApplicationListener, ContextClosedEvent, ContextRefreshedEvent,
+    ContextStartedEvent, ContextStoppedEvent, SimpleApplicationEventMulticaster,
+};
+use spring_beans::bean::BeanWrapper;
+use spring_beans::error::BeansError;
+use std::sync::Arc;
 use spring_macro::data;
 
 #[data]
 pub struct AbstractApplicationContext {
     bean_factory: DefaultListableBeanFactory,
+    event_multicaster: SimpleApplicationEventMulticaster,
+    active: bool,
+    running: bool,
 }
 
 impl ConfigurableApplicationContext for AbstractApplicationContext {
-   fn refresh(&mut self) {
+   fn refresh(&mut self) -> Result<(), BeansError> {
        let names = self.bean_factory.get_bean_definition_names().clone();
        for name in names {
            if let Some(definition) = self.bean_factory.get_bean_definition(&name) {
                if !definition.is_lazy_init() && definition.get_scope() == BeanScope::Singleton {
-                   self.bean_factory.do_create_bean(&name);
+                   self.bean_factory.do_create_bean(&name)?;
                }
            }
        }
+       self.active = true;
+       self.publish(ContextRefreshedEvent::new());
+       Ok(())
    }
 
    fn close(&mut self) {
+       if !self.active {
+           return;
+       }
+       // 先发布 ContextClosedEvent，监听器此时仍可使用其它 bean
+       self.publish(ContextClosedEvent::new());
+       // 等异步监听器执行完再销毁它们可能用到的 bean
+       self.event_multicaster.shutdown();
+       self.running = false;
+       self.active = false;
        self.bean_factory.destroy_singletons();
    }
 
    fn is_active(&self) -> bool {
-       true
+       self.active
+   }
+
+   fn get_environment(&self) -> &spring_beans::env::Environment {
+       self.bean_factory.get_environment()
    }
 
 }
 
 impl Lifecycle for AbstractApplicationContext {
     fn start(&mut self) {
+        self.running = true;
+        self.publish(ContextStartedEvent::new());
     }
 
     fn stop(&mut self) {
+        self.running = false;
+        self.publish(ContextStoppedEvent::new());
     }
 
     fn is_running(&self) -> bool {
-        true
+        self.running
     }
 }
 
@@ -49,10 +79,14 @@ impl ApplicationContext for AbstractApplicationContext {
        self.bean_factory.contains_bean(name)
    }
 
-   fn do_create_bean(&mut self, name: &str) -> Option<&dyn std::any::Any> {
+   fn do_create_bean(&mut self, name: &str) -> Result<Option<&dyn std::any::Any>, BeansError> {
        self.bean_factory.do_create_bean(name)
    }
 
+   fn create_prototype(&mut self, name: &str) -> Result<Box<dyn std::any::Any>, BeansError> {
+       self.bean_factory.create_prototype(name)
+   }
+
    fn get_bean(&self, name: &str) -> Option<&dyn std::any::Any> {
        self.bean_factory.get_bean(name)
    }
@@ -60,6 +94,37 @@ impl ApplicationContext for AbstractApplicationContext {
    fn is_singleton(&self, name: &str) -> bool {
        self.bean_factory.is_singleton(name)
    }
+
+   fn get_bean_wrapper(&self, name: &str) -> Result<BeanWrapper, BeansError> {
+       self.bean_factory.get_bean_wrapper(name)
+   }
+
+   fn get_bean_names_for_type(&self, type_id: std::any::TypeId) -> Vec<String> {
+       let registry: &dyn BeanDefinitionRegistry = &self.bean_factory;
+       registry.get_bean_definition_names()
+           .iter()
+           .filter(|name| registry.get_bean_definition(name).is_some_and(|bd| bd.get_type_id() == type_id))
+           .cloned()
+           .collect()
+   }
+
+   fn is_primary(&self, name: &str) -> bool {
+       let registry: &dyn BeanDefinitionRegistry = &self.bean_factory;
+       registry.get_bean_definition(name).is_some_and(|bd| bd.is_primary())
+   }
+}
+
+/// 只有已创建的 singleton 会收到 `#[EventListener]` 事件；尚未初始化的 lazy bean 不会因为事件而被创建
+impl ApplicationEventPublisher for AbstractApplicationContext {
+    fn publish_event(&self, event: Arc<dyn ApplicationEvent>) {
+        let beans_of_type = |type_id| {
+            self.get_bean_names_for_type(type_id)
+                .iter()
+                .filter_map(|name| self.bean_factory.get_singleton_wrapper(name).cloned())
+                .collect::<Vec<BeanWrapper>>()
+        };
+        self.event_multicaster.multicast_event(event, &beans_of_type);
+    }
 }
 
 impl BeanDefinitionRegistry for AbstractApplicationContext {
@@ -75,7 +140,7 @@ impl BeanDefinitionRegistry for AbstractApplicationContext {
         self.bean_factory.contains_bean_definition(bean_name)
     }
 
-    fn get_bean_definition(&self, bean_name: &str) -> Option<&Box<dyn BeanDefinition>> {
+    fn get_bean_definition(&self, bean_name: &str) -> Option<&dyn BeanDefinition> {
         self.bean_factory.get_bean_definition(bean_name)
     }
 
@@ -96,6 +161,9 @@ impl Default for AbstractApplicationContext {
     fn default() -> Self {
         Self {
             bean_factory: DefaultListableBeanFactory::new(),
+            event_multicaster: SimpleApplicationEventMulticaster::new(),
+            active: false,
+            running: false,
         }
     }
 }
@@ -106,4 +174,143 @@ impl AbstractApplicationContext {
     pub fn set_environment(&mut self, environment: spring_beans::env::Environment) {
         self.bean_factory.set_environment(environment);
     }
+    /// 按名称注册作用域，`#[Scope("name")]` 的 bean 从中获取
+    pub fn register_scope(&mut self, scope_name: &str, scope: Arc<dyn Scope>) {
+        self.bean_factory.register_scope(scope_name, scope);
+    }
+    /// 注册只接收 `E` 类型事件的监听器，对标 Java 的 `addApplicationListener`
+    pub fn add_application_listener<E: ApplicationEvent, L: ApplicationListener<E> + 'static>(&mut self, listener: L) {
+        self.event_multicaster.add_application_listener(listener);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spring_beans::bean::bean_wrapper::{WrapProbe, WrapShared as _};
+    use spring_beans::error::NoSuchBeanError;
+    use spring_beans::factory::RootBeanDefinition;
+    use crate::context::event::{ContextClosedEvent, ContextRefreshedEvent};
+    use std::any::{Any, TypeId};
+
+    #[derive(Debug, PartialEq)]
+    struct Greeter(&'static str);
+
+    struct Clock;
+
+    fn greeter_definition(name: &str, text: &'static str) -> RootBeanDefinition {
+        RootBeanDefinition::new(
+            name.to_string(),
+            TypeId::of::<Greeter>(),
+            BeanScope::Singleton,
+            false,
+            vec![],
+            Box::new(move |_, _| Ok(Box::new(Greeter(text)) as Box<dyn Any>)),
+            None,
+            WrapProbe::<Greeter>::new().wrap_fn(),
+        )
+    }
+
+    #[test]
+    fn test_typed_lookup() {
+        let mut context = AbstractApplicationContext::default();
+        context.register_bean_definition("hello", Box::new(greeter_definition("hello", "hello")));
+        context.refresh().unwrap();
+
+        assert_eq!(context.get_bean_typed::<Greeter>("hello"), Some(&Greeter("hello")));
+        assert!(context.get_bean_typed::<Clock>("hello").is_none());
+        assert_eq!(context.get_bean_of_type::<Greeter>(), Ok(&Greeter("hello")));
+        assert!(matches!(
+            context.get_bean_of_type::<Clock>(),
+            Err(NoSuchBeanError::NoBeanOfType { .. })
+        ));
+
+        context.register_bean_definition("hi", Box::new(greeter_definition("hi", "hi")));
+        context.refresh().unwrap();
+        assert_eq!(
+            context.get_bean_of_type::<Greeter>(),
+            Err(NoSuchBeanError::NoUniqueBean {
+                type_name: std::any::type_name::<Greeter>().to_string(),
+                candidates: vec!["hello".to_string(), "hi".to_string()],
+            })
+        );
+        let all: Vec<&str> = context.get_beans_of_type::<Greeter>().into_iter().map(|(_, g)| g.0).collect();
+        assert_eq!(all, vec!["hello", "hi"]);
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        seen: std::sync::Mutex<Vec<String>>,
+    }
+
+    struct Ping(u32);
+    impl ApplicationEvent for Ping {}
+
+    struct Pong;
+    impl ApplicationEvent for Pong {}
+
+    inventory::submit! {
+        crate::context::event::EventListenerRegistration {
+            bean_type: || TypeId::of::<Recorder>(),
+            event_type: || TypeId::of::<Ping>(),
+            is_async: false,
+            handler: |bean, event| {
+                let event: &dyn Any = event;
+                let (bean, ping) = (bean.downcast_ref::<Recorder>().unwrap(), event.downcast_ref::<Ping>().unwrap());
+                bean.seen.lock().unwrap().push(format!("ping {}", ping.0));
+            },
+        }
+    }
+
+    inventory::submit! {
+        crate::context::event::EventListenerRegistration {
+            bean_type: || TypeId::of::<Recorder>(),
+            event_type: || TypeId::of::<Pong>(),
+            is_async: true,
+            handler: |bean, _| {
+                let thread = std::thread::current().name().unwrap_or("").to_string();
+                bean.downcast_ref::<Recorder>().unwrap().seen.lock().unwrap().push(format!("pong on {}", thread));
+            },
+        }
+    }
+
+    #[test]
+    fn test_publish_events() {
+        let mut context = AbstractApplicationContext::default();
+        context.register_bean_definition("recorder", Box::new(RootBeanDefinition::new(
+            "recorder".to_string(),
+            TypeId::of::<Recorder>(),
+            BeanScope::Singleton,
+            false,
+            vec![],
+            Box::new(|_, _| Ok(Box::new(Recorder::default()) as Box<dyn Any>)),
+            None,
+            WrapProbe::<Recorder>::new().wrap_fn(),
+        )));
+        let lifecycle = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = Arc::clone(&lifecycle);
+        context.add_application_listener(move |_: &ContextRefreshedEvent| log.lock().unwrap().push("refreshed"));
+        let log = Arc::clone(&lifecycle);
+        context.add_application_listener(move |_: &ContextClosedEvent| log.lock().unwrap().push("closed"));
+
+        context.refresh().unwrap();
+        assert!(context.is_active());
+        let recorder = context.get_bean_factory().get_singleton_wrapper("recorder").unwrap().downcast_arc::<Recorder>().unwrap();
+
+        context.publish(Ping(1));
+        context.publish(Pong);
+        context.publish(Ping(2));
+        context.close();
+        context.close();
+        assert!(!context.is_active());
+
+        // close 返回前异步监听器已经执行完；它与同步监听器的先后顺序不确定
+        assert_eq!(*lifecycle.lock().unwrap(), ["refreshed", "closed"]);
+        let seen = recorder.seen.lock().unwrap().clone();
+        assert_eq!(seen.len(), 3);
+        let pings: Vec<_> = seen.iter().filter(|s| s.starts_with("ping")).collect();
+        assert_eq!(pings, ["ping 1", "ping 2"]);
+        let pong = seen.iter().find(|s| s.starts_with("pong")).unwrap();
+        assert!(pong.starts_with("pong on event-"), "{}", pong);
+    }
 }
@@ -0,0 +1,194 @@
+use crate::context::configurable_application_context::ConfigurableApplicationContext;
+use spring_beans::factory::{BeanFactory, BeanDefinitionRegistry, ConcurrentListableBeanFactory, SharedBean};
+use spring_beans::factory::config::{BeanDefinition, BeanScope, ConfigurableBeanFactory, Scope};
+use crate::context::application_context::ApplicationContext;
+use crate::context::lifecycle::{Lifecycle};
+use crate::context::event::{
+    ApplicationEvent, ApplicationEventPublisher, ApplicationListener, ContextClosedEvent, ContextRefreshedEvent,
+    ContextStartedEvent, ContextStoppedEvent, SimpleApplicationEventMulticaster,
+};
+use spring_beans::bean::BeanWrapper;
+use spring_beans::error::BeansError;
+use std::sync::Arc;
+use spring_macro::data;
+
+/// 线程安全的应用上下文。
+///
+/// 与 `AbstractApplicationContext` 的用法相同，但所有 bean 以 `Arc` 存放，
+/// 上下文本身是 `Send + Sync`：`refresh()` 之后可以放进 `Arc` 交给多个工作线程，
+/// 通过 [`ConcurrentApplicationContext::get_or_create_bean`] 并发获取（包括 lazy 单例）。
+#[data]
+pub struct ConcurrentApplicationContext {
+    bean_factory: ConcurrentListableBeanFactory,
+    event_multicaster: SimpleApplicationEventMulticaster,
+    active: bool,
+    running: bool,
+}
+
+impl ConfigurableApplicationContext for ConcurrentApplicationContext {
+   fn refresh(&mut self) -> Result<(), BeansError> {
+       let names = self.bean_factory.get_bean_definition_names().clone();
+       for name in names {
+           if let Some(definition) = self.bean_factory.get_bean_definition(&name) {
+               if !definition.is_lazy_init() && definition.get_scope() == BeanScope::Singleton {
+                   self.bean_factory.get_or_create_bean(&name)?;
+               }
+           }
+       }
+       self.active = true;
+       self.publish(ContextRefreshedEvent::new());
+       Ok(())
+   }
+
+   fn close(&mut self) {
+       if !self.active {
+           return;
+       }
+       // 先发布 ContextClosedEvent，监听器此时仍可使用其它 bean
+       self.publish(ContextClosedEvent::new());
+       // 等异步监听器执行完再销毁它们可能用到的 bean
+       self.event_multicaster.shutdown();
+       self.running = false;
+       self.active = false;
+       self.bean_factory.destroy_singletons();
+   }
+
+   fn is_active(&self) -> bool {
+       self.active
+   }
+
+   fn get_environment(&self) -> &spring_beans::env::Environment {
+       self.bean_factory.get_environment()
+   }
+}
+
+impl Lifecycle for ConcurrentApplicationContext {
+    fn start(&mut self) {
+        self.running = true;
+        self.publish(ContextStartedEvent::new());
+    }
+
+    fn stop(&mut self) {
+        self.running = false;
+        self.publish(ContextStoppedEvent::new());
+    }
+
+    fn is_running(&self) -> bool {
+        self.running
+    }
+}
+
+impl ApplicationContext for ConcurrentApplicationContext {
+   fn contains_bean(&self, name: &str) -> bool {
+       self.bean_factory.contains_bean(name)
+   }
+
+   fn do_create_bean(&mut self, name: &str) -> Result<Option<&dyn std::any::Any>, BeansError> {
+       self.bean_factory.do_create_bean(name)
+   }
+
+   fn create_prototype(&mut self, name: &str) -> Result<Box<dyn std::any::Any>, BeansError> {
+       self.bean_factory.create_prototype(name)
+   }
+
+   fn get_bean(&self, name: &str) -> Option<&dyn std::any::Any> {
+       self.bean_factory.get_bean(name)
+   }
+
+   fn is_singleton(&self, name: &str) -> bool {
+       self.bean_factory.is_singleton(name)
+   }
+
+   fn get_bean_wrapper(&self, name: &str) -> Result<BeanWrapper, BeansError> {
+       self.bean_factory.get_or_create_bean(name).map(BeanWrapper::Shared)
+   }
+
+   fn get_bean_names_for_type(&self, type_id: std::any::TypeId) -> Vec<String> {
+       let registry: &dyn BeanDefinitionRegistry = &self.bean_factory;
+       registry.get_bean_definition_names()
+           .iter()
+           .filter(|name| registry.get_bean_definition(name).is_some_and(|bd| bd.get_type_id() == type_id))
+           .cloned()
+           .collect()
+   }
+
+   fn is_primary(&self, name: &str) -> bool {
+       let registry: &dyn BeanDefinitionRegistry = &self.bean_factory;
+       registry.get_bean_definition(name).is_some_and(|bd| bd.is_primary())
+   }
+}
+
+/// 只有已创建的 singleton 会收到 `#[EventListener]` 事件；尚未初始化的 lazy bean 不会因为事件而被创建
+impl ApplicationEventPublisher for ConcurrentApplicationContext {
+    fn publish_event(&self, event: Arc<dyn ApplicationEvent>) {
+        let beans_of_type = |type_id| {
+            self.get_bean_names_for_type(type_id)
+                .iter()
+                .filter_map(|name| self.bean_factory.get_singleton(name).map(BeanWrapper::Shared))
+                .collect::<Vec<BeanWrapper>>()
+        };
+        self.event_multicaster.multicast_event(event, &beans_of_type);
+    }
+}
+
+impl BeanDefinitionRegistry for ConcurrentApplicationContext {
+    fn register_bean_definition(&mut self, name: &str, bean_definition: Box<dyn BeanDefinition>) {
+        self.bean_factory.register_bean_definition(name, bean_definition);
+    }
+
+    fn remove_bean_definition(&mut self, bean_name: &str) {
+        self.bean_factory.remove_bean_definition(bean_name);
+    }
+
+    fn contains_bean_definition(&self, bean_name: &str) -> bool {
+        self.bean_factory.contains_bean_definition(bean_name)
+    }
+
+    fn get_bean_definition(&self, bean_name: &str) -> Option<&dyn BeanDefinition> {
+        self.bean_factory.get_bean_definition(bean_name)
+    }
+
+    fn get_bean_definition_names(&self) -> &Vec<String> {
+        self.bean_factory.get_bean_definition_names()
+    }
+
+    fn get_bean_definition_count(&self) -> usize {
+        self.bean_factory.get_bean_definition_count()
+    }
+
+    fn is_bean_name_in_use(&self, bean_name: &str) -> bool {
+        self.bean_factory.is_bean_name_in_use(bean_name)
+    }
+}
+
+impl Default for ConcurrentApplicationContext {
+    fn default() -> Self {
+        Self {
+            bean_factory: ConcurrentListableBeanFactory::new(),
+            event_multicaster: SimpleApplicationEventMulticaster::new(),
+            active: false,
+            running: false,
+        }
+    }
+}
+
+impl ConcurrentApplicationContext {
+    /// 获取 bean 的共享指针，必要时创建；可在任意线程中调用
+    pub fn get_or_create_bean(&self, name: &str) -> Result<SharedBean, BeansError> {
+        self.bean_factory.get_or_create_bean(name)
+    }
+    pub fn register_post_processor(&mut self, processor: Box<dyn spring_beans::bean::bean_post_processor::BeanPostProcessor>) {
+        self.bean_factory.register_post_processor(processor);
+    }
+    pub fn set_environment(&mut self, environment: spring_beans::env::Environment) {
+        self.bean_factory.set_environment(environment);
+    }
+    /// 按名称注册作用域，`#[Scope("name")]` 的 bean 从中获取
+    pub fn register_scope(&mut self, scope_name: &str, scope: Arc<dyn Scope>) {
+        self.bean_factory.register_scope(scope_name, scope);
+    }
+    /// 注册只接收 `E` 类型事件的监听器，对标 Java 的 `addApplicationListener`
+    pub fn add_application_listener<E: ApplicationEvent, L: ApplicationListener<E> + 'static>(&mut self, listener: L) {
+        self.event_multicaster.add_application_listener(listener);
+    }
+}
@@ -14,7 +14,7 @@ impl BeanDefinitionRegistry for GenericApplicationContext{
         self.bean_factory.contains_bean_definition(bean_name)
     }
 
-    fn get_bean_definition(&self, bean_name: &str) -> Option<&Box<dyn spring_beans::factory::BeanDefinition>> {
+    fn get_bean_definition(&self, bean_name: &str) -> Option<&dyn spring_beans::factory::BeanDefinition> {
         self.bean_factory.get_bean_definition(bean_name)
     }
 
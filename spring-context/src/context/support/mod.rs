@@ -1,4 +1,8 @@
 pub mod generic_application_context;
 pub mod abstract_application_context;
+pub mod concurrent_application_context;
+pub mod thread_scope;
 pub use abstract_application_context::AbstractApplicationContext;
-pub use generic_application_context::GenericApplicationContext;
\ No newline at end of file
+pub use concurrent_application_context::ConcurrentApplicationContext;
+pub use generic_application_context::GenericApplicationContext;
+pub use thread_scope::ThreadScope;
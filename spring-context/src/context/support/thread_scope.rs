@@ -0,0 +1,116 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use spring_beans::bean::lifecycle::DestroyFn;
+use spring_beans::bean::BeanWrapper;
+use spring_beans::error::BeansError;
+use spring_beans::factory::config::{ObjectFactory, Scope, ScopeRegistration, ScopedObjects};
+
+/// 线程销毁时调用作用域中对象的销毁回调
+struct ThreadObjects(ScopedObjects);
+
+impl Drop for ThreadObjects {
+    fn drop(&mut self) {
+        self.0.destroy();
+    }
+}
+
+thread_local! {
+    static THREAD_OBJECTS: RefCell<ThreadObjects> = RefCell::new(ThreadObjects(ScopedObjects::new()));
+}
+
+/// `#[Scope("thread")]`：每个线程一个实例，对标 Spring 的 `SimpleThreadScope`。
+///
+/// 与 Spring 不同，销毁回调会在线程结束时执行；主线程的 thread-local 在进程退出时
+/// 不一定被销毁，因此不要依赖主线程上的 `#[PreDestroy]`。
+#[derive(Debug, Default)]
+pub struct ThreadScope;
+
+impl Scope for ThreadScope {
+    fn get(&self, name: &str, object_factory: &mut ObjectFactory<'_>) -> Result<BeanWrapper, BeansError> {
+        if let Some(object) = THREAD_OBJECTS.with(|objects| objects.borrow().0.get(name)) {
+            return Ok(object);
+        }
+        // 创建期间不持有借用：object_factory 可能获取同一线程中的其它 thread 作用域 bean
+        let object = object_factory()?;
+        THREAD_OBJECTS.with(|objects| objects.borrow_mut().0.insert(name, object.clone()));
+        Ok(object)
+    }
+
+    fn remove(&self, name: &str) -> Option<BeanWrapper> {
+        THREAD_OBJECTS.with(|objects| objects.borrow_mut().0.remove(name))
+    }
+
+    fn register_destruction_callback(&self, name: &str, callback: DestroyFn) {
+        THREAD_OBJECTS.with(|objects| objects.borrow_mut().0.register_destruction_callback(name, callback));
+    }
+
+    fn get_conversation_id(&self) -> Option<String> {
+        std::thread::current().name().map(str::to_string)
+    }
+}
+
+inventory::submit! {
+    ScopeRegistration { name: "thread", scope: || Arc::new(ThreadScope) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::support::ConcurrentApplicationContext;
+    use crate::context::application_context::ApplicationContext;
+    use crate::context::configurable_application_context::ConfigurableApplicationContext;
+    use spring_beans::bean::bean_wrapper::{WrapProbe, WrapShared as _};
+    use spring_beans::factory::config::BeanScope;
+    use spring_beans::factory::{BeanDefinitionRegistry, RootBeanDefinition};
+    use std::any::{Any, TypeId};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Buffer(usize);
+
+    static CREATED: AtomicUsize = AtomicUsize::new(0);
+    static DESTROYED: AtomicUsize = AtomicUsize::new(0);
+
+    fn buffer_definition() -> RootBeanDefinition {
+        let mut definition = RootBeanDefinition::new(
+            "buffer".to_string(),
+            TypeId::of::<Buffer>(),
+            BeanScope::Custom("thread"),
+            false,
+            vec![],
+            Box::new(|_, _| Ok(Box::new(Buffer(CREATED.fetch_add(1, Ordering::SeqCst))) as Box<dyn Any>)),
+            None,
+            WrapProbe::<Buffer>::new().wrap_fn(),
+        );
+        definition.set_destroy_method(Some(|_| {
+            DESTROYED.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }));
+        definition
+    }
+
+    #[test]
+    fn test_one_instance_per_thread() {
+        let mut context = ConcurrentApplicationContext::default();
+        context.register_bean_definition("buffer", Box::new(buffer_definition()));
+        let err = context.get_bean_wrapper("buffer").err().unwrap();
+        assert!(err.to_string().contains("no Scope registered for scope name 'thread'"), "{}", err);
+
+        context.register_scope("thread", Arc::new(ThreadScope));
+        context.refresh().unwrap();
+        assert!(context.get_bean("buffer").is_none());
+
+        let context = Arc::new(context);
+        let buffer_id = |context: &ConcurrentApplicationContext| {
+            context.get_bean_wrapper("buffer").unwrap().downcast_arc::<Buffer>().unwrap().0
+        };
+        let first = buffer_id(&context);
+        assert_eq!(buffer_id(&context), first);
+        let shared = Arc::clone(&context);
+        let other = std::thread::spawn(move || (buffer_id(&shared), buffer_id(&shared))).join().unwrap();
+        assert_eq!(other.0, other.1);
+        assert_ne!(other.0, first);
+        // 子线程结束时销毁了它的实例
+        assert_eq!(DESTROYED.load(Ordering::SeqCst), 1);
+    }
+}
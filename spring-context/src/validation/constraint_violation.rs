@@ -0,0 +1,125 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// 一条约束违规：字段路径（如 `pool.max_size`、`items[0].name`）、约束名、提示信息与被拒绝的值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintViolation {
+    pub property_path: String,
+    /// 约束注解名，如 `"NotBlank"`、`"Size"`
+    pub constraint: &'static str,
+    pub message: String,
+    pub invalid_value: Option<String>,
+}
+
+impl Display for ConstraintViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.property_path, self.message)?;
+        if let Some(value) = &self.invalid_value {
+            write!(f, " (rejected value '{}')", value)?;
+        }
+        Ok(())
+    }
+}
+
+/// `#[derive(Validate)]` 校验后得到的全部违规，为空表示校验通过
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConstraintViolations {
+    violations: Vec<ConstraintViolation>,
+}
+
+impl ConstraintViolations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(
+        &mut self,
+        property_path: impl Into<String>,
+        constraint: &'static str,
+        message: impl Into<String>,
+        invalid_value: Option<String>,
+    ) {
+        self.violations.push(ConstraintViolation {
+            property_path: property_path.into(),
+            constraint,
+            message: message.into(),
+            invalid_value,
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.violations.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ConstraintViolation> {
+        self.violations.iter()
+    }
+
+    /// 某个字段上的违规
+    pub fn for_property<'a>(&'a self, property_path: &'a str) -> impl Iterator<Item = &'a ConstraintViolation> {
+        self.violations.iter().filter(move |v| v.property_path == property_path)
+    }
+
+    /// 序列化为 JSON 数组：`[{"field":"name","constraint":"NotBlank","message":"...","rejectedValue":""}]`
+    pub fn to_json(&self) -> String {
+        let items: Vec<String> = self
+            .violations
+            .iter()
+            .map(|v| {
+                let rejected = match &v.invalid_value {
+                    Some(value) => format!("\"{}\"", escape_json(value)),
+                    None => "null".to_string(),
+                };
+                format!(
+                    r#"{{"field":"{}","constraint":"{}","message":"{}","rejectedValue":{}}}"#,
+                    escape_json(&v.property_path),
+                    v.constraint,
+                    escape_json(&v.message),
+                    rejected
+                )
+            })
+            .collect();
+        format!("[{}]", items.join(","))
+    }
+}
+
+impl IntoIterator for ConstraintViolations {
+    type Item = ConstraintViolation;
+    type IntoIter = std::vec::IntoIter<ConstraintViolation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.violations.into_iter()
+    }
+}
+
+impl Display for ConstraintViolations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} constraint violation(s):", self.violations.len())?;
+        for violation in &self.violations {
+            write!(f, "\n    - {}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ConstraintViolations {}
+
+pub(crate) fn escape_json(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
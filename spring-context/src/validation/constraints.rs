@@ -0,0 +1,258 @@
+//! `#[derive(Validate)]` 生成代码调用的内置约束。
+//!
+//! 与 Bean Validation 一致，`None` 对除 `#[NotBlank]` 以外的约束都视为合法，
+//! 需要必填时与 `#[NotBlank]` 组合使用。
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::{Mutex, OnceLock};
+
+use regex::Regex;
+
+use super::constraint_violation::ConstraintViolations;
+
+/// 可作为文本校验的值：`#[NotBlank]`、`#[Pattern]`、`#[Email]`
+pub trait TextValue {
+    fn text(&self) -> Option<&str>;
+}
+
+/// 有长度的值：`#[Size]`（字符串按字符数计）
+pub trait SizedValue {
+    fn size(&self) -> Option<usize>;
+}
+
+/// 数值：`#[Min]`、`#[Max]`
+pub trait NumericValue {
+    fn numeric(&self) -> Option<Number>;
+}
+
+/// `#[Min]` / `#[Max]` 比较的数值。整数之间按整数比较，不经过 `f64`，超过 2^53 的值也不会丢失精度；
+/// 只有一方是浮点数时才按 `f64` 比较
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+impl Number {
+    fn as_f64(self) -> f64 {
+        match self {
+            Number::Signed(n) => n as f64,
+            Number::Unsigned(n) => n as f64,
+            Number::Float(n) => n,
+        }
+    }
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (*self, *other) {
+            (Number::Signed(a), Number::Signed(b)) => Some(a.cmp(&b)),
+            (Number::Unsigned(a), Number::Unsigned(b)) => Some(a.cmp(&b)),
+            (Number::Signed(a), Number::Unsigned(b)) => Some(u128::try_from(a).map_or(Ordering::Less, |a| a.cmp(&b))),
+            (Number::Unsigned(a), Number::Signed(b)) => Some(u128::try_from(b).map_or(Ordering::Greater, |b| a.cmp(&b))),
+            (a, b) => a.as_f64().partial_cmp(&b.as_f64()),
+        }
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Number::Signed(n) => Display::fmt(n, f),
+            Number::Unsigned(n) => Display::fmt(n, f),
+            Number::Float(n) => Display::fmt(n, f),
+        }
+    }
+}
+
+impl TextValue for str {
+    fn text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl TextValue for String {
+    fn text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T: TextValue + ?Sized> TextValue for &T {
+    fn text(&self) -> Option<&str> {
+        (**self).text()
+    }
+}
+
+impl<T: TextValue> TextValue for Option<T> {
+    fn text(&self) -> Option<&str> {
+        self.as_ref().and_then(TextValue::text)
+    }
+}
+
+impl SizedValue for str {
+    fn size(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl SizedValue for String {
+    fn size(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl<T> SizedValue for [T] {
+    fn size(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T> SizedValue for Vec<T> {
+    fn size(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<K, V> SizedValue for HashMap<K, V> {
+    fn size(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T: SizedValue + ?Sized> SizedValue for &T {
+    fn size(&self) -> Option<usize> {
+        (**self).size()
+    }
+}
+
+impl<T: SizedValue> SizedValue for Option<T> {
+    fn size(&self) -> Option<usize> {
+        self.as_ref().and_then(SizedValue::size)
+    }
+}
+
+macro_rules! numeric_value {
+    ($variant:ident as $repr:ty: $($ty:ty),*) => {
+        $(
+            impl NumericValue for $ty {
+                fn numeric(&self) -> Option<Number> {
+                    Some(Number::$variant(*self as $repr))
+                }
+            }
+        )*
+    };
+}
+
+numeric_value!(Signed as i128: i8, i16, i32, i64, i128, isize);
+numeric_value!(Unsigned as u128: u8, u16, u32, u64, u128, usize);
+numeric_value!(Float as f64: f32, f64);
+
+impl<T: NumericValue> NumericValue for Option<T> {
+    fn numeric(&self) -> Option<Number> {
+        self.as_ref().and_then(NumericValue::numeric)
+    }
+}
+
+/// 自定义 message 中的 `{min}` / `{max}` / `{value}` / `{regexp}` 会被替换为约束参数
+fn interpolate(message: Option<&str>, default: String, params: &[(&str, String)]) -> String {
+    let Some(message) = message else { return default };
+    params
+        .iter()
+        .fold(message.to_string(), |acc, (name, value)| acc.replace(&format!("{{{}}}", name), value))
+}
+
+pub fn not_blank<V: TextValue + ?Sized>(value: &V, path: &str, message: Option<&str>, violations: &mut ConstraintViolations) {
+    let text = value.text();
+    if text.is_none_or(|text| text.trim().is_empty()) {
+        let message = interpolate(message, "must not be blank".to_string(), &[]);
+        violations.add(path, "NotBlank", message, text.map(str::to_string));
+    }
+}
+
+pub fn min<V: NumericValue + ?Sized>(value: &V, bound: Number, path: &str, message: Option<&str>, violations: &mut ConstraintViolations) {
+    if let Some(number) = value.numeric().filter(|number| *number < bound) {
+        let default = format!("must be greater than or equal to {}", bound);
+        let message = interpolate(message, default, &[("value", bound.to_string())]);
+        violations.add(path, "Min", message, Some(number.to_string()));
+    }
+}
+
+pub fn max<V: NumericValue + ?Sized>(value: &V, bound: Number, path: &str, message: Option<&str>, violations: &mut ConstraintViolations) {
+    if let Some(number) = value.numeric().filter(|number| *number > bound) {
+        let default = format!("must be less than or equal to {}", bound);
+        let message = interpolate(message, default, &[("value", bound.to_string())]);
+        violations.add(path, "Max", message, Some(number.to_string()));
+    }
+}
+
+pub fn size<V: SizedValue + ?Sized>(
+    value: &V,
+    min: usize,
+    max: usize,
+    path: &str,
+    message: Option<&str>,
+    violations: &mut ConstraintViolations,
+) {
+    if let Some(size) = value.size().filter(|size| *size < min || *size > max) {
+        let default = if max == usize::MAX {
+            format!("size must be at least {}", min)
+        } else {
+            format!("size must be between {} and {}", min, max)
+        };
+        let message = interpolate(message, default, &[("min", min.to_string()), ("max", max.to_string())]);
+        violations.add(path, "Size", message, Some(size.to_string()));
+    }
+}
+
+/// 整个字符串必须匹配 `regexp`（与 Java 的 `Matcher::matches` 相同，不是部分匹配）
+pub fn pattern<V: TextValue + ?Sized>(
+    value: &V,
+    regexp: &'static str,
+    path: &str,
+    message: Option<&str>,
+    violations: &mut ConstraintViolations,
+) {
+    let Some(text) = value.text() else { return };
+    if !full_match(regexp, text) {
+        let message = interpolate(message, format!("must match \"{}\"", regexp), &[("regexp", regexp.to_string())]);
+        violations.add(path, "Pattern", message, Some(text.to_string()));
+    }
+}
+
+pub fn email<V: TextValue + ?Sized>(value: &V, path: &str, message: Option<&str>, violations: &mut ConstraintViolations) {
+    let Some(text) = value.text() else { return };
+    if !is_email(text) {
+        let message = interpolate(message, "must be a well-formed email address".to_string(), &[]);
+        violations.add(path, "Email", message, Some(text.to_string()));
+    }
+}
+
+fn full_match(regexp: &'static str, text: &str) -> bool {
+    static CACHE: OnceLock<Mutex<HashMap<&'static str, Regex>>> = OnceLock::new();
+    let mut cache = CACHE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    // 正则在编译期已由 #[derive(Validate)] 检查过
+    let regex = cache
+        .entry(regexp)
+        .or_insert_with(|| Regex::new(&format!("^(?:{})$", regexp)).expect("invalid #[Pattern] regexp"));
+    regex.is_match(text)
+}
+
+/// `local@domain`：local 部分非空且不含空白；domain 由字母、数字、`-` 组成的若干段以 `.` 连接
+fn is_email(text: &str) -> bool {
+    let Some((local, domain)) = text.rsplit_once('@') else { return false };
+    let local_ok = !local.is_empty() && !local.chars().any(|c| c.is_whitespace() || c == '@');
+    let domain_ok = !domain.is_empty()
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        });
+    local_ok && domain_ok
+}
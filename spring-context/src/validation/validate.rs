@@ -0,0 +1,104 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use spring_beans::error::BeansError;
+
+use super::constraint_violation::ConstraintViolations;
+
+/// 可校验的类型，由 `#[derive(Validate)]` 生成实现。
+///
+/// `Option<T>`、`Vec<T>`、`HashMap<String, T>`、`Box<T>` 会逐个校验其中的元素，
+/// 因此 `#[Valid]` 可以直接标注在这些类型的字段上。
+pub trait Validate {
+    /// 把违规追加到 `violations`，字段路径以 `path` 为前缀
+    fn validate_into(&self, path: &str, violations: &mut ConstraintViolations);
+
+    fn validate(&self) -> Result<(), ConstraintViolations> {
+        let mut violations = ConstraintViolations::new();
+        self.validate_into("", &mut violations);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// `a` + `b` → `a.b`；`path` 为空时就是 `b`
+pub fn join_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_into(&self, path: &str, violations: &mut ConstraintViolations) {
+        if let Some(value) = self {
+            value.validate_into(path, violations);
+        }
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate_into(&self, path: &str, violations: &mut ConstraintViolations) {
+        self.as_ref().validate_into(path, violations);
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_into(&self, path: &str, violations: &mut ConstraintViolations) {
+        for (index, value) in self.iter().enumerate() {
+            value.validate_into(&format!("{}[{}]", path, index), violations);
+        }
+    }
+}
+
+impl<T: Validate> Validate for HashMap<String, T> {
+    fn validate_into(&self, path: &str, violations: &mut ConstraintViolations) {
+        for (key, value) in self {
+            value.validate_into(&format!("{}[{}]", path, key), violations);
+        }
+    }
+}
+
+// `#[ConfigurationProperties]` 绑定完成后校验 bean；类型没有实现 `Validate` 时什么都不做。
+// 与 `LifecycleProbe` 相同，通过自动引用选择实现：
+//   (&ValidateProbe::<T>::new()).validate_bean(name, prefix, bean)
+
+#[doc(hidden)]
+pub struct ValidateProbe<T>(PhantomData<T>);
+
+impl<T> ValidateProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        ValidateProbe(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaValidate<T> {
+    fn validate_bean(&self, bean_name: &str, prefix: &str, bean: &dyn Any) -> Result<(), BeansError>;
+}
+
+impl<T: Validate + 'static> ViaValidate<T> for ValidateProbe<T> {
+    fn validate_bean(&self, bean_name: &str, prefix: &str, bean: &dyn Any) -> Result<(), BeansError> {
+        let Some(bean) = bean.downcast_ref::<T>() else { return Ok(()) };
+        bean.validate().map_err(|violations| BeansError::BeanCreation {
+            bean_name: bean_name.to_string(),
+            message: format!("properties with prefix '{}' failed validation", prefix),
+            cause: Some(Box::new(violations)),
+        })
+    }
+}
+
+#[doc(hidden)]
+pub trait NoValidate<T> {
+    fn validate_bean(&self, _bean_name: &str, _prefix: &str, _bean: &dyn Any) -> Result<(), BeansError> {
+        Ok(())
+    }
+}
+
+impl<T> NoValidate<T> for &ValidateProbe<T> {}
@@ -1,2 +1,3 @@
-// Bean scope 相关定义
-// 实际的作用域定义在 spring-beans 中
+// 作用域 SPI 定义在 spring-beans，这里重新导出
+pub use spring_beans::factory::config::scope::{ObjectFactory, Scope, ScopeRegistration, ScopedObjects};
+pub use spring_beans::factory::config::BeanScope;
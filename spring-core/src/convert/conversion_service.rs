@@ -0,0 +1,3 @@
+pub use spring_beans::convert::conversion_service::{
+    ConversionProbe, ConversionService, GenericConversionService, ViaConversionService, ViaFromStr,
+};
@@ -0,0 +1 @@
+pub use spring_beans::convert::converter::{ConversionError, Converter, ConverterRegistration};
@@ -1,2 +1,5 @@
 pub mod conversion_service;
 pub mod converter;
+
+pub use conversion_service::{ConversionService, GenericConversionService};
+pub use converter::{ConversionError, Converter, ConverterRegistration};
@@ -1,13 +1,3 @@
-pub trait ConfigurableEnvironment {
-    fn set_active_profiles(&mut self, profiles: Vec<String>);
-    fn add_active_profile(&mut self, profile: String);
-    fn get_active_profiles(&self) -> Vec<String>;
-}
-
-impl Environment for ConfigurableEnvironment {
-
-}
-
-impl ConfigruablePropertyResolver for ConfigurableEnvironment {
-
-}
\ No newline at end of file
+pub use spring_beans::env::configurable_environment::{
+    parse_profiles, ConfigurableEnvironment, ACTIVE_PROFILES_PROPERTY_NAME, DEFAULT_PROFILE_NAME,
+};
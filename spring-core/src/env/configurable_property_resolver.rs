@@ -1,8 +1 @@
-pub trait ConfigruablePropertyResolver {
-    fn set_ignore_unresolvable_nested_placeholders(&mut self, ignore: bool);
-    fn is_ignore_unresolvable_nested_placeholders(&self) -> bool;
-}
-
-impl PropertyResolver for ConfigruablePropertyResolver {
-
-}
\ No newline at end of file
+pub use spring_beans::env::property_resolver::ConfigruablePropertyResolver;
@@ -1,3 +1 @@
-pub trait Environment{
-    
-}
\ No newline at end of file
+pub use spring_beans::env::Environment;
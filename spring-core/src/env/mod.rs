@@ -0,0 +1,9 @@
+pub mod configurable_environment;
+pub mod configurable_property_resolver;
+pub mod environment;
+pub mod property_resolver;
+
+pub use configurable_environment::ConfigurableEnvironment;
+pub use configurable_property_resolver::ConfigruablePropertyResolver;
+pub use environment::Environment;
+pub use property_resolver::PropertyResolver;
@@ -1,4 +1 @@
-pub trait PropertyResolver {
-    fn contains_property(&self, key: &str) -> bool;
-    fn get_property(&self, key: &str) -> Option<String>;
-}
\ No newline at end of file
+pub use spring_beans::env::property_resolver::PropertyResolver;
@@ -0,0 +1,2 @@
+// spring-core 依赖 spring-beans，错误类型定义在 spring-beans 中，这里重新导出
+pub use spring_beans::error::BeansError;
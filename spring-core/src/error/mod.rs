@@ -1,2 +1,4 @@
 pub mod bean_creation_error;
 pub mod no_such_bean_error;
+pub use bean_creation_error::BeansError;
+pub use no_such_bean_error::NoSuchBeanError;
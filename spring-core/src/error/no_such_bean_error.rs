@@ -0,0 +1,2 @@
+// 定义见 spring-beans，这里重新导出
+pub use spring_beans::error::NoSuchBeanError;
@@ -1,8 +1,10 @@
 // Spring Core 模块 - 核心功能和接口
 pub mod bean;
 pub mod convert;
+pub mod env;
 pub mod error;
 pub mod registry;
+pub mod task;
 pub mod util;
 
 // 重新导出核心类型
@@ -0,0 +1,5 @@
+pub mod task_executor;
+pub mod thread_pool_task_executor;
+
+pub use task_executor::{Task, TaskExecutor, TaskRejectedError};
+pub use thread_pool_task_executor::{PanicHandler, ThreadPoolTaskExecutor};
@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// 提交给 [`TaskExecutor`] 的任务
+pub type Task = Box<dyn FnOnce() + Send + 'static>;
+
+/// 对标 Java 的 `TaskExecutor`：异步执行一个任务，不关心结果。
+pub trait TaskExecutor: Send + Sync {
+    /// 提交任务；执行器已关闭时返回 [`TaskRejectedError`]，调用方可以从中取回任务自行处理
+    fn execute(&self, task: Task) -> Result<(), TaskRejectedError>;
+
+    /// 停止接收新任务，并等待已提交的任务执行完毕。默认什么也不做。
+    fn shutdown(&self) {}
+}
+
+/// 对标 Java 的 `TaskRejectedException`：执行器不再接收任务
+pub struct TaskRejectedError {
+    task: Task,
+}
+
+impl TaskRejectedError {
+    pub fn new(task: Task) -> Self {
+        Self { task }
+    }
+
+    /// 取回被拒绝的任务
+    pub fn into_task(self) -> Task {
+        self.task
+    }
+}
+
+impl Debug for TaskRejectedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskRejectedError").finish_non_exhaustive()
+    }
+}
+
+impl Display for TaskRejectedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("executor is shut down, task rejected")
+    }
+}
+
+impl Error for TaskRejectedError {}
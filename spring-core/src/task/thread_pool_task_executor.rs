@@ -0,0 +1,168 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread::{self, JoinHandle};
+
+use super::task_executor::{Task, TaskExecutor, TaskRejectedError};
+
+/// 任务 panic 时的回调，参数为工作线程名和 panic 负载
+pub type PanicHandler = Arc<dyn Fn(&str, &(dyn Any + Send)) + Send + Sync>;
+
+/// 固定大小的线程池。
+///
+/// 任务按提交顺序进入同一个队列，由空闲的工作线程取走执行；
+/// 单个任务 panic 不会拖垮工作线程，panic 交给 [`ThreadPoolTaskExecutor::set_panic_handler`] 设置的回调（默认写到 stderr）。
+/// [`TaskExecutor::shutdown`] 或 drop 时等待队列中已提交的任务全部执行完毕。
+pub struct ThreadPoolTaskExecutor {
+    sender: Mutex<Option<Sender<Task>>>,
+    workers: Mutex<Vec<JoinHandle<()>>>,
+    pool_size: usize,
+    panic_handler: Arc<RwLock<PanicHandler>>,
+}
+
+impl ThreadPoolTaskExecutor {
+    /// 创建 `pool_size` 个工作线程（至少 1 个），线程名为 `{name_prefix}-{序号}`
+    pub fn new(pool_size: usize, name_prefix: &str) -> Self {
+        let pool_size = pool_size.max(1);
+        let (sender, receiver) = mpsc::channel::<Task>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let panic_handler = Arc::new(RwLock::new(Self::default_panic_handler()));
+        let workers = (0..pool_size)
+            .map(|i| {
+                let receiver = Arc::clone(&receiver);
+                let panic_handler = Arc::clone(&panic_handler);
+                thread::Builder::new()
+                    .name(format!("{}-{}", name_prefix, i + 1))
+                    .spawn(move || Self::work(&receiver, &panic_handler))
+                    .expect("failed to spawn executor thread")
+            })
+            .collect();
+        ThreadPoolTaskExecutor {
+            sender: Mutex::new(Some(sender)),
+            workers: Mutex::new(workers),
+            pool_size,
+            panic_handler,
+        }
+    }
+
+    /// 按 CPU 核数创建线程池
+    pub fn with_available_parallelism(name_prefix: &str) -> Self {
+        let size = thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+        Self::new(size, name_prefix)
+    }
+
+    pub fn get_pool_size(&self) -> usize {
+        self.pool_size
+    }
+
+    /// 替换任务 panic 时的回调，对之后发生的 panic 生效
+    pub fn set_panic_handler(&self, handler: impl Fn(&str, &(dyn Any + Send)) + Send + Sync + 'static) {
+        *self.panic_handler.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = Arc::new(handler);
+    }
+
+    fn default_panic_handler() -> PanicHandler {
+        Arc::new(|thread, payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("Box<dyn Any>");
+            eprintln!("[task] task panicked on thread '{}': {}", thread, message);
+        })
+    }
+
+    fn work(receiver: &Mutex<Receiver<Task>>, panic_handler: &RwLock<PanicHandler>) {
+        loop {
+            // 只在取任务时持锁，执行任务时其它线程可以继续取
+            let task = match receiver.lock() {
+                Ok(receiver) => receiver.recv(),
+                Err(poisoned) => poisoned.into_inner().recv(),
+            };
+            match task {
+                Ok(task) => {
+                    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(task)) {
+                        let handler = Arc::clone(&panic_handler.read().unwrap_or_else(|poisoned| poisoned.into_inner()));
+                        handler(thread::current().name().unwrap_or("?"), payload.as_ref());
+                    }
+                }
+                // 发送端已关闭：线程池正在销毁
+                Err(_) => return,
+            }
+        }
+    }
+}
+
+impl TaskExecutor for ThreadPoolTaskExecutor {
+    fn execute(&self, task: Task) -> Result<(), TaskRejectedError> {
+        let sender = self.sender.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        match sender.as_ref() {
+            Some(sender) => sender.send(task).map_err(|e| TaskRejectedError::new(e.0)),
+            None => Err(TaskRejectedError::new(task)),
+        }
+    }
+
+    fn shutdown(&self) {
+        // 关闭队列后工作线程执行完剩余任务即退出
+        self.sender.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take();
+        let workers = std::mem::take(&mut *self.workers.lock().unwrap_or_else(|poisoned| poisoned.into_inner()));
+        let current = thread::current().id();
+        for worker in workers {
+            // 在池内线程上关闭时不能等待自己
+            if worker.thread().id() != current {
+                let _ = worker.join();
+            }
+        }
+    }
+}
+
+impl Drop for ThreadPoolTaskExecutor {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_runs_all_tasks_before_drop_returns() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let executor = ThreadPoolTaskExecutor::new(3, "test-pool");
+        for i in 0..20 {
+            let counter = Arc::clone(&counter);
+            executor.execute(Box::new(move || {
+                if i == 5 {
+                    panic!("boom");
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+            })).unwrap();
+        }
+        drop(executor);
+        assert_eq!(counter.load(Ordering::SeqCst), 19);
+    }
+
+    #[test]
+    fn test_shutdown_rejects_tasks_and_reports_panics() {
+        let panics = Arc::new(Mutex::new(Vec::new()));
+        let executor = ThreadPoolTaskExecutor::new(1, "test-shutdown");
+        let seen = Arc::clone(&panics);
+        executor.set_panic_handler(move |thread, payload| {
+            let message = payload.downcast_ref::<&str>().unwrap();
+            seen.lock().unwrap().push(format!("{}: {}", thread, message));
+        });
+        executor.execute(Box::new(|| panic!("boom"))).unwrap();
+        executor.shutdown();
+        assert_eq!(*panics.lock().unwrap(), ["test-shutdown-1: boom"]);
+
+        let ran = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&ran);
+        let rejected = executor.execute(Box::new(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        })).unwrap_err();
+        rejected.into_task()();
+        assert_eq!(ran.load(Ordering::SeqCst), 1);
+    }
+}
@@ -5,6 +5,8 @@
 //! - [`InMemoryRepository<T>`]：基于 `HashMap` + 自动递增 u64 主键的内存实现
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
 
 // ─────────────────────────────────────────────
 //  Repository trait
@@ -37,21 +39,27 @@ pub trait Repository<T> {
 //  InMemoryRepository<T>
 // ─────────────────────────────────────────────
 
-/// 基于 `std::cell::RefCell<HashMap<u64, T>>` 的内存 Repository 实现。
-/// 使用自增 u64 主键；通过 `RefCell` 提供内部可变性，
-/// 以便在 `&self` 上调用写操作（契合 IoC 容器只保存 `&T`/`Box<dyn Any>` 的模式）。
+/// 基于 `Mutex<HashMap<u64, T>>` 的内存 Repository 实现。
+/// 使用自增 u64 主键；通过 `Mutex` 提供内部可变性，
+/// 以便在 `&self` 上调用写操作（契合 IoC 容器只保存 `&T`/`Arc<T>` 的模式）。
+/// 当 `T: Send` 时 Repository 是 `Send + Sync`，可以作为单例在多个线程间共享。
 pub struct InMemoryRepository<T> {
-    store: std::cell::RefCell<HashMap<u64, T>>,
-    next_id: std::cell::Cell<u64>,
+    store: Mutex<HashMap<u64, T>>,
+    next_id: AtomicU64,
 }
 
 impl<T> InMemoryRepository<T> {
     pub fn new() -> Self {
         Self {
-            store: std::cell::RefCell::new(HashMap::new()),
-            next_id: std::cell::Cell::new(1),
+            store: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
         }
     }
+
+    /// 某个线程在持锁时 panic 不应让整个 Repository 不可用
+    fn store(&self) -> MutexGuard<'_, HashMap<u64, T>> {
+        self.store.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
 }
 
 impl<T> Default for InMemoryRepository<T> {
@@ -62,29 +70,30 @@ impl<T> Default for InMemoryRepository<T> {
 
 impl<T> Repository<T> for InMemoryRepository<T> {
     fn save(&self, entity: T) -> u64 {
-        let id = self.next_id.get();
-        self.store.borrow_mut().insert(id, entity);
-        self.next_id.set(id + 1);
+        // 在持锁期间分配主键，避免与 delete_all 的重置交错
+        let mut store = self.store();
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        store.insert(id, entity);
         id
     }
 
     fn update(&self, id: u64, entity: T) -> bool {
-        let mut store = self.store.borrow_mut();
-        if store.contains_key(&id) {
-            store.insert(id, entity);
-            true
-        } else {
-            false
+        match self.store().get_mut(&id) {
+            Some(slot) => {
+                *slot = entity;
+                true
+            }
+            None => false,
         }
     }
 
     fn find_by_id<R, F: FnOnce(Option<&T>) -> R>(&self, id: u64, f: F) -> R {
-        let store = self.store.borrow();
+        let store = self.store();
         f(store.get(&id))
     }
 
     fn for_each<F: FnMut(u64, &T)>(&self, mut f: F) {
-        let store = self.store.borrow();
+        let store = self.store();
         let mut pairs: Vec<(u64, &T)> = store.iter().map(|(&k, v)| (k, v)).collect();
         pairs.sort_by_key(|(k, _)| *k);
         for (id, val) in pairs {
@@ -93,27 +102,28 @@ impl<T> Repository<T> for InMemoryRepository<T> {
     }
 
     fn find_all_cloned(&self) -> Vec<(u64, T)> where T: Clone {
-        let store = self.store.borrow();
+        let store = self.store();
         let mut pairs: Vec<(u64, T)> = store.iter().map(|(&k, v)| (k, v.clone())).collect();
         pairs.sort_by_key(|(k, _)| *k);
         pairs
     }
 
     fn delete_by_id(&self, id: u64) -> bool {
-        self.store.borrow_mut().remove(&id).is_some()
+        self.store().remove(&id).is_some()
     }
 
     fn delete_all(&self) {
-        self.store.borrow_mut().clear();
-        self.next_id.set(1);
+        let mut store = self.store();
+        store.clear();
+        self.next_id.store(1, Ordering::SeqCst);
     }
 
     fn count(&self) -> usize {
-        self.store.borrow().len()
+        self.store().len()
     }
 
     fn exists_by_id(&self, id: u64) -> bool {
-        self.store.borrow().contains_key(&id)
+        self.store().contains_key(&id)
     }
 }
 
@@ -193,4 +203,26 @@ mod tests {
         let id = repo.save(user("I", 3));
         assert_eq!(id, 1);
     }
+
+    #[test]
+    fn test_concurrent_save() {
+        let repo: std::sync::Arc<InMemoryRepository<User>> = std::sync::Arc::new(InMemoryRepository::new());
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let repo = repo.clone();
+                std::thread::spawn(move || {
+                    for i in 0..25 {
+                        repo.save(user(&format!("u{}-{}", t, i), i));
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+        assert_eq!(repo.count(), 100);
+        // 主键不重复且连续
+        let ids: Vec<u64> = repo.find_all_cloned().into_iter().map(|(id, _)| id).collect();
+        assert_eq!(ids, (1..=100).collect::<Vec<u64>>());
+    }
 }
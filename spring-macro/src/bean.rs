@@ -1,10 +1,10 @@
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
-use syn::{parse::Parser, parse_macro_input, ItemFn, LitBool, LitStr, ReturnType, Type};
+use syn::{parse::Parser, parse_macro_input, Ident, ItemFn, LitBool, LitStr, ReturnType, Type};
 
 pub fn bean_impl(attribute: TokenStream, item: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(item as ItemFn);
+    let mut input = parse_macro_input!(item as ItemFn);
     let args = match parse_bean_args(attribute) {
         Ok(a) => a,
         Err(e) => return e.to_compile_error().into(),
@@ -18,19 +18,12 @@ pub fn bean_impl(attribute: TokenStream, item: TokenStream) -> TokenStream {
     let name_lit = LitStr::new(&bean_name, Span::call_site());
 
     // scope
-    let scope_token = match args.scope.as_deref().unwrap_or("singleton") {
-        "singleton" => quote! { spring_beans::factory::config::BeanScope::Singleton },
-        "prototype" => quote! { spring_beans::factory::config::BeanScope::Prototype },
-        other => {
-            return syn::Error::new(
-                Span::call_site(),
-                format!(
-                    "@Bean scope must be \"singleton\" or \"prototype\", got \"{}\"",
-                    other
-                ),
-            )
-            .to_compile_error()
-            .into();
+    let scope_token = match crate::component::build_scope_token(args.scope.as_deref().unwrap_or("singleton")) {
+        Ok(token) => token,
+        Err(message) => {
+            return syn::Error::new(Span::call_site(), format!("@Bean {}", message))
+                .to_compile_error()
+                .into();
         }
     };
 
@@ -46,6 +39,43 @@ pub fn bean_impl(attribute: TokenStream, item: TokenStream) -> TokenStream {
         }
     };
 
+    let wrap_fn = crate::component::build_wrap_fn(&quote! { #ret_ty });
+    let (init_fn, destroy_fn) = crate::lifecycle::build_lifecycle_fns(
+        &quote! { #ret_ty },
+        args.init_method.as_ref(),
+        args.destroy_method.as_ref(),
+    );
+
+    // #[Profile] 是 helper attribute，读取后从函数上剥离
+    let set_profiles = match crate::profile::extract_profile_attr(&input.attrs) {
+        Ok(profiles) => crate::profile::build_set_profiles(&profiles),
+        Err(err) => return err.to_compile_error().into(),
+    };
+    input.attrs.retain(|attr| !attr.path().is_ident("Profile"));
+    // #[Primary] / #[Order] 同理
+    let primary = crate::component::has_primary_attr(&input.attrs);
+    let set_order = match crate::component::extract_order_attr(&input.attrs) {
+        Ok(order) => crate::component::build_set_order(order.as_ref()),
+        Err(err) => return err.to_compile_error().into(),
+    };
+    input.attrs.retain(|attr| !attr.path().is_ident("Primary") && !attr.path().is_ident("Order"));
+    let set_provided_types = crate::component::build_set_provided_types(&quote! { #ret_ty }, &args.provides);
+
+    // 参数按类型或 #[Qualifier] 从容器注入
+    let injection = match crate::constructor::build_parameter_injection(&mut input.sig.inputs, &quote! { #name_lit }) {
+        Ok(injection) => injection,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let crate::constructor::ParameterInjection {
+        args,
+        dependencies,
+        lazy_dependencies,
+        provider_dependencies,
+        optional_dependencies,
+        collection_dependencies,
+        descriptors,
+    } = injection;
+
     // 保留原函数（供内部调用）
     let original_fn = &input;
 
@@ -57,18 +87,31 @@ pub fn bean_impl(attribute: TokenStream, item: TokenStream) -> TokenStream {
         inventory::submit! {
             spring_beans::registry::BeanRegistration {
                 definition: || {
-                    spring_beans::factory::config::RootBeanDefinition::new(
+                    let mut definition = spring_beans::factory::config::RootBeanDefinition::new(
                         #name_lit.to_string(),
                         std::any::TypeId::of::<#ret_ty>(),
                         #scope_token,
                         #lazy,
-                        vec![],  // @Bean 方法的依赖通过手动调用容器 API 解析（暂不自动推断）
-                        Box::new(|_resolved_deps: &std::collections::HashMap<String, Box<dyn std::any::Any>>, _env: &std::collections::HashMap<String, String>| {
-                            let instance = #fn_ident();
-                            Box::new(instance) as Box<dyn std::any::Any>
+                        vec![#(#dependencies.to_string()),*],
+                        Box::new(|resolved_deps: &spring_beans::factory::config::ResolvedDependencies, _env: &std::collections::HashMap<String, String>| {
+                            let instance = #fn_ident(#(#args),*);
+                            Ok(Box::new(instance) as Box<dyn std::any::Any>)
                         }),
                         None,
-                    )
+                        #wrap_fn,
+                    );
+                    definition.set_lazy_dependencies(vec![#(#lazy_dependencies.to_string()),*]);
+                    definition.set_provider_dependencies(vec![#(#provider_dependencies.to_string()),*]);
+                    definition.set_optional_dependencies(vec![#(#optional_dependencies.to_string()),*]);
+                    definition.set_collection_dependencies(vec![#(#collection_dependencies.to_string()),*]);
+                    definition.set_dependency_descriptors(vec![#(#descriptors),*]);
+                    definition.set_primary(#primary);
+                    #set_order
+                    #set_provided_types
+                    definition.set_init_method(Some(#init_fn));
+                    definition.set_destroy_method(Some(#destroy_fn));
+                    #set_profiles
+                    definition
                 },
             }
         }
@@ -81,6 +124,9 @@ struct BeanArgs {
     name: Option<String>,
     scope: Option<String>,
     lazy: Option<bool>,
+    provides: Vec<Type>,
+    init_method: Option<Ident>,
+    destroy_method: Option<Ident>,
 }
 
 fn parse_bean_args(attribute: TokenStream) -> syn::Result<BeanArgs> {
@@ -104,6 +150,20 @@ fn parse_bean_args(attribute: TokenStream) -> syn::Result<BeanArgs> {
             args.lazy = Some(v.value());
             return Ok(());
         }
+        if meta.path.is_ident("provides") {
+            args.provides.extend(crate::component::parse_provides(&meta)?);
+            return Ok(());
+        }
+        if meta.path.is_ident("init_method") {
+            let v: LitStr = meta.value()?.parse()?;
+            args.init_method = Some(v.parse()?);
+            return Ok(());
+        }
+        if meta.path.is_ident("destroy_method") {
+            let v: LitStr = meta.value()?.parse()?;
+            args.destroy_method = Some(v.parse()?);
+            return Ok(());
+        }
         Err(meta.error("unsupported @Bean attribute key"))
     });
     parser.parse(attribute)?;
@@ -3,12 +3,21 @@ use proc_macro2::Span;
 use quote::quote;
 use syn::parse::Parser;
 use syn::{
-    parse_macro_input, Attribute, Expr, ExprArray, ExprLit, Fields, GenericArgument, Ident, ItemStruct, Lit,
+    parse_macro_input, Attribute, Expr, ExprArray, ExprLit, Fields, GenericArgument, Ident, Item, ItemStruct, Lit,
     LitBool, LitStr, PathArguments, Type,
 };
 
 pub fn component_impl(attribute: TokenStream, item: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(item as ItemStruct);
+    let input = match parse_macro_input!(item as Item) {
+        Item::Struct(input) => input,
+        // #[Component] impl Foo { #[Autowired] fn new(..) -> Self }
+        Item::Impl(input) => return crate::constructor::component_constructor_impl(attribute, input),
+        other => {
+            return syn::Error::new_spanned(other, "#[Component] can only be applied to a struct or its impl block")
+                .to_compile_error()
+                .into();
+        }
+    };
     let args = match parse_component_args(attribute) {
         Ok(args) => args,
         Err(err) => return err.to_compile_error().into(),
@@ -22,34 +31,34 @@ pub fn component_impl(attribute: TokenStream, item: TokenStream) -> TokenStream
     let scope = struct_scope.or(args.scope).unwrap_or_else(|| "singleton".to_string());
     let lazy  = struct_lazy.or(args.lazy).unwrap_or(false);
     let name_lit = LitStr::new(&name, Span::call_site());
-    let scope_token = match scope.as_str() {
-        "singleton" => quote! { spring_beans::factory::config::BeanScope::Singleton },
-        "prototype" => quote! { spring_beans::factory::config::BeanScope::Prototype },
-        _ => {
-            return syn::Error::new_spanned(&input, "scope must be \"singleton\" or \"prototype\"")
-                .to_compile_error()
-                .into()
-        }
+    let scope_token = match build_scope_token(&scope) {
+        Ok(token) => token,
+        Err(message) => return syn::Error::new_spanned(&input, message).to_compile_error().into(),
     };
     // 无条件扫描 #[autowired] 字段，无需 autowire=true 参数（Spring 风格）
-    let deps_list = if !args.deps.is_empty() {
-        args.deps
-    } else {
-        collect_autowired_fields(&input)
-            .iter()
-            .map(|(_, bean_name, _)| bean_name.clone())
-            .collect()
+    let autowired_fields = match collect_autowired_fields(&input, args.deps.is_empty()) {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
     };
+    let SplitDependencies {
+        eager: field_deps,
+        lazy: lazy_deps,
+        provider: provider_deps,
+        optional: optional_deps,
+        collection: collection_deps,
+    } = split_autowired_deps(&autowired_fields);
+    let descriptors = autowired_fields.iter().filter_map(|(_, point)| point.descriptor.as_ref());
+    let deps_list = if !args.deps.is_empty() { args.deps } else { field_deps };
+    let primary = has_primary_attr(&input.attrs);
 
     let deps: Vec<LitStr> = deps_list
         .iter()
         .map(|dep| LitStr::new(dep, Span::call_site()))
         .collect();
 
-    // 无条件生成注入语句
-    // 无条件生成注入语句（autowired + Value）
-    let inject_stmts = build_inject_stmts(&input);
-    let value_inject_stmts = build_value_inject_stmts(&input);
+    // 无条件生成 #[autowired] 注入语句；#[Value] 字段在实例创建后由 value_binder 注入
+    let inject_stmts = build_inject_stmts(&autowired_fields, &name_lit);
+    let value_binder = build_value_binder(ident, &input, &name_lit);
 
     // 读取 #[ConditionalOnProperty("key", having = "value")] 条件
     let condition_token = match extract_conditional_attr(&input.attrs) {
@@ -61,6 +70,23 @@ pub fn component_impl(attribute: TokenStream, item: TokenStream) -> TokenStream
         None => quote! { None },
     };
 
+    let wrap_fn = build_wrap_fn(&quote! { #ident });
+    let (construct, constructor_dependencies) = build_constructor_probe(ident, &name_lit);
+    let (init_fn, destroy_fn) = crate::lifecycle::build_lifecycle_fns(
+        &quote! { #ident },
+        args.init_method.as_ref(),
+        args.destroy_method.as_ref(),
+    );
+    let set_profiles = match crate::profile::extract_profile_attr(&input.attrs) {
+        Ok(profiles) => crate::profile::build_set_profiles(&profiles),
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let set_order = match extract_order_attr(&input.attrs) {
+        Ok(order) => build_set_order(order.as_ref()),
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let set_provided_types = build_set_provided_types(&quote! { #ident }, &args.provides);
+
     // 剥离 struct 字段上的 #[autowired] 属性，避免编译器找不到该 helper attribute
     let clean_input = strip_helper_attrs(input.clone());
     let expanded = quote! {
@@ -71,20 +97,34 @@ pub fn component_impl(attribute: TokenStream, item: TokenStream) -> TokenStream
             }
 
             pub fn bean_definition() -> spring_beans::factory::config::RootBeanDefinition {
-                spring_beans::factory::config::RootBeanDefinition::new(
+                let mut definition = spring_beans::factory::config::RootBeanDefinition::new(
                     #name_lit.to_string(),
                     std::any::TypeId::of::<#ident>(),
                     #scope_token,
                     #lazy,
                     vec![#(#deps.to_string()),*],
-                    Box::new(|resolved_deps: &std::collections::HashMap<String, Box<dyn std::any::Any>>, env: &std::collections::HashMap<String, String>| {
-                        let mut instance = #ident::default();
+                    Box::new(|resolved_deps: &spring_beans::factory::config::ResolvedDependencies, _env: &std::collections::HashMap<String, String>| {
+                        let mut instance = #construct;
                         #(#inject_stmts)*
-                        #(#value_inject_stmts)*
-                        Box::new(instance) as Box<dyn std::any::Any>
+                        Ok(Box::new(instance) as Box<dyn std::any::Any>)
                     }),
                     #condition_token,
-                )
+                    #wrap_fn,
+                );
+                definition.set_lazy_dependencies(vec![#(#lazy_deps.to_string()),*]);
+                definition.set_provider_dependencies(vec![#(#provider_deps.to_string()),*]);
+                definition.set_optional_dependencies(vec![#(#optional_deps.to_string()),*]);
+                definition.set_collection_dependencies(vec![#(#collection_deps.to_string()),*]);
+                definition.set_dependency_descriptors(vec![#(#descriptors),*]);
+                definition.add_constructor_dependencies(#constructor_dependencies);
+                definition.set_primary(#primary);
+                #set_order
+                #set_provided_types
+                #value_binder
+                definition.set_init_method(Some(#init_fn));
+                definition.set_destroy_method(Some(#destroy_fn));
+                #set_profiles
+                definition
             }
         }
         // 编译期自动向全局注册表提交一条记录，Application::run() 启动时自动扫描
@@ -104,14 +144,33 @@ pub fn component_derive_impl(item: TokenStream) -> TokenStream {
     let name_lit = LitStr::new(&name, Span::call_site());
 
     // 收集所有带 #[autowired] 的字段信息用于生成 deps 列表和注入代码
-    let autowired_fields = collect_autowired_fields(&input);
-    let deps: Vec<LitStr> = autowired_fields
+    let autowired_fields = match collect_autowired_fields(&input, true) {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let SplitDependencies {
+        eager: field_deps,
+        lazy: lazy_deps,
+        provider: provider_deps,
+        optional: optional_deps,
+        collection: collection_deps,
+    } = split_autowired_deps(&autowired_fields);
+    let descriptors = autowired_fields.iter().filter_map(|(_, point)| point.descriptor.as_ref());
+    let primary = has_primary_attr(&input.attrs);
+    let deps: Vec<LitStr> = field_deps
         .iter()
-        .map(|(_, bean_name, _)| LitStr::new(bean_name, Span::call_site()))
+        .map(|dep| LitStr::new(dep, Span::call_site()))
         .collect();
 
-    let inject_stmts = build_inject_stmts(&input);
-    let value_inject_stmts = build_value_inject_stmts(&input);
+    let inject_stmts = build_inject_stmts(&autowired_fields, &name_lit);
+    let value_binder = build_value_binder(ident, &input, &name_lit);
+    let wrap_fn = build_wrap_fn(&quote! { #ident });
+    let (construct, constructor_dependencies) = build_constructor_probe(ident, &name_lit);
+    let (init_fn, destroy_fn) = crate::lifecycle::build_lifecycle_fns(&quote! { #ident }, None, None);
+    let set_order = match extract_order_attr(&input.attrs) {
+        Ok(order) => build_set_order(order.as_ref()),
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     let expanded = quote! {
         impl #ident {
@@ -120,32 +179,217 @@ pub fn component_derive_impl(item: TokenStream) -> TokenStream {
             }
 
             pub fn bean_definition() -> spring_beans::factory::config::RootBeanDefinition {
-                spring_beans::factory::config::RootBeanDefinition::new(
+                let mut definition = spring_beans::factory::config::RootBeanDefinition::new(
                     #name_lit.to_string(),
                     std::any::TypeId::of::<#ident>(),
                     spring_beans::factory::config::BeanScope::Singleton,
                     false,
                     vec![#(#deps.to_string()),*],
-                    Box::new(|resolved_deps: &std::collections::HashMap<String, Box<dyn std::any::Any>>, env: &std::collections::HashMap<String, String>| {
-                        let mut instance = #ident::default();
+                    Box::new(|resolved_deps: &spring_beans::factory::config::ResolvedDependencies, _env: &std::collections::HashMap<String, String>| {
+                        let mut instance = #construct;
                         #(#inject_stmts)*
-                        #(#value_inject_stmts)*
-                        Box::new(instance) as Box<dyn std::any::Any>
+                        Ok(Box::new(instance) as Box<dyn std::any::Any>)
                     }),
                     None,
-                )
+                    #wrap_fn,
+                );
+                definition.set_lazy_dependencies(vec![#(#lazy_deps.to_string()),*]);
+                definition.set_provider_dependencies(vec![#(#provider_deps.to_string()),*]);
+                definition.set_optional_dependencies(vec![#(#optional_deps.to_string()),*]);
+                definition.set_collection_dependencies(vec![#(#collection_deps.to_string()),*]);
+                definition.set_dependency_descriptors(vec![#(#descriptors),*]);
+                definition.add_constructor_dependencies(#constructor_dependencies);
+                definition.set_primary(#primary);
+                #set_order
+                #value_binder
+                definition.set_init_method(Some(#init_fn));
+                definition.set_destroy_method(Some(#destroy_fn));
+                definition
             }
         }
     };
     expanded.into()
 }
 
-/// 收集带 #[autowired] 的字段：(字段名 Ident, bean name String, 字段类型 Type)
-fn collect_autowired_fields(input: &ItemStruct) -> Vec<(Ident, String, Type)> {
+/// 一个注入点：`#[autowired]` 字段，或 `#[Autowired]` 构造函数 / `#[Bean]` 函数的参数
+pub(crate) struct InjectionPoint {
+    /// `ResolvedDependencies` 中的键：`#[Qualifier]` 指定的 bean 名称，或按类型注入时的字段 / 参数名
+    pub key: String,
+    pub ty: Type,
+    /// 按类型注入时生成的 `DependencyDescriptor` 表达式
+    pub descriptor: Option<proc_macro2::TokenStream>,
+    /// `Vec<..>` / `HashMap<String, ..>`：注入所有类型匹配的 bean
+    pub collection: bool,
+}
+
+/// 标注 `#[Qualifier("name")]` 时按名称注入；否则按类型注入，由容器在同类型的候选中选择
+/// （`#[Primary]` 优先，其次是与字段 / 参数同名的 bean），没有同类型的定义时退回由类型推断的 bean 名称。
+/// `Vec<..>` / `HashMap<String, ..>` 注入所有元素类型的 bean（包括以 `provides` 声明了该 trait 的 bean）。
+/// 类型无法推断 bean 名称（如 `u32`、`String`）且没有 `#[Qualifier]` 时返回 `None`
+pub(crate) fn injection_point(ty: &Type, qualifier: Option<String>, property: &str) -> Option<InjectionPoint> {
+    if let Some(name) = qualifier {
+        return Some(InjectionPoint { key: name, ty: ty.clone(), descriptor: None, collection: false });
+    }
+    if let Some((_, element)) = collection_kind(ty) {
+        let key = camel_case(property);
+        if key.is_empty() {
+            return None;
+        }
+        let bean_ty = injected_type(&element);
+        let descriptor = quote! {
+            spring_beans::factory::config::DependencyDescriptor::of::<#bean_ty>(#key, #key)
+        };
+        return Some(InjectionPoint { key, ty: ty.clone(), descriptor: Some(descriptor), collection: true });
+    }
+    let default_name = extract_dependency_name(ty)?;
+    let key = match camel_case(property) {
+        name if name.is_empty() => default_name.clone(),
+        name => name,
+    };
+    let bean_ty = injected_type(ty);
+    let descriptor = quote! {
+        spring_beans::factory::config::DependencyDescriptor::of::<#bean_ty>(#key, #default_name)
+    };
+    Some(InjectionPoint { key, ty: ty.clone(), descriptor: Some(descriptor), collection: false })
+}
+
+enum CollectionKind {
+    Vec,
+    Map,
+}
+
+/// `Vec<P>` → (Vec, P)；`HashMap<String, P>` → (Map, P)，键为 bean 名称
+fn collection_kind(ty: &Type) -> Option<(CollectionKind, Type)> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    let mut types = args.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+    match segment.ident.to_string().as_str() {
+        "Vec" => Some((CollectionKind::Vec, types.next()?.clone())),
+        "HashMap" => {
+            let key = types.next()?;
+            if !matches!(key, Type::Path(key) if key.path.is_ident("String")) {
+                return None;
+            }
+            Some((CollectionKind::Map, types.next()?.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// 注入点引用的 bean 在容器中的类型，与 [`dependency_expr`] 的转换方式对应
+fn injected_type(ty: &Type) -> Type {
+    if let Some(inner) = option_inner(ty) {
+        return injected_type(&inner);
+    }
+    if let Some(inner) = lazy_inner(ty).or_else(|| provider_inner(ty)) {
+        return inner;
+    }
+    if let Some((_, inner)) = shared_pointer_kind(ty) {
+        return inner;
+    }
+    match ty {
+        Type::Reference(reference) => (*reference.elem).clone(),
+        other => other.clone(),
+    }
+}
+
+/// `user_repo` → `userRepo`；忽略前导下划线与 `r#` 前缀
+fn camel_case(snake: &str) -> String {
+    let snake = snake.trim_start_matches("r#");
+    let mut parts = snake.split('_').filter(|part| !part.is_empty());
+    let mut result = parts.next().map(lowercase_first).unwrap_or_default();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+            result.push_str(chars.as_str());
+        }
+    }
+    result
+}
+
+/// `#[Qualifier("beanName")]`
+pub(crate) fn extract_qualifier(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    match attrs.iter().find(|attr| attr.path().is_ident("Qualifier")) {
+        Some(attr) => Ok(Some(attr.parse_args::<LitStr>()?.value())),
+        None => Ok(None),
+    }
+}
+
+/// `#[Primary]`：同一类型有多个 bean 时优先注入这一个
+pub(crate) fn has_primary_attr(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("Primary"))
+}
+
+/// `#[Order(n)]`：集合注入时的排序，数值小的在前；没有标注的排在最后
+pub(crate) fn extract_order_attr(attrs: &[Attribute]) -> syn::Result<Option<Expr>> {
+    let mut orders = attrs.iter().filter(|attr| attr.path().is_ident("Order"));
+    let Some(attr) = orders.next() else { return Ok(None) };
+    if let Some(other) = orders.next() {
+        return Err(syn::Error::new_spanned(other, "duplicate #[Order]"));
+    }
+    attr.parse_args::<Expr>().map(Some)
+}
+
+/// 生成 `definition.set_order(..)`；没有 `#[Order]` 时为空
+pub(crate) fn build_set_order(order: Option<&Expr>) -> proc_macro2::TokenStream {
+    match order {
+        Some(order) => quote! { definition.set_order(Some((#order) as i32)); },
+        None => quote! {},
+    }
+}
+
+/// 解析 `provides = "dyn Trait"` / `provides = ["dyn A", "dyn B"]`
+pub(crate) fn parse_provides(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Vec<Type>> {
+    let literals = match meta.value()?.parse::<Expr>()? {
+        Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => vec![s],
+        Expr::Array(ExprArray { elems, .. }) => elems
+            .into_iter()
+            .map(|elem| match elem {
+                Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Ok(s),
+                other => Err(syn::Error::new_spanned(other, "provides must be string literals")),
+            })
+            .collect::<syn::Result<_>>()?,
+        other => return Err(syn::Error::new_spanned(other, "provides must be string literals")),
+    };
+    literals
+        .iter()
+        .map(|literal| match literal.parse::<Type>()? {
+            ty @ Type::TraitObject(_) => Ok(ty),
+            _ => Err(syn::Error::new_spanned(literal, "provides expects trait object types such as \"dyn PaymentGateway\"")),
+        })
+        .collect()
+}
+
+/// 生成 `definition.set_provided_types(..)`：bean 可按这些 trait 对象类型注入到
+/// `Vec<Arc<dyn Trait>>` / `HashMap<String, Arc<dyn Trait>>`（`Rc` bean 对应 `Rc<dyn Trait>`）。
+/// `ty` 未实现该 trait 时在这里编译失败
+pub(crate) fn build_set_provided_types(ty: &proc_macro2::TokenStream, provides: &[Type]) -> proc_macro2::TokenStream {
+    if provides.is_empty() {
+        return quote! {};
+    }
+    quote! {
+        definition.set_provided_types(vec![#(
+            spring_beans::factory::config::ProvidedType::of::<#provides>({
+                #[allow(unused_imports)]
+                use spring_beans::bean::bean_wrapper::{ViewLocal as _, ViewShared as _};
+                (&spring_beans::bean::bean_wrapper::ViewProbe::<#ty>::new()).view_fn::<#provides>(|bean| bean, |bean| bean)
+            })
+        ),*]);
+    }
+}
+
+/// 收集带 #[autowired] 的字段：(字段名 Ident, 注入点)。
+/// `by_type` 为 false（显式 `deps = [..]`）时一律按由类型推断的 bean 名称注入
+fn collect_autowired_fields(input: &ItemStruct, by_type: bool) -> syn::Result<Vec<(Ident, InjectionPoint)>> {
     let mut result = Vec::new();
     let fields = match &input.fields {
         Fields::Named(fields) => &fields.named,
-        _ => return result,
+        _ => return Ok(result),
     };
     for field in fields {
         if field
@@ -157,35 +401,299 @@ fn collect_autowired_fields(input: &ItemStruct) -> Vec<(Ident, String, Type)> {
                 Some(id) => id,
                 None => continue,
             };
-            if let Some(bean_name) = extract_dependency_name(&field.ty) {
-                result.push((field_ident, bean_name, field.ty.clone()));
+            let qualifier = extract_qualifier(&field.attrs)?;
+            let point = if by_type || qualifier.is_some() {
+                injection_point(&field.ty, qualifier, &field_ident.to_string())
+            } else {
+                extract_dependency_name(&field.ty)
+                    .map(|key| InjectionPoint { key, ty: field.ty.clone(), descriptor: None, collection: false })
+            };
+            if let Some(point) = point {
+                result.push((field_ident, point));
             }
         }
     }
-    result
+    Ok(result)
 }
 
-/// 为每个 #[autowired] 字段生成 resolved_deps 取值 + downcast + clone 的注入语句
-fn build_inject_stmts(input: &ItemStruct) -> Vec<proc_macro2::TokenStream> {
-    collect_autowired_fields(input)
-        .into_iter()
-        .map(|(field_ident, bean_name, field_ty)| {
-            let bean_name_lit = LitStr::new(&bean_name, Span::call_site());
+/// 为每个 #[autowired] 字段生成注入语句，依赖缺失或类型不符时 supplier 返回 `BeansError`
+fn build_inject_stmts(fields: &[(Ident, InjectionPoint)], owner_name: &LitStr) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|(field_ident, point)| {
+            let value = dependency_expr(point, &quote! { #owner_name }, &field_ident.to_string());
             quote! {
-                instance.#field_ident = resolved_deps
-                    .get(#bean_name_lit)
-                    .and_then(|b| b.downcast_ref::<#field_ty>())
-                    .map(Clone::clone)
-                    .unwrap_or_else(|| panic!(
-                        "autowire failed: bean '{}' not found or type mismatch for field '{}'",
-                        #bean_name_lit,
-                        stringify!(#field_ident)
-                    ));
+                instance.#field_ident = #value;
             }
         })
         .collect()
 }
 
+/// 从 `resolved_deps` 中取出依赖并转换为注入点类型的表达式（失败时以 `?` 返回 `BeansError`），
+/// `#[autowired]` 字段、`#[Autowired]` 构造函数与 `#[Bean]` 函数参数共用：
+/// - `Lazy<T>` / `ObjectProvider<T>` / `LazyBean<T>`：提前引用与获取函数
+/// - `Option<P>`：容器中没有目标 bean 时为 `None`，否则按 `P` 转换
+/// - `Arc<T>` / `Rc<T>`：克隆容器中的共享指针，所有注入方共享同一个实例
+/// - `&T`：借用容器中的实例（只用于参数）
+/// - `Vec<P>` / `HashMap<String, P>`：所有匹配的 bean，每个元素按 `P` 转换
+/// - 其他类型：downcast + clone，得到一份独立拷贝
+///
+/// `owner` 是求值为注入方 bean 名称（`&str`）的表达式
+pub(crate) fn dependency_expr(point: &InjectionPoint, owner: &proc_macro2::TokenStream, property: &str) -> proc_macro2::TokenStream {
+    let ty = &point.ty;
+    let bean_name_lit = LitStr::new(&point.key, Span::call_site());
+    let missing = quote! {
+        spring_beans::error::BeansError::missing_dependency(#owner, #bean_name_lit)
+    };
+    if point.collection {
+        return collection_expr(point, owner, property);
+    }
+    if let Some(inner_ty) = lazy_inner(ty) {
+        return quote! {
+            spring_beans::bean::lazy::Lazy::<#inner_ty>::from_early(
+                resolved_deps.get_early(#bean_name_lit).cloned().ok_or_else(|| #missing)?,
+            )
+        };
+    }
+    if let Some(inner_ty) = lazy_bean_inner(ty) {
+        return quote! {
+            spring_beans::bean::lazy::LazyBean::<#inner_ty>::new(
+                resolved_deps.get_provider(#bean_name_lit).cloned().ok_or_else(|| #missing)?,
+            )
+        };
+    }
+    if let Some(inner_ty) = provider_inner(ty) {
+        return quote! {
+            spring_beans::bean::provider::ObjectProvider::<#inner_ty>::new(
+                resolved_deps.get_provider(#bean_name_lit).cloned().ok_or_else(|| #missing)?,
+            )
+        };
+    }
+    let mismatch = quote! {
+        spring_beans::error::BeansError::dependency_type_mismatch(
+            #owner,
+            #property,
+            #bean_name_lit,
+            stringify!(#ty),
+        )
+    };
+    // 容器中没有目标 bean 时不会注入，得到 None
+    if let Some(inner_ty) = option_inner(ty) {
+        let lookup = downcast_expr(&inner_ty);
+        return quote! {
+            match resolved_deps.get(#bean_name_lit) {
+                Some(_dep) => Some(#lookup.ok_or_else(|| #mismatch)?),
+                None => None,
+            }
+        };
+    }
+    let lookup = downcast_expr(ty);
+    quote! {
+        {
+            let _dep = resolved_deps.get(#bean_name_lit).ok_or_else(|| #missing)?;
+            #lookup.ok_or_else(|| #mismatch)?
+        }
+    }
+}
+
+/// 把 `_dep: &BeanWrapper` 转换为 `Option<ty>` 的表达式
+fn downcast_expr(ty: &Type) -> proc_macro2::TokenStream {
+    match (shared_pointer_kind(ty), ty) {
+        (Some((SharedPointer::Arc, inner_ty)), _) => quote! {
+            _dep.downcast_arc::<#inner_ty>()
+        },
+        (Some((SharedPointer::Rc, inner_ty)), _) => quote! {
+            _dep.downcast_rc::<#inner_ty>()
+        },
+        (None, Type::Reference(reference)) => {
+            let inner_ty = &reference.elem;
+            quote! {
+                _dep.as_any().downcast_ref::<#inner_ty>()
+            }
+        }
+        (None, _) => quote! {
+            _dep.as_any().downcast_ref::<#ty>().cloned()
+        },
+    }
+}
+
+/// 集合注入：逐个转换 `CollectionEntry`。元素为 `Arc<dyn X>` / `Rc<dyn X>` 时使用 bean 通过 `provides`
+/// 声明的 trait 对象指针，元素为具体类型时与单个依赖的转换方式相同
+fn collection_expr(point: &InjectionPoint, owner: &proc_macro2::TokenStream, property: &str) -> proc_macro2::TokenStream {
+    let Some((kind, element)) = collection_kind(&point.ty) else {
+        return quote! {};
+    };
+    let key = &point.key;
+    let bean_ty = injected_type(&element);
+    let convert = match (shared_pointer_kind(&element), &bean_ty) {
+        (Some(_), Type::TraitObject(_)) => quote! { _entry.view::<#element>() },
+        (Some((SharedPointer::Arc, _)), _) => quote! { _entry.bean().downcast_arc::<#bean_ty>() },
+        (Some((SharedPointer::Rc, _)), _) => quote! { _entry.bean().downcast_rc::<#bean_ty>() },
+        (None, _) => quote! { _entry.bean().as_any().downcast_ref::<#element>().cloned() },
+    };
+    let (init, add) = match kind {
+        CollectionKind::Vec => (
+            quote! { Vec::with_capacity(_entries.len()) },
+            quote! { _beans.push(_bean) },
+        ),
+        CollectionKind::Map => (
+            quote! { std::collections::HashMap::with_capacity(_entries.len()) },
+            quote! { _beans.insert(_entry.name().to_string(), _bean) },
+        ),
+    };
+    quote! {
+        {
+            let _entries = resolved_deps
+                .get_collection(#key)
+                .ok_or_else(|| spring_beans::error::BeansError::missing_dependency(#owner, #key))?;
+            let mut _beans = #init;
+            for _entry in _entries {
+                let _bean = #convert.ok_or_else(|| spring_beans::error::BeansError::dependency_type_mismatch(
+                    #owner,
+                    #property,
+                    _entry.name(),
+                    stringify!(#element),
+                ))?;
+                #add;
+            }
+            _beans
+        }
+    }
+}
+
+/// 把 #[autowired] 依赖分为普通依赖、`Lazy<T>` 依赖（可在目标创建完成前注入）、
+/// `ObjectProvider<T>` / `LazyBean<T>` 依赖（注入获取函数，获取时才取出或新建目标）、
+/// `Option<..>` 依赖（目标不存在时不注入）与集合依赖
+fn split_autowired_deps(fields: &[(Ident, InjectionPoint)]) -> SplitDependencies {
+    split_dependencies(fields.iter().map(|(_, point)| point))
+}
+
+/// 按注入方式分组的依赖键
+#[derive(Default)]
+pub(crate) struct SplitDependencies {
+    pub eager: Vec<String>,
+    pub lazy: Vec<String>,
+    pub provider: Vec<String>,
+    pub optional: Vec<String>,
+    pub collection: Vec<String>,
+}
+
+pub(crate) fn split_dependencies<'a>(points: impl IntoIterator<Item = &'a InjectionPoint>) -> SplitDependencies {
+    let mut split = SplitDependencies::default();
+    for point in points {
+        let group = if point.collection {
+            &mut split.collection
+        } else if lazy_inner(&point.ty).is_some() {
+            &mut split.lazy
+        } else if provider_inner(&point.ty).is_some() {
+            &mut split.provider
+        } else if option_inner(&point.ty).is_some() {
+            &mut split.optional
+        } else {
+            &mut split.eager
+        };
+        group.push(point.key.clone());
+    }
+    split
+}
+
+/// `Lazy<T>` → T
+fn lazy_inner(ty: &Type) -> Option<Type> {
+    generic_inner(ty, &["Lazy"])
+}
+
+/// `ObjectProvider<T>` / `Provider<T>` / `LazyBean<T>` → T，都注入获取函数
+fn provider_inner(ty: &Type) -> Option<Type> {
+    generic_inner(ty, &["ObjectProvider", "Provider", "LazyBean"])
+}
+
+/// `LazyBean<T>` → T
+fn lazy_bean_inner(ty: &Type) -> Option<Type> {
+    generic_inner(ty, &["LazyBean"])
+}
+
+/// `Option<P>` → P
+fn option_inner(ty: &Type) -> Option<Type> {
+    generic_inner(ty, &["Option"])
+}
+
+fn generic_inner(ty: &Type, wrappers: &[&str]) -> Option<Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    if !wrappers.iter().any(|wrapper| segment.ident == wrapper) {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(inner) => Some(inner.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// "singleton" / "prototype" 为内置作用域，其余名称（request、session、thread、自定义）
+/// 生成 `BeanScope::Custom("name")`，运行时在容器中按名称查找对应的 Scope
+pub(crate) fn build_scope_token(scope: &str) -> Result<proc_macro2::TokenStream, String> {
+    match scope {
+        "singleton" => Ok(quote! { spring_beans::factory::config::BeanScope::Singleton }),
+        "prototype" => Ok(quote! { spring_beans::factory::config::BeanScope::Prototype }),
+        "" => Err("scope name must not be empty".to_string()),
+        other if other.chars().any(char::is_whitespace) => {
+            Err(format!("scope name must not contain whitespace, got \"{}\"", other))
+        }
+        other => Ok(quote! { spring_beans::factory::config::BeanScope::Custom(#other) }),
+    }
+}
+
+/// 生成 `WrapFn`：`Send + Sync` 类型存为 `Arc`，其余存为 `Rc`（编译期探测）
+pub(crate) fn build_wrap_fn(ty: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use spring_beans::bean::bean_wrapper::{WrapLocal as _, WrapShared as _};
+            (&spring_beans::bean::bean_wrapper::WrapProbe::<#ty>::new()).wrap_fn()
+        }
+    }
+}
+
+/// 生成创建实例的表达式与构造函数依赖：有 `#[Autowired]` 构造函数时调用它，
+/// 否则调用 `Default::default()`（编译期探测）
+fn build_constructor_probe(ident: &Ident, name_lit: &LitStr) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let probe = quote! {
+        #[allow(unused_imports)]
+        use spring_beans::bean::constructor::{ConstructAutowired as _, ConstructDefault as _};
+        let probe = spring_beans::bean::constructor::ConstructorProbe::<#ident>::new();
+    };
+    (
+        quote! { { #probe (&probe).construct(#name_lit, resolved_deps, _env)? } },
+        quote! { { #probe (&probe).constructor_dependencies() } },
+    )
+}
+
+enum SharedPointer {
+    Arc,
+    Rc,
+}
+
+/// `Arc<T>` / `std::sync::Arc<T>` → (Arc, T)；`Rc<T>` → (Rc, T)
+fn shared_pointer_kind(ty: &Type) -> Option<(SharedPointer, Type)> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    let kind = match segment.ident.to_string().as_str() {
+        "Arc" => SharedPointer::Arc,
+        "Rc" => SharedPointer::Rc,
+        _ => return None,
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(inner) => Some((kind, inner.clone())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 /// 收集带 #[Value("${key:default}")] 的字段：(字段名 Ident, placeholder String, 字段类型 Type)
 fn collect_value_fields(input: &ItemStruct) -> Vec<(Ident, String, Type)> {
     let mut result = Vec::new();
@@ -208,78 +716,79 @@ fn collect_value_fields(input: &ItemStruct) -> Vec<(Ident, String, Type)> {
     result
 }
 
-/// 为每个 #[Value("${key:default}")] 字段生成 env 读取 + parse 注入语句
-fn build_value_inject_stmts(input: &ItemStruct) -> Vec<proc_macro2::TokenStream> {
-    collect_value_fields(input)
-        .into_iter()
-        .map(|(field_ident, placeholder, field_ty)| {
-            let placeholder_lit = LitStr::new(&placeholder, Span::call_site());
-
+/// 为 #[Value] 字段生成注入回调：实例创建之后由容器连同 `Environment` 一起调用（与
+/// `#[ConfigurationProperties]` 共用 `property_binder` 钩子），没有 #[Value] 字段时为空。
+///
+/// `${...}` 由 `Environment` 解析：支持嵌入文本、嵌套与递归的占位符，并遵循
+/// `ignore_unresolvable_nested_placeholders`；`#{...}` 交给 SpEL 求值。
+/// 结果经共享的 `GenericConversionService` 转换，实现了 `FromStr` 的类型可回退到 `from_str`。
+/// 解析、求值或转换失败时返回 `BeansError`
+fn build_value_binder(ident: &Ident, input: &ItemStruct, bean_name: &LitStr) -> proc_macro2::TokenStream {
+    let value_fields = collect_value_fields(input);
+    if value_fields.is_empty() {
+        return quote! {};
+    }
+    let inject_stmts = value_fields.into_iter().map(|(field_ident, placeholder, field_ty)| {
+        let placeholder_lit = LitStr::new(&placeholder, Span::call_site());
+        let raw = match placeholder.strip_prefix("#{").and_then(|s| s.strip_suffix('}')) {
             // ── SpEL path: #{expr} ──────────────────────────────────────
-            if let Some(spel_expr) = placeholder
-                .strip_prefix("#{")
-                .and_then(|s| s.strip_suffix('}'))
-            {
+            Some(spel_expr) => {
                 let spel_lit = LitStr::new(spel_expr.trim(), Span::call_site());
-                return quote! {
-                    instance.#field_ident = {
-                        let _raw = spring_boot::spel::eval(#spel_lit, env)
-                            .unwrap_or_else(|e| panic!(
-                                "#[Value] SpEL evaluation failed for expression '{}': {}",
-                                #spel_lit, e
-                            ));
-                        _raw.parse().unwrap_or_else(|_| panic!(
-                            "#[Value] failed to parse SpEL result '{}' (expression '{}') as {}",
-                            _raw,
-                            #spel_lit,
-                            stringify!(#field_ty)
-                        ))
-                    };
-                };
+                quote! {
+                    spring_boot::spel::eval(#spel_lit, &env.as_map())
+                        .map_err(|e| spring_beans::error::BeansError::BeanCreation {
+                            bean_name: #bean_name.to_string(),
+                            message: format!("#[Value] SpEL evaluation failed for expression '{}'", #spel_lit),
+                            cause: Some(e.into()),
+                        })?
+                }
             }
-
             // ── property placeholder path: ${key:default} ───────────────
-            let (key, default_val) = parse_placeholder(&placeholder);
-            let key_lit = LitStr::new(&key, Span::call_site());
-            let default_lit = LitStr::new(&default_val, Span::call_site());
-            quote! {
-                instance.#field_ident = {
-                    let _raw = env
-                        .get(#key_lit)
-                        .map(|s| s.as_str())
-                        .unwrap_or(#default_lit);
-                    _raw.parse().unwrap_or_else(|_| panic!(
-                        "#[Value] failed to parse property '{}' (placeholder '{}') as {}",
-                        #key_lit,
-                        #placeholder_lit,
-                        stringify!(#field_ty)
-                    ))
+            None => quote! {
+                spring_beans::env::PropertyResolver::resolve_required_placeholders(env, #placeholder_lit)
+                    .map_err(|e| spring_beans::error::BeansError::BeanCreation {
+                        bean_name: #bean_name.to_string(),
+                        message: format!("could not resolve #[Value(\"{}\")]", #placeholder_lit),
+                        cause: Some(e.into()),
+                    })?
+            },
+        };
+        quote! {
+            instance.#field_ident = {
+                let _raw: String = #raw;
+                (&spring_beans::convert::ConversionProbe::<#field_ty>::new()).convert_str(&_raw).map_err(|_| spring_beans::error::BeansError::value_type_mismatch(
+                    #bean_name,
+                    #placeholder_lit,
+                    &_raw,
+                    stringify!(#field_ty),
+                ))?
+            };
+        }
+    });
+    quote! {
+        definition.set_property_binder(Some(
+            (|bean: &mut dyn std::any::Any, env: &spring_beans::env::Environment| {
+                #[allow(unused_imports)]
+                use spring_beans::convert::{ViaConversionService as _, ViaFromStr as _};
+                let Some(instance) = bean.downcast_mut::<#ident>() else {
+                    return Ok(());
                 };
-            }
-        })
-        .collect()
-}
-
-/// Parse `${key:default}` or `${key}` into (key, default) where default is "" when absent.
-fn parse_placeholder(placeholder: &str) -> (String, String) {
-    let inner = placeholder
-        .strip_prefix("${")
-        .and_then(|s| s.strip_suffix('}'))
-        .unwrap_or(placeholder);
-    if let Some(pos) = inner.find(':') {
-        (inner[..pos].to_string(), inner[pos + 1..].to_string())
-    } else {
-        (inner.to_string(), String::new())
+                #(#inject_stmts)*
+                Ok(())
+            }) as spring_beans::env::bind::BindFn,
+        ));
     }
 }
 
-
 #[derive(Default)]
 struct ComponentArgs {
     name: Option<String>,
     scope: Option<String>,
     lazy: Option<bool>,
     deps: Vec<String>,
+    provides: Vec<Type>,
+    init_method: Option<Ident>,
+    destroy_method: Option<Ident>,
 }
 
 fn parse_component_args(attribute: TokenStream) -> syn::Result<ComponentArgs> {
@@ -300,6 +809,20 @@ fn parse_component_args(attribute: TokenStream) -> syn::Result<ComponentArgs> {
             args.lazy = Some(value.value());
             return Ok(());
         }
+        if meta.path.is_ident("init_method") {
+            let value: LitStr = meta.value()?.parse()?;
+            args.init_method = Some(value.parse()?);
+            return Ok(());
+        }
+        if meta.path.is_ident("destroy_method") {
+            let value: LitStr = meta.value()?.parse()?;
+            args.destroy_method = Some(value.parse()?);
+            return Ok(());
+        }
+        if meta.path.is_ident("provides") {
+            args.provides.extend(parse_provides(&meta)?);
+            return Ok(());
+        }
         if meta.path.is_ident("deps") {
             let expr: Expr = meta.value()?.parse()?;
             match expr {
@@ -338,12 +861,13 @@ fn default_bean_name(ident: &syn::Ident) -> String {
 }
 
 
-fn extract_dependency_name(ty: &Type) -> Option<String> {
+pub(crate) fn extract_dependency_name(ty: &Type) -> Option<String> {
     match ty {
+        Type::Reference(reference) => extract_dependency_name(&reference.elem),
         Type::Path(path) => {
-            let segment = path.path.segments.first()?;
+            let segment = path.path.segments.last()?;
             let ident = segment.ident.to_string();
-            if ident == "Option" || ident == "Box" {
+            if matches!(ident.as_str(), "Option" | "Box" | "Arc" | "Rc" | "Lazy" | "LazyBean" | "ObjectProvider" | "Provider") {
                 if let PathArguments::AngleBracketed(args) = &segment.arguments {
                     let inner = args.args.first()?;
                     if let GenericArgument::Type(inner_ty) = inner {
@@ -381,7 +905,7 @@ fn is_primitive_type(ident: &str) -> bool {
     )
 }
 
-fn lowercase_first(raw: &str) -> String {
+pub(crate) fn lowercase_first(raw: &str) -> String {
     let mut chars = raw.chars();
     match chars.next() {
         Some(first) => format!("{}{}", first.to_lowercase(), chars.collect::<String>()),
@@ -399,11 +923,19 @@ fn strip_helper_attrs(mut input: ItemStruct) -> ItemStruct {
             && !attr.path().is_ident("Lazy")
             && !attr.path().is_ident("lazy")
             && !attr.path().is_ident("ConditionalOnProperty")
+            && !attr.path().is_ident("Profile")
+            && !attr.path().is_ident("Primary")
+            && !attr.path().is_ident("Order")
     });
-    // 剥离字段上的 #[autowired]
+    // 剥离字段上的 #[autowired] / #[Qualifier] / #[Value]
     if let Fields::Named(ref mut fields) = input.fields {
         for field in fields.named.iter_mut() {
-            field.attrs.retain(|attr| !attr.path().is_ident("autowired") && !attr.path().is_ident("Value") && !attr.path().is_ident("value"));
+            field.attrs.retain(|attr| {
+                !attr.path().is_ident("autowired")
+                    && !attr.path().is_ident("Qualifier")
+                    && !attr.path().is_ident("Value")
+                    && !attr.path().is_ident("value")
+            });
         }
     }
     input
@@ -413,7 +945,8 @@ fn strip_helper_attrs(mut input: ItemStruct) -> ItemStruct {
 fn extract_scope_attr(attrs: &[Attribute]) -> Option<String> {
     for attr in attrs {
         if attr.path().is_ident("Scope") || attr.path().is_ident("scope") {
-            // 支持两种语法: #[Scope("prototype")] 和 #[Scope = "prototype"]
+            // 支持两种语法: #[Scope("prototype")] 和 #[Scope = "prototype"]；
+            // 名称也可以是 "request"、"session"、"thread" 或自定义作用域
             if let Ok(lit) = attr.parse_args::<LitStr>() {
                 return Some(lit.value());
             }
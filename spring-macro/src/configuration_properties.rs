@@ -0,0 +1,189 @@
+/// `#[ConfigurationProperties(prefix = "app.datasource")]` / `#[derive(Bindable)]`
+///
+/// `#[derive(Bindable)]` 为结构体实现 `Bindable` 与 `Bind`：每个具名字段按
+/// `prefix.field-name` 绑定，字段类型需实现 `Bind`（标量、`Option`、`Vec`、
+/// `HashMap<String, _>`，或同样 derive 了 `Bindable` 的嵌套结构体），
+/// 或者是能由 `ConversionService` 转换（注册了 `str → T` 转换器 / 实现了 `FromStr`）的自定义标量。
+///
+/// `#[ConfigurationProperties]` 额外把结构体注册为 singleton bean：容器先用 `Default`
+/// 创建实例，再把 `prefix` 下的属性绑定上去，所有绑定失败汇总为一个启动错误。
+/// 结构体同时 `#[derive(Validate)]` 时，绑定后立即校验，违规同样导致启动失败。
+///
+/// ```rust,ignore
+/// #[ConfigurationProperties(prefix = "app.datasource")]
+/// #[derive(Debug, Default)]
+/// struct DataSourceProperties {
+///     url: String,
+///     max_pool_size: u32,          // app.datasource.max-pool-size
+///     connect_timeout: Duration,   // app.datasource.connect-timeout=30s
+///     pool: PoolProperties,        // #[derive(Bindable)]
+/// }
+/// ```
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::parse::Parser;
+use syn::{parse_macro_input, DeriveInput, Fields, ItemStruct, LitStr};
+
+pub fn bindable_derive_impl(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    let fields = match &input.data {
+        syn::Data::Struct(data) => &data.fields,
+        _ => {
+            return syn::Error::new_spanned(&input.ident, "#[derive(Bindable)] only supports structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+    match build_bindable_impls(&input.ident, &input.generics, fields) {
+        Ok(impls) => impls.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+pub fn configuration_properties_impl(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemStruct);
+    let prefix = match parse_prefix(attribute) {
+        Ok(prefix) => prefix,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    if !input.generics.params.is_empty() {
+        return syn::Error::new_spanned(&input.generics, "#[ConfigurationProperties] struct cannot be generic")
+            .to_compile_error()
+            .into();
+    }
+    let ident = &input.ident;
+    let bindable = match build_bindable_impls(ident, &input.generics, &input.fields) {
+        Ok(impls) => impls,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let name = crate::component::lowercase_first(&ident.to_string());
+    let name_lit = LitStr::new(&name, Span::call_site());
+    let wrap_fn = crate::component::build_wrap_fn(&quote! { #ident });
+    let (init_fn, destroy_fn) = crate::lifecycle::build_lifecycle_fns(&quote! { #ident }, None, None);
+    let set_profiles = match crate::profile::extract_profile_attr(&input.attrs) {
+        Ok(profiles) => crate::profile::build_set_profiles(&profiles),
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let mut clean_input = input.clone();
+    clean_input.attrs.retain(|attr| !attr.path().is_ident("Profile"));
+    let expanded = quote! {
+        #clean_input
+        #bindable
+
+        impl #ident {
+            pub fn bean_name() -> &'static str {
+                #name_lit
+            }
+
+            pub fn bean_definition() -> spring_beans::factory::config::RootBeanDefinition {
+                let mut definition = spring_beans::factory::config::RootBeanDefinition::new(
+                    #name_lit.to_string(),
+                    std::any::TypeId::of::<#ident>(),
+                    spring_beans::factory::config::BeanScope::Singleton,
+                    false,
+                    vec![],
+                    Box::new(|_: &spring_beans::factory::config::ResolvedDependencies, _: &std::collections::HashMap<String, String>| {
+                        Ok(Box::new(<#ident as ::std::default::Default>::default()) as Box<dyn std::any::Any>)
+                    }),
+                    None,
+                    #wrap_fn,
+                );
+                definition.set_property_binder(Some(
+                    (|bean: &mut dyn std::any::Any, env: &spring_beans::env::Environment| {
+                        spring_beans::env::bind::bind_bean::<#ident>(#name_lit, #prefix, bean, env)?;
+                        // 实现了 Validate（#[derive(Validate)]）时校验绑定结果
+                        #[allow(unused_imports)]
+                        use spring_boot::validation::{NoValidate as _, ViaValidate as _};
+                        (&spring_boot::validation::ValidateProbe::<#ident>::new()).validate_bean(#name_lit, #prefix, bean)
+                    }) as spring_beans::env::bind::BindFn,
+                ));
+                definition.set_init_method(Some(#init_fn));
+                definition.set_destroy_method(Some(#destroy_fn));
+                #set_profiles
+                definition
+            }
+        }
+
+        inventory::submit! {
+            spring_beans::registry::BeanRegistration {
+                definition: || #ident::bean_definition(),
+            }
+        }
+    };
+    expanded.into()
+}
+
+/// `#[ConfigurationProperties("app")]` 或 `#[ConfigurationProperties(prefix = "app")]`
+fn parse_prefix(attribute: TokenStream) -> syn::Result<LitStr> {
+    let mut prefix = None;
+    if let Ok(lit) = syn::parse::<LitStr>(attribute.clone()) {
+        prefix = Some(lit);
+    } else {
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("prefix") {
+                prefix = Some(meta.value()?.parse()?);
+                return Ok(());
+            }
+            Err(meta.error("unsupported ConfigurationProperties attribute, expected `prefix`"))
+        });
+        parser.parse(attribute)?;
+    }
+    let prefix = prefix.ok_or_else(|| {
+        syn::Error::new(Span::call_site(), "#[ConfigurationProperties] needs a prefix, e.g. prefix = \"app.datasource\"")
+    })?;
+    let value = prefix.value();
+    let canonical = value.split('.').all(|segment| {
+        !segment.is_empty() && segment.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    });
+    if !canonical {
+        return Err(syn::Error::new_spanned(
+            &prefix,
+            "prefix must be in canonical form: lowercase, '.' separated, words joined by '-' (e.g. \"app.data-source\")",
+        ));
+    }
+    Ok(prefix)
+}
+
+fn build_bindable_impls(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    fields: &Fields,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let Fields::Named(fields) = fields else {
+        return Err(syn::Error::new_spanned(ident, "configuration properties need named fields"));
+    };
+    let bind_fields = fields.named.iter().map(|field| {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let field_ty = &field.ty;
+        let field_name = LitStr::new(&field_ident.to_string(), Span::call_site());
+        quote! {
+            let name = binder.field_name(prefix, #field_name);
+            if let Some(value) = (&spring_beans::env::bind::BindProbe::<#field_ty>::new()).bind_field(binder, &name) {
+                self.#field_ident = value;
+                bound = true;
+            }
+        }
+    });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics spring_beans::env::bind::Bindable for #ident #ty_generics #where_clause {
+            fn bind_properties(&mut self, binder: &mut spring_beans::env::bind::Binder<'_>, prefix: &str) -> bool {
+                #[allow(unused_imports)]
+                use spring_beans::env::bind::{ViaBind as _, ViaConversionBind as _, ViaFromStrBind as _};
+                #[allow(unused_mut)]
+                let mut bound = false;
+                #(#bind_fields)*
+                bound
+            }
+        }
+
+        impl #impl_generics spring_beans::env::bind::Bind for #ident #ty_generics #where_clause {
+            fn bind(binder: &mut spring_beans::env::bind::Binder<'_>, name: &str) -> Option<Self> {
+                let mut value = <Self as ::std::default::Default>::default();
+                spring_beans::env::bind::Bindable::bind_properties(&mut value, binder, name).then_some(value)
+            }
+        }
+    })
+}
@@ -0,0 +1,155 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Attribute, FnArg, ImplItem, ItemImpl, Pat};
+
+use crate::component::{dependency_expr, extract_qualifier, injection_point, split_dependencies, SplitDependencies};
+
+/// 解析出的函数参数注入：调用实参表达式、(普通, `Lazy<T>`, `ObjectProvider<T>`, `Option<..>`, 集合) 依赖名
+/// 与按类型注入的参数的 `DependencyDescriptor` 表达式
+pub(crate) struct ParameterInjection {
+    pub args: Vec<proc_macro2::TokenStream>,
+    pub dependencies: Vec<String>,
+    pub lazy_dependencies: Vec<String>,
+    pub provider_dependencies: Vec<String>,
+    pub optional_dependencies: Vec<String>,
+    pub collection_dependencies: Vec<String>,
+    pub descriptors: Vec<proc_macro2::TokenStream>,
+}
+
+/// `#[Autowired]` 构造函数与 `#[Bean]` 函数的参数：标注 `#[Qualifier("name")]` 时按名称注入，
+/// 否则按参数类型注入（与 `#[autowired]` 字段相同）。读取后剥离参数上的 `#[Qualifier]`。
+///
+/// `owner` 是求值为注入方 bean 名称（`&str`）的表达式，用于错误信息
+pub(crate) fn build_parameter_injection(
+    inputs: &mut Punctuated<FnArg, Comma>,
+    owner: &proc_macro2::TokenStream,
+) -> syn::Result<ParameterInjection> {
+    let mut args = Vec::new();
+    let mut points = Vec::new();
+    let mut descriptors = Vec::new();
+    for input in inputs.iter_mut() {
+        let FnArg::Typed(param) = input else {
+            return Err(syn::Error::new_spanned(input, "injected functions cannot take `self`"));
+        };
+        let qualifier = extract_qualifier(&param.attrs)?;
+        param.attrs.retain(|attr| !attr.path().is_ident("Qualifier"));
+        let pat = &param.pat;
+        let label = quote!(#pat).to_string();
+        // 非标识符模式（如元组解构）没有参数名，按类型推断的 bean 名称作键
+        let property = match param.pat.as_ref() {
+            Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
+            _ => String::new(),
+        };
+        let Some(point) = injection_point(&param.ty, qualifier, &property) else {
+            return Err(syn::Error::new_spanned(
+                &param.ty,
+                format!("cannot infer the bean to inject into `{}`; add #[Qualifier(\"beanName\")]", label),
+            ));
+        };
+        args.push(dependency_expr(&point, owner, &label));
+        descriptors.extend(point.descriptor.clone());
+        points.push(point);
+    }
+    let SplitDependencies { eager, lazy, provider, optional, collection } = split_dependencies(&points);
+    Ok(ParameterInjection {
+        args,
+        dependencies: eager,
+        lazy_dependencies: lazy,
+        provider_dependencies: provider,
+        optional_dependencies: optional,
+        collection_dependencies: collection,
+        descriptors,
+    })
+}
+
+fn is_autowired(attr: &Attribute) -> bool {
+    attr.path().is_ident("Autowired") || attr.path().is_ident("autowired")
+}
+
+/// `#[Component] impl Foo { #[Autowired] fn new(repo: Arc<Repo>, cfg: &AppConfig) -> Self { .. } }`
+///
+/// 为 `Foo` 实现 `AutowiredConstructor`；struct 上的 `#[Component]` 在编译期探测到它后
+/// 改用构造函数创建实例，因此 `Foo` 不再需要实现 `Default`。
+pub fn component_constructor_impl(attribute: TokenStream, mut input: ItemImpl) -> TokenStream {
+    if !attribute.is_empty() {
+        return syn::Error::new(Span::call_site(), "#[Component] on an impl block takes no arguments; configure the bean on its struct")
+            .to_compile_error()
+            .into();
+    }
+    if input.trait_.is_some() {
+        return syn::Error::new_spanned(&input.self_ty, "#[Component] constructors must be declared in an inherent impl block")
+            .to_compile_error()
+            .into();
+    }
+    let mut constructors = input.items.iter_mut().filter_map(|item| match item {
+        ImplItem::Fn(method) if method.attrs.iter().any(is_autowired) => Some(method),
+        _ => None,
+    });
+    let Some(constructor) = constructors.next() else {
+        return syn::Error::new_spanned(&input.self_ty, "#[Component] impl block must contain an #[Autowired] constructor")
+            .to_compile_error()
+            .into();
+    };
+    if let Some(other) = constructors.next() {
+        return syn::Error::new_spanned(&other.sig, "only one #[Autowired] constructor is allowed per bean")
+            .to_compile_error()
+            .into();
+    }
+    constructor.attrs.retain(|attr| !is_autowired(attr));
+    let fn_ident = constructor.sig.ident.clone();
+    let injection = match build_parameter_injection(&mut constructor.sig.inputs, &quote! { bean_name }) {
+        Ok(injection) => injection,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let ParameterInjection {
+        args,
+        dependencies,
+        lazy_dependencies,
+        provider_dependencies,
+        optional_dependencies,
+        collection_dependencies,
+        descriptors,
+    } = injection;
+
+    let self_ty = &input.self_ty;
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+    let expanded = quote! {
+        #input
+
+        impl #impl_generics spring_beans::bean::constructor::AutowiredConstructor for #self_ty #where_clause {
+            fn constructor_dependencies() -> spring_beans::bean::constructor::ConstructorDependencies {
+                spring_beans::bean::constructor::ConstructorDependencies {
+                    dependencies: vec![#(#dependencies.to_string()),*],
+                    lazy_dependencies: vec![#(#lazy_dependencies.to_string()),*],
+                    provider_dependencies: vec![#(#provider_dependencies.to_string()),*],
+                    optional_dependencies: vec![#(#optional_dependencies.to_string()),*],
+                    collection_dependencies: vec![#(#collection_dependencies.to_string()),*],
+                    descriptors: vec![#(#descriptors),*],
+                }
+            }
+
+            fn construct(
+                bean_name: &str,
+                resolved_deps: &spring_beans::factory::config::ResolvedDependencies,
+                _env: &std::collections::HashMap<String, String>,
+            ) -> Result<Self, spring_beans::error::BeansError> {
+                Ok(Self::#fn_ident(#(#args),*))
+            }
+        }
+    };
+    expanded.into()
+}
+
+/// 单独使用的 `#[Autowired]`：构造函数必须放在 `#[Component] impl` 块中才会被容器调用
+pub fn autowired_impl(item: TokenStream) -> TokenStream {
+    let item = proc_macro2::TokenStream::from(item);
+    let error = syn::Error::new(
+        Span::call_site(),
+        "#[Autowired] constructors must be declared inside a `#[Component] impl` block",
+    )
+    .to_compile_error();
+    quote! { #error #item }.into()
+}
@@ -0,0 +1,116 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{FnArg, ImplItem, ItemImpl, Type};
+
+// ── #[EventListener] ──────────────────────────────────────────────────────────
+//
+// 方法级属性宏拿不到所在的类型，所以需要同时标注在 `impl` 块上：
+// 外层的 #[EventListener] 扫描块内带 #[EventListener] / #[EventListener(async)]
+// 的方法，剥离这些标记，并为每个方法提交一条 EventListenerRegistration。
+//
+//   #[EventListener]
+//   impl AuditLog {
+//       #[EventListener]
+//       fn on_refresh(&self, event: &ContextRefreshedEvent) { ... }
+//
+//       #[EventListener(async)]
+//       fn on_order(&self, event: &OrderPlaced) { ... }
+//   }
+//
+// 事件类型由第二个参数 `&E` 决定，只有 `E` 类型的事件会分发给该方法。
+
+pub fn event_listener_impl(_attribute: TokenStream, item: TokenStream) -> TokenStream {
+    let mut impl_block = match syn::parse::<ItemImpl>(item) {
+        Ok(impl_block) => impl_block,
+        Err(err) => {
+            return syn::Error::new(
+                err.span(),
+                "#[EventListener] methods need #[EventListener] on the enclosing impl block as well",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+    let self_ty = impl_block.self_ty.clone();
+
+    let mut registrations = Vec::new();
+    for impl_item in &mut impl_block.items {
+        let ImplItem::Fn(method) = impl_item else { continue };
+        let Some(pos) = method.attrs.iter().position(|a| a.path().is_ident("EventListener")) else {
+            continue;
+        };
+        let attr = method.attrs.remove(pos);
+        let is_async = match parse_listener_args(&attr) {
+            Ok(is_async) => is_async,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        let event_ty = match listener_event_type(method) {
+            Ok(ty) => ty,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        let method_ident = &method.sig.ident;
+
+        // async 监听器会在线程池中使用 bean，要求 bean 可以跨线程共享
+        let send_sync_check = is_async.then(|| {
+            quote! {
+                fn assert_send_sync<T: Send + Sync>() {}
+                assert_send_sync::<#self_ty>();
+            }
+        });
+
+        registrations.push(quote! {
+            const _: fn() = || {
+                fn assert_event<E: spring_boot::event::ApplicationEvent>() {}
+                assert_event::<#event_ty>();
+                #send_sync_check
+            };
+
+            inventory::submit! {
+                spring_boot::event::EventListenerRegistration {
+                    bean_type: || std::any::TypeId::of::<#self_ty>(),
+                    event_type: || std::any::TypeId::of::<#event_ty>(),
+                    is_async: #is_async,
+                    handler: |bean: &dyn std::any::Any, event: &dyn spring_boot::event::ApplicationEvent| {
+                        let event: &dyn std::any::Any = event;
+                        if let (Some(bean), Some(event)) = (bean.downcast_ref::<#self_ty>(), event.downcast_ref::<#event_ty>()) {
+                            bean.#method_ident(event);
+                        }
+                    },
+                }
+            }
+        });
+    }
+
+    quote! {
+        #impl_block
+        #(#registrations)*
+    }
+    .into()
+}
+
+/// `#[EventListener]` → false，`#[EventListener(async)]` → true
+fn parse_listener_args(attr: &syn::Attribute) -> syn::Result<bool> {
+    match &attr.meta {
+        syn::Meta::Path(_) => Ok(false),
+        syn::Meta::List(list) if list.tokens.to_string() == "async" => Ok(true),
+        _ => Err(syn::Error::new_spanned(attr, "expected #[EventListener] or #[EventListener(async)]")),
+    }
+}
+
+/// 监听方法的签名必须是 `fn name(&self, event: &E)`，返回 `E`
+fn listener_event_type(method: &syn::ImplItemFn) -> syn::Result<Type> {
+    let inputs: Vec<&FnArg> = method.sig.inputs.iter().collect();
+    if let [FnArg::Receiver(receiver), FnArg::Typed(event)] = inputs.as_slice() {
+        if receiver.reference.is_some() && receiver.mutability.is_none() {
+            if let Type::Reference(reference) = event.ty.as_ref() {
+                if reference.mutability.is_none() {
+                    return Ok((*reference.elem).clone());
+                }
+            }
+        }
+    }
+    Err(syn::Error::new_spanned(
+        &method.sig,
+        "#[EventListener] method must have the signature `fn name(&self, event: &EventType)`",
+    ))
+}
@@ -1,6 +1,7 @@
 use proc_macro::TokenStream;
 
 mod component;
+mod constructor;
 mod bean;
 mod value;
 mod data;
@@ -13,25 +14,74 @@ mod aspect;
 mod aop_methods;
 mod repository;
 mod web;
+mod lifecycle;
+mod event_listener;
+mod profile;
+mod configuration_properties;
+mod validate;
 #[proc_macro_attribute]
 pub fn component(attribute: TokenStream, item: TokenStream) -> TokenStream {
     component::component_impl(attribute, item)
 }
 
 /// derive macro 内部别名（保持向后兼容）
-#[proc_macro_derive(ComponentDerive, attributes(autowired))]
+#[proc_macro_derive(ComponentDerive, attributes(autowired, Qualifier, Primary, Order))]
     pub fn component_derive(item: TokenStream) -> TokenStream {
     component::component_derive_impl(item)
 }
 
-/// #[Component] attribute macro —— Spring 风格的主入口，自动处理 #[autowired] 字段注入
+/// #[Component] attribute macro —— Spring 风格的主入口，自动处理 #[autowired] 字段注入；
+/// 标注在 impl 块上时声明其中的 #[Autowired] 构造函数
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
     pub fn Component(attribute: TokenStream, item: TokenStream) -> TokenStream {
     component::component_impl(attribute, item)
 }
 
+/// #[Autowired] —— 标注在 `#[Component] impl` 块中的构造函数上，参数按类型或
+/// `#[Qualifier("name")]` 从容器注入：
+///
+/// ```rust,ignore
+/// #[Component]
+/// impl UserService {
+///     #[Autowired]
+///     fn new(repo: Arc<UserRepo>, #[Qualifier("appConfig")] cfg: &AppConfig) -> Self { .. }
+/// }
+/// ```
+///
+/// 真正逻辑由 impl 块上的 #[Component] 处理；单独使用时报错。
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn Autowired(_attribute: TokenStream, item: TokenStream) -> TokenStream {
+    constructor::autowired_impl(item)
+}
+
+/// #[Primary] —— 附加在 #[Component] struct 或 #[Bean] 函数上：同一类型有多个 bean 时优先注入这一个。
+/// 本宏仅作 helper attribute 使用，真正逻辑由 #[Component] / #[Bean] 处理。
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn Primary(_attribute: TokenStream, item: TokenStream) -> TokenStream {
+    item  // 透传，内容由 #[Component] / #[Bean] 处理
+}
+
+/// #[Order(n)] —— 附加在 #[Component] struct 或 #[Bean] 函数上：注入 `Vec<..>` / `HashMap<String, ..>`
+/// 集合时按 n 从小到大排列，未标注的 bean 排在最后。本宏仅作 helper attribute 使用。
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn Order(_attribute: TokenStream, item: TokenStream) -> TokenStream {
+    item  // 透传，内容由 #[Component] / #[Bean] 处理
+}
+
+/// #[Qualifier("beanName")] —— 附加在 #[autowired] 字段或注入参数上，按名称而不是按类型选择 bean。
+/// 字段与参数上的属性由 #[Component] / #[Bean] 读取并剥离，本宏仅用于文档与导出。
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn Qualifier(_attribute: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
 /// #[Scope("prototype")] / #[Scope("singleton")] —— 附加在 #[Component] struct 上，指定 bean 作用域
+/// 也可以是 #[Scope("request")] / #[Scope("session")] / #[Scope("thread")] 或任意已注册的自定义作用域
 /// 本宏仅作 helper attribute 使用，真正逻辑由 #[Component] 处理。
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
@@ -48,12 +98,28 @@ pub fn Lazy(_attribute: TokenStream, item: TokenStream) -> TokenStream {
 
 /// #[Bean] —— 方法级别注解，类似 Java @Bean。标注在函数上，函数返回值就是 bean 实例。
 /// 支持: #[Bean] / #[Bean(name="foo")] / #[Bean(scope="prototype")] / #[Bean(lazy=true)]
+/// / #[Bean(init_method="start", destroy_method="stop")] / #[Bean(provides = "dyn Trait")]
+/// 函数参数与 #[Autowired] 构造函数一样按类型或 #[Qualifier("name")] 从容器注入。
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
 pub fn Bean(attribute: TokenStream, item: TokenStream) -> TokenStream {
     bean::bean_impl(attribute, item)
 }
 
+/// #[PostConstruct] —— 标注在 impl 块的方法上，依赖注入完成后由容器调用
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn PostConstruct(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    lifecycle::post_construct_impl(attribute, item)
+}
+
+/// #[PreDestroy] —— 标注在 impl 块的方法上，容器 close() 时按依赖倒序调用
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn PreDestroy(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    lifecycle::pre_destroy_impl(attribute, item)
+}
+
 /// #[Value("${key:default}")] —— 字段级注解，从 Environment 注入配置值。
 /// 本宏仅作 helper attribute 使用，真正逻辑由 #[Component] 处理。
 #[proc_macro_attribute]
@@ -123,6 +189,14 @@ pub fn AopMethods(attribute: TokenStream, item: TokenStream) -> TokenStream {
     aop_methods::aop_methods_impl(attribute, item)
 }
 
+/// #[EventListener] —— 同时标注在 impl 块和其中的方法上，把方法注册为事件监听器。
+/// 方法签名为 `fn name(&self, event: &E)`；`#[EventListener(async)]` 在线程池中执行。
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn EventListener(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    event_listener::event_listener_impl(attribute, item)
+}
+
 /// #[ConditionalOnProperty("key", having = "value")] —— the bean is only registered
 /// when `application.properties` contains `key=value`.
 ///
@@ -134,6 +208,35 @@ pub fn ConditionalOnProperty(_attribute: TokenStream, item: TokenStream) -> Toke
     item
 }
 
+/// #[Profile("dev")] / #[Profile("!prod")] / #[Profile("dev", "test")]
+/// —— 只有在对应 profile 激活（或未激活）时才注册该 bean，可用于 #[Component] / #[Bean] / #[Repository]。
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn Profile(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    profile::profile_impl(attribute, item)
+}
+
+/// #[ConfigurationProperties(prefix = "app.datasource")] —— 把前缀下的属性绑定到 struct 的所有字段，并注册为 singleton bean。
+/// 字段名按 kebab-case 对应属性名（`max_pool_size` → `max-pool-size`），绑定失败会汇总报告。
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn ConfigurationProperties(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    configuration_properties::configuration_properties_impl(attribute, item)
+}
+
+/// #[derive(Bindable)] —— 用于 #[ConfigurationProperties] 中嵌套的 struct 字段类型
+#[proc_macro_derive(Bindable)]
+pub fn bindable_derive(item: TokenStream) -> TokenStream {
+    configuration_properties::bindable_derive_impl(item)
+}
+
+/// #[derive(Validate)] —— 字段约束 #[NotBlank] / #[Min(1)] / #[Max(10)] / #[Pattern("re")] / #[Email]
+/// / #[Size(min = 1, max = 10)]，#[Valid] 递归校验嵌套字段，结果为 `ConstraintViolations`
+#[proc_macro_derive(Validate, attributes(NotBlank, Email, Min, Max, Pattern, Size, Valid))]
+pub fn validate_derive(item: TokenStream) -> TokenStream {
+    validate::validate_derive_impl(item)
+}
+
 /// #[Repository(User)] / #[Repository(entity = "User")]
 /// 标注在空 struct 上，自动生成内存 CRUD 方法并注册为 IoC bean。
 #[proc_macro_attribute]
@@ -150,6 +253,10 @@ pub fn RestController(attribute: TokenStream, item: TokenStream) -> TokenStream
 }
 
 /// #[GetMapping("/path")] —— 注册 GET 路由
+///
+/// handler 可以是 `async fn`。`HttpServer` 在工作线程上把它执行到完成，没有 tokio 运行时，
+/// 其中使用 tokio 的 I/O、定时器或 `tokio::spawn` 会 panic（响应 500）；这类 handler 需要 `AsyncHttpServer`。
+/// 其它 `*Mapping` 宏相同。
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
 pub fn GetMapping(attribute: TokenStream, item: TokenStream) -> TokenStream {
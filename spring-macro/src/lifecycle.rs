@@ -0,0 +1,111 @@
+/// `#[PostConstruct]` / `#[PreDestroy]` —— 标注在 `impl` 块中的方法上。
+///
+/// 原方法保持不变，另外生成一个隐藏的固有方法 `__spring_post_construct` /
+/// `__spring_pre_destroy` 转发到它。`#[Component]` / `#[Bean]` 生成的生命周期回调
+/// 总是调用这两个名字；类型上没有标注时，会落到 `LifecycleFallback` 的空实现。
+///
+/// ```rust,ignore
+/// impl ConnectionPool {
+///     #[PostConstruct]
+///     fn open(&mut self) -> Result<(), std::io::Error> { ... }
+///
+///     #[PreDestroy]
+///     fn close(&self) { ... }
+/// }
+/// ```
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, FnArg, ImplItemFn};
+
+pub fn post_construct_impl(_attribute: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ImplItemFn);
+    if let Err(err) = check_receiver(&input, true, "#[PostConstruct]") {
+        return err.to_compile_error().into();
+    }
+    let method = &input.sig.ident;
+    let expanded = quote! {
+        #input
+
+        #[doc(hidden)]
+        pub fn __spring_post_construct(&mut self) -> spring_beans::bean::lifecycle::LifecycleResult {
+            spring_beans::bean::lifecycle::IntoLifecycleResult::into_lifecycle_result(self.#method())
+        }
+    };
+    expanded.into()
+}
+
+pub fn pre_destroy_impl(_attribute: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ImplItemFn);
+    if let Err(err) = check_receiver(&input, false, "#[PreDestroy]") {
+        return err.to_compile_error().into();
+    }
+    let method = &input.sig.ident;
+    let expanded = quote! {
+        #input
+
+        #[doc(hidden)]
+        pub fn __spring_pre_destroy(&self) -> spring_beans::bean::lifecycle::LifecycleResult {
+            spring_beans::bean::lifecycle::IntoLifecycleResult::into_lifecycle_result(self.#method())
+        }
+    };
+    expanded.into()
+}
+
+/// 生命周期方法只能接收 `&self`（`#[PostConstruct]` 也允许 `&mut self`），不能有其它参数
+fn check_receiver(input: &ImplItemFn, allow_mut: bool, attr: &str) -> syn::Result<()> {
+    let mut inputs = input.sig.inputs.iter();
+    let ok = match (inputs.next(), inputs.next()) {
+        (Some(FnArg::Receiver(receiver)), None) => {
+            receiver.reference.is_some() && (allow_mut || receiver.mutability.is_none())
+        }
+        _ => false,
+    };
+    if ok {
+        return Ok(());
+    }
+    let expected = if allow_mut { "`&self` or `&mut self`" } else { "`&self`" };
+    Err(syn::Error::new_spanned(
+        &input.sig,
+        format!("{} method must take {} and no other arguments", attr, expected),
+    ))
+}
+
+/// 生成 `(init_fn, destroy_fn)` 两个表达式，类型分别为 `InitFn` / `DestroyFn`。
+///
+/// 初始化顺序与 Spring 一致：`#[PostConstruct]` → `InitializingBean` → 自定义 `init_method`；
+/// 销毁顺序：`#[PreDestroy]` → `DisposableBean` → 自定义 `destroy_method`。
+pub(crate) fn build_lifecycle_fns(
+    ty: &proc_macro2::TokenStream,
+    init_method: Option<&syn::Ident>,
+    destroy_method: Option<&syn::Ident>,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let custom_init = init_method.map(|m| {
+        quote! { spring_beans::bean::lifecycle::IntoLifecycleResult::into_lifecycle_result(bean.#m())?; }
+    });
+    let custom_destroy = destroy_method.map(|m| {
+        quote! { spring_beans::bean::lifecycle::IntoLifecycleResult::into_lifecycle_result(bean.#m())?; }
+    });
+    let init_fn = quote! {
+        (|bean: &mut dyn std::any::Any| -> spring_beans::bean::lifecycle::LifecycleResult {
+            #[allow(unused_imports)]
+            use spring_beans::bean::lifecycle::{LifecycleFallback as _, NoInitializingBean as _, ViaInitializingBean as _};
+            let Some(bean) = bean.downcast_mut::<#ty>() else { return Ok(()) };
+            bean.__spring_post_construct()?;
+            (&spring_beans::bean::lifecycle::LifecycleProbe::<#ty>::new()).after_properties_set(bean)?;
+            #custom_init
+            Ok(())
+        }) as spring_beans::bean::lifecycle::InitFn
+    };
+    let destroy_fn = quote! {
+        (|bean: &dyn std::any::Any| -> spring_beans::bean::lifecycle::LifecycleResult {
+            #[allow(unused_imports)]
+            use spring_beans::bean::lifecycle::{LifecycleFallback as _, NoDisposableBean as _, ViaDisposableBean as _};
+            let Some(bean) = bean.downcast_ref::<#ty>() else { return Ok(()) };
+            bean.__spring_pre_destroy()?;
+            (&spring_beans::bean::lifecycle::LifecycleProbe::<#ty>::new()).destroy(bean)?;
+            #custom_destroy
+            Ok(())
+        }) as spring_beans::bean::lifecycle::DestroyFn
+    };
+    (init_fn, destroy_fn)
+}
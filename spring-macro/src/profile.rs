@@ -0,0 +1,51 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{parse_macro_input, Attribute, Item, LitStr};
+
+/// 会读取 `#[Profile]` 的注册宏
+const REGISTERING_ATTRS: [&str; 4] = ["Component", "Bean", "Repository", "ConfigurationProperties"];
+
+/// `#[Profile("dev")]` / `#[Profile("!prod")]` / `#[Profile("dev", "test")]`
+///
+/// 真正的过滤由 `#[Component]` / `#[Bean]` / `#[Repository]` 读取后写入 bean 定义完成。
+/// 属性宏由外向内展开，若 `#[Profile]` 写在这些注解之上，会先于它们展开；
+/// 此时把自身移到注册宏之后重新输出，保证注解的先后顺序无关紧要。
+pub fn profile_impl(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    let attribute = proc_macro2::TokenStream::from(attribute);
+    let mut item = parse_macro_input!(item as Item);
+    let attrs = match &mut item {
+        Item::Struct(item) => &mut item.attrs,
+        Item::Fn(item) => &mut item.attrs,
+        other => return quote! { #other }.into(),
+    };
+    if let Some(pos) = attrs
+        .iter()
+        .rposition(|attr| REGISTERING_ATTRS.iter().any(|name| attr.path().is_ident(name)))
+    {
+        attrs.insert(pos + 1, syn::parse_quote! { #[Profile(#attribute)] });
+    }
+    quote! { #item }.into()
+}
+
+/// 读取并合并所有 `#[Profile(...)]` 中的表达式
+pub(crate) fn extract_profile_attr(attrs: &[Attribute]) -> syn::Result<Vec<String>> {
+    let mut profiles = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("Profile")) {
+        let list = attr.parse_args_with(Punctuated::<LitStr, Comma>::parse_terminated)?;
+        if list.is_empty() {
+            return Err(syn::Error::new_spanned(attr, "#[Profile] needs at least one profile name"));
+        }
+        profiles.extend(list.iter().map(LitStr::value));
+    }
+    Ok(profiles)
+}
+
+/// 生成 `definition.set_profiles(...)`；没有 `#[Profile]` 时为空
+pub(crate) fn build_set_profiles(profiles: &[String]) -> proc_macro2::TokenStream {
+    if profiles.is_empty() {
+        return quote! {};
+    }
+    quote! { definition.set_profiles(vec![#(#profiles.to_string()),*]); }
+}
@@ -48,6 +48,12 @@ pub fn repository_impl(attribute: TokenStream, item: TokenStream) -> TokenStream
     let name = default_bean_name(struct_ident);
     let name_lit = LitStr::new(&name, Span::call_site());
 
+    let wrap_fn = crate::component::build_wrap_fn(&quote! { #struct_ident });
+    let set_profiles = match crate::profile::extract_profile_attr(&input.attrs) {
+        Ok(profiles) => crate::profile::build_set_profiles(&profiles),
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     let expanded = quote! {
         // ── 生成的结构体 ──────────────────────────────────────────
         pub struct #struct_ident {
@@ -118,18 +124,22 @@ pub fn repository_impl(attribute: TokenStream, item: TokenStream) -> TokenStream
             }
 
             pub fn bean_definition() -> spring_beans::factory::config::RootBeanDefinition {
-                spring_beans::factory::config::RootBeanDefinition::new(
+                #[allow(unused_mut)]
+                let mut definition = spring_beans::factory::config::RootBeanDefinition::new(
                     #name_lit.to_string(),
                     std::any::TypeId::of::<#struct_ident>(),
                     spring_beans::factory::config::BeanScope::Singleton,
                     false,
                     vec![],
-                    Box::new(|_resolved_deps: &std::collections::HashMap<String, Box<dyn std::any::Any>>,
+                    Box::new(|_resolved_deps: &spring_beans::factory::config::ResolvedDependencies,
                                _env: &std::collections::HashMap<String, String>| {
-                        Box::new(#struct_ident::default()) as Box<dyn std::any::Any>
+                        Ok(Box::new(#struct_ident::default()) as Box<dyn std::any::Any>)
                     }),
                     None,
-                )
+                    #wrap_fn,
+                );
+                #set_profiles
+                definition
             }
         }
 
@@ -0,0 +1,201 @@
+/// `#[derive(Validate)]` —— 根据字段上的约束注解生成 `Validate::validate_into`。
+///
+/// ```rust,ignore
+/// #[derive(Validate)]
+/// struct NewUser {
+///     #[NotBlank]
+///     #[Size(max = 32)]
+///     name: String,
+///     #[Email(message = "please enter a valid email")]
+///     email: String,
+///     #[Min(18)] #[Max(130)]
+///     age: u32,
+///     #[Pattern("[A-Z]{2}")]
+///     country: Option<String>,
+///     #[Valid]
+///     address: Address,
+/// }
+/// ```
+///
+/// 所有约束都支持 `message = "..."`，其中的 `{min}` / `{max}` / `{value}` / `{regexp}` 会被替换。
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, DeriveInput, Expr, ExprLit, ExprUnary, Fields, Lit, LitStr, UnOp};
+
+pub fn validate_derive_impl(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    match build_validate_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn build_validate_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    let fields = match &input.data {
+        syn::Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            Fields::Unit => return Ok(empty_impl(input)),
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(ident, "#[derive(Validate)] needs named fields"))
+            }
+        },
+        _ => return Err(syn::Error::new_spanned(ident, "#[derive(Validate)] only supports structs")),
+    };
+
+    let mut checks = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let mut field_checks = Vec::new();
+        for attr in &field.attrs {
+            if let Some(check) = build_check(attr, field_ident)? {
+                field_checks.push(check);
+            }
+        }
+        if field_checks.is_empty() {
+            continue;
+        }
+        let field_name = LitStr::new(&field_ident.to_string(), Span::call_site());
+        checks.push(quote! {
+            {
+                let field_path = spring_boot::validation::join_path(path, #field_name);
+                #(#field_checks)*
+            }
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics spring_boot::validation::Validate for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn validate_into(&self, path: &str, violations: &mut spring_boot::validation::ConstraintViolations) {
+                #(#checks)*
+            }
+        }
+    })
+}
+
+fn empty_impl(input: &DeriveInput) -> proc_macro2::TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics spring_boot::validation::Validate for #ident #ty_generics #where_clause {
+            fn validate_into(&self, _path: &str, _violations: &mut spring_boot::validation::ConstraintViolations) {}
+        }
+    }
+}
+
+/// 约束注解的参数：位置参数（`#[Min(1)]`、`#[Pattern("..")]`）或 `key = value`
+#[derive(Default)]
+struct ConstraintArgs {
+    positional: Option<Expr>,
+    value: Option<Expr>,
+    regexp: Option<LitStr>,
+    min: Option<Expr>,
+    max: Option<Expr>,
+    message: Option<LitStr>,
+}
+
+fn parse_args(attr: &Attribute) -> syn::Result<ConstraintArgs> {
+    let mut args = ConstraintArgs::default();
+    if matches!(attr.meta, syn::Meta::Path(_)) {
+        return Ok(args);
+    }
+    if let Ok(expr) = attr.parse_args::<Expr>() {
+        if !matches!(expr, Expr::Assign(_)) {
+            args.positional = Some(expr);
+            return Ok(args);
+        }
+    }
+    attr.parse_nested_meta(|meta| {
+        let key = meta.path.get_ident().map(ToString::to_string).unwrap_or_default();
+        match key.as_str() {
+            "value" => args.value = Some(meta.value()?.parse()?),
+            "regexp" => args.regexp = Some(meta.value()?.parse()?),
+            "min" => args.min = Some(meta.value()?.parse()?),
+            "max" => args.max = Some(meta.value()?.parse()?),
+            "message" => args.message = Some(meta.value()?.parse()?),
+            _ => return Err(meta.error(format!("unsupported constraint parameter `{}`", key))),
+        }
+        Ok(())
+    })?;
+    Ok(args)
+}
+
+fn build_check(attr: &Attribute, field: &syn::Ident) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let Some(name) = attr.path().get_ident().map(ToString::to_string) else { return Ok(None) };
+    if !matches!(name.as_str(), "NotBlank" | "Email" | "Min" | "Max" | "Pattern" | "Size" | "Valid") {
+        return Ok(None);
+    }
+    let args = parse_args(attr)?;
+    let message = match &args.message {
+        Some(message) => quote! { Some(#message) },
+        None => quote! { None },
+    };
+    let constraints = quote! { spring_boot::validation::constraints };
+    let check = match name.as_str() {
+        "Valid" => quote! {
+            spring_boot::validation::Validate::validate_into(&self.#field, &field_path, violations);
+        },
+        "NotBlank" => quote! { #constraints::not_blank(&self.#field, &field_path, #message, violations); },
+        "Email" => quote! { #constraints::email(&self.#field, &field_path, #message, violations); },
+        "Min" | "Max" => {
+            let bound = args
+                .positional
+                .or(args.value)
+                .ok_or_else(|| syn::Error::new_spanned(attr, format!("#[{}] needs a bound, e.g. #[{}(1)]", name, name)))?;
+            let bound = parse_number(&bound, false)?;
+            let function = syn::Ident::new(&name.to_lowercase(), Span::call_site());
+            quote! { #constraints::#function(&self.#field, #bound, &field_path, #message, violations); }
+        }
+        "Pattern" => {
+            let regexp = match (args.positional, args.regexp) {
+                (Some(Expr::Lit(ExprLit { lit: Lit::Str(lit), .. })), _) | (None, Some(lit)) => lit,
+                _ => return Err(syn::Error::new_spanned(attr, "#[Pattern] needs a string regexp, e.g. #[Pattern(\"[a-z]+\")]")),
+            };
+            if let Err(err) = regex::Regex::new(&regexp.value()) {
+                return Err(syn::Error::new_spanned(&regexp, format!("invalid regexp: {}", err)));
+            }
+            quote! { #constraints::pattern(&self.#field, #regexp, &field_path, #message, violations); }
+        }
+        "Size" => {
+            if args.positional.is_some() {
+                return Err(syn::Error::new_spanned(attr, "use #[Size(min = .., max = ..)]"));
+            }
+            let min = args.min.map(|min| quote! { #min }).unwrap_or_else(|| quote! { 0 });
+            let max = args.max.map(|max| quote! { #max }).unwrap_or_else(|| quote! { usize::MAX });
+            quote! { #constraints::size(&self.#field, #min, #max, &field_path, #message, violations); }
+        }
+        _ => unreachable!(),
+    };
+    Ok(Some(check))
+}
+
+/// `#[Min]` / `#[Max]` 的边界只能是数字字面量（可带负号），在编译期转换为 `constraints::Number`：
+/// 整数边界保持为整数，与整数字段精确比较
+fn parse_number(expr: &Expr, negative: bool) -> syn::Result<proc_macro2::TokenStream> {
+    let number = quote! { spring_boot::validation::constraints::Number };
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => {
+            let value: u128 = lit.base10_parse()?;
+            if !negative {
+                return Ok(quote! { #number::Unsigned(#value) });
+            }
+            let value = 0i128
+                .checked_sub_unsigned(value)
+                .ok_or_else(|| syn::Error::new_spanned(lit, "bound is out of range"))?;
+            Ok(quote! { #number::Signed(#value) })
+        }
+        Expr::Lit(ExprLit { lit: Lit::Float(lit), .. }) => {
+            let value: f64 = lit.base10_parse()?;
+            if !value.is_finite() {
+                return Err(syn::Error::new_spanned(lit, "bound is out of range"));
+            }
+            let value = if negative { -value } else { value };
+            Ok(quote! { #number::Float(#value) })
+        }
+        Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. }) if !negative => parse_number(expr, true),
+        _ => Err(syn::Error::new_spanned(expr, "bound must be a number literal")),
+    }
+}
@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
-use syn::{parse_macro_input, FnArg, Ident, ItemFn, LitStr, Type};
+use syn::{parse_macro_input, FnArg, Ident, ItemFn, LitStr, ReturnType, Type};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // 公共入口（按 HTTP 方法区分）
@@ -34,72 +34,159 @@ pub fn rest_controller_impl(_attr: TokenStream, item: TokenStream) -> TokenStrea
 
 /// 通用路由宏实现。
 ///
-/// 支持两种 handler 签名：
+/// handler 参数按类型识别：
 ///
 /// 1. **Plain handler** — 无 bean 注入:
-///    ```rust
+///    ```rust,ignore
 ///    #[GetMapping("/hello")]
 ///    fn hello(req: &HttpRequest) -> HttpResponse { ... }
 ///    ```
 ///
 /// 2. **Bean handler** — 从 IoC 容器注入第一个参数所对应的 bean:
-///    ```rust
+///    ```rust,ignore
 ///    #[GetMapping("/users")]
 ///    fn list_users(ctrl: &UserController, req: &HttpRequest) -> HttpResponse { ... }
 ///    ```
 ///    宏自动从 `UserController` 推导 bean 名称为 `"userController"`。
+///
+/// 3. 其余参数通过 `FromRequest` 从请求中构造；标注 `#[Valid]` 的参数构造后再校验，
+///    违规时直接返回 400 + JSON，不会调用 handler:
+///    ```rust,ignore
+///    #[PostMapping("/users")]
+///    fn create(ctrl: &UserController, req: &HttpRequest, #[Valid] user: NewUser) -> HttpResponse { ... }
+///    ```
+///
+/// 4. 参数注解，值经 `ConversionService` 转换为参数类型，缺失或转换失败时返回 400:
+///    - `#[PathVariable]` / `#[PathVariable("id")]` — 路径参数，名称必须出现在路径中
+///    - `#[RequestParam]` / `#[RequestParam(name = "q", default = "10")]` — Query 参数
+///    - `#[RequestHeader]` / `#[RequestHeader("X-Request-Id")]` — 请求头，默认名称为参数名（`_` 换成 `-`）
+///    - `#[RequestBody]` — 用 `FromRequest` 从 body 构造，body 为空时返回 400
+///
+///    参数类型为 `Option<T>` 时缺失不算错误（`#[PathVariable]` 除外）:
+///    ```rust,ignore
+///    #[GetMapping("/users/{id}/posts")]
+///    fn posts(ctrl: &UserController, #[PathVariable] id: u64,
+///             #[RequestParam(default = "10")] limit: usize, #[RequestHeader] if_none_match: Option<String>) -> HttpResponse { ... }
+///    ```
+///
+/// 以上形式都可以写成 `async fn`，注册为 `Handler::Async` / `Handler::AsyncWithBean`。
+/// `HttpServer` 不提供 tokio 运行时，依赖 tokio 的 `async fn` 只能在 `AsyncHttpServer` 下运行。
+///
+/// 返回值经 `IntoResponse` 转换：`HttpResponse`、`Json<T>`、`String` / `&str`（text/plain）
+/// 与 `impl IntoResponse` 直接转换；`Result<T, E: ResponseError>` 的 `Err` 转为错误响应；
+/// 其余类型（包括 `Result` 的 `Ok` 类型）视为 `T: Serialize`，包装成 `Json<T>` 返回 200 + JSON
+/// （需要 `serde` feature）。包装按返回类型的写法判断，类型别名不会被展开。
 fn mapping_impl(method: &str, attr: TokenStream, item: TokenStream) -> TokenStream {
     // 1. 解析路径字面量  e.g. "/users/{id}"
     let path_lit = parse_macro_input!(attr as LitStr);
     let path_str = path_lit.value();
 
-    // 2. 解析被注解的函数
-    let func = parse_macro_input!(item as ItemFn);
+    // 2. 解析被注解的函数，识别参数并剥离参数上的 #[Valid]
+    let mut func = parse_macro_input!(item as ItemFn);
+    let params = match classify_params(&mut func, &path_lit) {
+        Ok(params) => params,
+        Err(err) => return err.to_compile_error().into(),
+    };
     let func_name = &func.sig.ident;
-    let inputs = &func.sig.inputs;
 
     // 3. 生成 HttpMethod token
     let method_ident = Ident::new(method, Span::call_site());
 
-    // 4. 根据参数个数判断 handler 类型
-    let register = match inputs.len() {
-        // ── Plain handler: fn handler(req: &HttpRequest) -> HttpResponse ──
-        1 => {
-            quote! {
-                inventory::submit! {
-                    spring_boot::web::RouteRegistration {
-                        method:  spring_boot::web::HttpMethod::#method_ident,
-                        path:    #path_str,
-                        handler: spring_boot::web::Handler::Plain(#func_name),
-                    }
-                }
+    // 4. 生成包装函数：取 bean、提取参数、校验，然后调用原函数
+    let wrapper_name = Ident::new(
+        &format!("__spring_web_handler_{}", func_name),
+        Span::call_site(),
+    );
+    let mut prepare = Vec::new();
+    let mut call_args = Vec::new();
+    let mut controller = None;
+    for (index, param) in params.iter().enumerate() {
+        match param {
+            HandlerParam::Controller(bean_type_ident) => {
+                controller = Some(bean_type_ident.clone());
+                call_args.push(quote! { ctrl });
+            }
+            HandlerParam::Request => call_args.push(quote! { req }),
+            HandlerParam::Extract { ty, source, valid } => {
+                let arg = Ident::new(&format!("__arg{}", index), Span::call_site());
+                let optional = source.is_optional();
+                let validate = match (valid, optional) {
+                    (false, _) => None,
+                    (true, false) => Some(quote! {
+                        if let Err(violations) = spring_boot::validation::Validate::validate(&#arg) {
+                            return spring_boot::web::HttpResponse::constraint_violations(&violations);
+                        }
+                    }),
+                    (true, true) => Some(quote! {
+                        if let Some(value) = &#arg {
+                            if let Err(violations) = spring_boot::validation::Validate::validate(value) {
+                                return spring_boot::web::HttpResponse::constraint_violations(&violations);
+                            }
+                        }
+                    }),
+                };
+                let extract = source.extract(ty);
+                prepare.push(quote! {
+                    let #arg: #ty = match #extract {
+                        Ok(value) => value,
+                        Err(response) => return response,
+                    };
+                    #validate
+                });
+                call_args.push(quote! { #arg });
             }
         }
-        // ── Bean handler: fn handler(ctrl: &ControllerType, req: &HttpRequest) ──
-        2 => {
-            // 提取第一个参数的类型 Ident（去掉 & 引用）
-            let bean_type_ident = match extract_ref_type_ident(&inputs[0]) {
-                Some(id) => id,
-                None => {
-                    return syn::Error::new_spanned(
-                        &inputs[0],
-                        "first parameter must be a shared reference, e.g. `ctrl: &UserController`",
-                    )
-                    .to_compile_error()
-                    .into();
+    }
+
+    // `async fn` handler：包装函数返回装箱的 future，参数提取与校验在 future 中进行
+    let is_async = func.sig.asyncness.is_some();
+    let call = if is_async {
+        quote! { #func_name(#(#call_args),*).await }
+    } else {
+        quote! { #func_name(#(#call_args),*) }
+    };
+    let call = into_response(&func.sig.output, call);
+
+    let register = match (controller, is_async) {
+        // ── Plain handler: fn handler(req: &HttpRequest, ...) -> HttpResponse ──
+        (None, false) => quote! {
+            fn #wrapper_name(req: &spring_boot::web::HttpRequest) -> spring_boot::web::HttpResponse {
+                let _ = req;
+                #(#prepare)*
+                #call
+            }
+
+            inventory::submit! {
+                spring_boot::web::RouteRegistration {
+                    method:  spring_boot::web::HttpMethod::#method_ident,
+                    path:    #path_str,
+                    handler: spring_boot::web::Handler::Plain(#wrapper_name),
                 }
-            };
+            }
+        },
+        // ── Async plain handler: async fn handler(req: &HttpRequest, ...) -> HttpResponse ──
+        (None, true) => quote! {
+            fn #wrapper_name<'a>(req: &'a spring_boot::web::HttpRequest) -> spring_boot::web::ResponseFuture<'a> {
+                Box::pin(async move {
+                    let _ = req;
+                    #(#prepare)*
+                    #call
+                })
+            }
 
+            inventory::submit! {
+                spring_boot::web::RouteRegistration {
+                    method:  spring_boot::web::HttpMethod::#method_ident,
+                    path:    #path_str,
+                    handler: spring_boot::web::Handler::Async(#wrapper_name),
+                }
+            }
+        },
+        // ── Bean handler: fn handler(ctrl: &ControllerType, req: &HttpRequest, ...) ──
+        (Some(bean_type_ident), false) => {
             // 派生 bean 名称：首字母小写
             let bean_name = camel_to_bean_name(&bean_type_ident.to_string());
             let bean_name_lit = LitStr::new(&bean_name, Span::call_site());
-
-            // 生成包装函数，负责 downcast + 实际调用
-            let wrapper_name = Ident::new(
-                &format!("__spring_web_handler_{}", func_name),
-                Span::call_site(),
-            );
-
             quote! {
                 fn #wrapper_name(
                     req:  &spring_boot::web::HttpRequest,
@@ -111,7 +198,9 @@ fn mapping_impl(method: &str, attr: TokenStream, item: TokenStream) -> TokenStre
                             "[spring-web] downcast failed for bean: ",
                             #bean_name
                         ));
-                    #func_name(ctrl, req)
+                    let _ = req;
+                    #(#prepare)*
+                    #call
                 }
 
                 inventory::submit! {
@@ -126,14 +215,39 @@ fn mapping_impl(method: &str, attr: TokenStream, item: TokenStream) -> TokenStre
                 }
             }
         }
-        _ => {
-            return syn::Error::new_spanned(
-                &func.sig,
-                "#[GetMapping] handler must have exactly 1 or 2 parameters: \
-                 `(req: &HttpRequest)` or `(ctrl: &Controller, req: &HttpRequest)`",
-            )
-            .to_compile_error()
-            .into();
+        // ── Async bean handler: async fn handler(ctrl: &ControllerType, req: &HttpRequest, ...) ──
+        (Some(bean_type_ident), true) => {
+            let bean_name = camel_to_bean_name(&bean_type_ident.to_string());
+            let bean_name_lit = LitStr::new(&bean_name, Span::call_site());
+            quote! {
+                fn #wrapper_name<'a>(
+                    req:  &'a spring_boot::web::HttpRequest,
+                    bean: &'a dyn std::any::Any,
+                ) -> spring_boot::web::ResponseFuture<'a> {
+                    Box::pin(async move {
+                        let ctrl = bean
+                            .downcast_ref::<#bean_type_ident>()
+                            .expect(concat!(
+                                "[spring-web] downcast failed for bean: ",
+                                #bean_name
+                            ));
+                        let _ = req;
+                        #(#prepare)*
+                        #call
+                    })
+                }
+
+                inventory::submit! {
+                    spring_boot::web::RouteRegistration {
+                        method:  spring_boot::web::HttpMethod::#method_ident,
+                        path:    #path_str,
+                        handler: spring_boot::web::Handler::AsyncWithBean {
+                            bean_name: #bean_name_lit,
+                            f:         #wrapper_name,
+                        },
+                    }
+                }
+            }
         }
     };
 
@@ -145,6 +259,258 @@ fn mapping_impl(method: &str, attr: TokenStream, item: TokenStream) -> TokenStre
     expanded.into()
 }
 
+/// handler 参数的三种角色
+enum HandlerParam {
+    /// 第一个参数为 `&SomeController`：从 IoC 容器取出的 bean
+    Controller(Ident),
+    /// `&HttpRequest`
+    Request,
+    /// 其它参数：按 `source` 从请求中提取，`valid` 表示标注了 `#[Valid]`
+    Extract { ty: Box<Type>, source: Source, valid: bool },
+}
+
+/// 参数值的来源；`optional` 为 `Option<T>` 中的 `T`
+enum Source {
+    /// 未标注：`FromRequest::from_request`
+    FromRequest,
+    /// `#[PathVariable]`
+    PathVariable(LitStr),
+    /// `#[RequestParam]`
+    RequestParam { name: LitStr, default: Option<LitStr>, optional: Option<Box<Type>> },
+    /// `#[RequestHeader]`
+    RequestHeader { name: LitStr, default: Option<LitStr>, optional: Option<Box<Type>> },
+    /// `#[RequestBody]`
+    RequestBody { optional: Option<Box<Type>> },
+}
+
+impl Source {
+    fn is_optional(&self) -> bool {
+        match self {
+            Source::FromRequest | Source::PathVariable(_) => false,
+            Source::RequestParam { optional, .. }
+            | Source::RequestHeader { optional, .. }
+            | Source::RequestBody { optional } => optional.is_some(),
+        }
+    }
+
+    /// 生成类型为 `Result<#ty, HttpResponse>` 的提取表达式
+    fn extract(&self, ty: &Type) -> proc_macro2::TokenStream {
+        let default = |default: &Option<LitStr>| match default {
+            Some(lit) => quote! { Some(#lit) },
+            None => quote! { None },
+        };
+        match self {
+            Source::FromRequest => quote! { <#ty as spring_boot::web::FromRequest>::from_request(req) },
+            Source::PathVariable(name) => quote! { req.path_param_as::<#ty>(#name) },
+            Source::RequestParam { name, optional: Some(inner), .. } => quote! { req.query_param_as::<#inner>(#name) },
+            Source::RequestParam { name, default: value, optional: None } => {
+                let value = default(value);
+                quote! { req.required_query_param_as::<#ty>(#name, #value) }
+            }
+            Source::RequestHeader { name, optional: Some(inner), .. } => quote! { req.header_as::<#inner>(#name) },
+            Source::RequestHeader { name, default: value, optional: None } => {
+                let value = default(value);
+                quote! { req.required_header_as::<#ty>(#name, #value) }
+            }
+            Source::RequestBody { optional: Some(inner) } => quote! { req.body_as::<#inner>() },
+            Source::RequestBody { optional: None } => quote! { req.required_body_as::<#ty>() },
+        }
+    }
+}
+
+const SOURCE_ATTRS: [&str; 4] = ["PathVariable", "RequestParam", "RequestHeader", "RequestBody"];
+
+fn classify_params(func: &mut ItemFn, path: &LitStr) -> syn::Result<Vec<HandlerParam>> {
+    let mut params = Vec::new();
+    for (index, input) in func.sig.inputs.iter_mut().enumerate() {
+        let FnArg::Typed(pat_type) = input else {
+            return Err(syn::Error::new_spanned(input, "route handler must be a free function, not a method"));
+        };
+        let valid = pat_type.attrs.iter().any(|attr| attr.path().is_ident("Valid"));
+        pat_type.attrs.retain(|attr| !attr.path().is_ident("Valid"));
+        let source = parse_source(pat_type, path)?;
+        if source.is_none() && is_http_request_ref(&pat_type.ty) {
+            params.push(HandlerParam::Request);
+            continue;
+        }
+        if index == 0 && !valid && source.is_none() {
+            if let Some(bean_type_ident) = extract_ref_type_ident(input) {
+                params.push(HandlerParam::Controller(bean_type_ident));
+                continue;
+            }
+        }
+        let FnArg::Typed(pat_type) = input else { unreachable!() };
+        if matches!(*pat_type.ty, Type::Reference(_)) {
+            return Err(syn::Error::new_spanned(
+                &pat_type.ty,
+                "only the first parameter (the controller bean) and `&HttpRequest` may be references; \
+                 other parameters are built by value via `FromRequest`",
+            ));
+        }
+        params.push(HandlerParam::Extract {
+            ty: pat_type.ty.clone(),
+            source: source.unwrap_or(Source::FromRequest),
+            valid,
+        });
+    }
+    Ok(params)
+}
+
+/// 解析并剥离参数上的 `#[PathVariable]` / `#[RequestParam]` / `#[RequestHeader]` / `#[RequestBody]`
+fn parse_source(pat_type: &mut syn::PatType, path: &LitStr) -> syn::Result<Option<Source>> {
+    let mut attrs = pat_type
+        .attrs
+        .iter()
+        .filter(|attr| SOURCE_ATTRS.iter().any(|name| attr.path().is_ident(name)));
+    let Some(attr) = attrs.next().cloned() else { return Ok(None) };
+    if let Some(extra) = attrs.next() {
+        return Err(syn::Error::new_spanned(extra, "a handler parameter can have only one of #[PathVariable], #[RequestParam], #[RequestHeader] and #[RequestBody]"));
+    }
+    pat_type.attrs.retain(|attr| !SOURCE_ATTRS.iter().any(|name| attr.path().is_ident(name)));
+
+    let kind = attr.path().get_ident().map(ToString::to_string).unwrap_or_default();
+    let optional = option_inner(&pat_type.ty).cloned().map(Box::new);
+    if kind == "RequestBody" {
+        if !matches!(attr.meta, syn::Meta::Path(_)) {
+            return Err(syn::Error::new_spanned(&attr, "#[RequestBody] takes no arguments"));
+        }
+        return Ok(Some(Source::RequestBody { optional }));
+    }
+
+    let args = parse_source_args(&attr)?;
+    let name = match args.name {
+        Some(name) => name,
+        None => match &*pat_type.pat {
+            syn::Pat::Ident(pat) => {
+                let name = pat.ident.to_string();
+                let name = if kind == "RequestHeader" { name.replace('_', "-") } else { name };
+                LitStr::new(&name, pat.ident.span())
+            }
+            pat => return Err(syn::Error::new_spanned(pat, format!("#[{}] needs a name, e.g. #[{}(\"id\")]", kind, kind))),
+        },
+    };
+    if optional.is_some() {
+        if let Some(default) = &args.default {
+            return Err(syn::Error::new_spanned(default, "`default` has no effect on an `Option` parameter"));
+        }
+    }
+    match kind.as_str() {
+        "PathVariable" => {
+            if let Some(default) = &args.default {
+                return Err(syn::Error::new_spanned(default, "#[PathVariable] does not support `default`"));
+            }
+            if optional.is_some() {
+                return Err(syn::Error::new_spanned(&pat_type.ty, "a path variable is always present; use the value type instead of `Option`"));
+            }
+            if !path.value().contains(&format!("{{{}}}", name.value())) {
+                return Err(syn::Error::new_spanned(
+                    &name,
+                    format!("path variable `{}` not found in \"{}\"", name.value(), path.value()),
+                ));
+            }
+            Ok(Some(Source::PathVariable(name)))
+        }
+        "RequestParam" => Ok(Some(Source::RequestParam { name, default: args.default, optional })),
+        _ => Ok(Some(Source::RequestHeader { name, default: args.default, optional })),
+    }
+}
+
+#[derive(Default)]
+struct SourceArgs {
+    name: Option<LitStr>,
+    default: Option<LitStr>,
+}
+
+/// `#[X]`、`#[X("name")]` 或 `#[X(name = "..", default = "..")]`
+fn parse_source_args(attr: &syn::Attribute) -> syn::Result<SourceArgs> {
+    let mut args = SourceArgs::default();
+    if matches!(attr.meta, syn::Meta::Path(_)) {
+        return Ok(args);
+    }
+    if let Ok(lit) = attr.parse_args::<LitStr>() {
+        args.name = Some(lit);
+        return Ok(args);
+    }
+    attr.parse_nested_meta(|meta| {
+        let key = meta.path.get_ident().map(ToString::to_string).unwrap_or_default();
+        match key.as_str() {
+            "name" | "value" => args.name = Some(meta.value()?.parse()?),
+            "default" => args.default = Some(meta.value()?.parse()?),
+            _ => return Err(meta.error(format!("unsupported parameter `{}`, expected `name` or `default`", key))),
+        }
+        Ok(())
+    })?;
+    Ok(args)
+}
+
+/// `Option<T>` → `T`
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else { return None };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    match args.args.first()? {
+        syn::GenericArgument::Type(inner) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// 生成把 handler 返回值转为 `HttpResponse` 的表达式。
+/// serde 也为 `Result` 实现了 `Serialize`，`IntoResponse` 无法对 `T: Serialize` 做 blanket 实现，
+/// 因此在这里按返回类型包装 `Json`
+fn into_response(output: &ReturnType, call: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let value = match output {
+        ReturnType::Type(_, ty) if !is_responder(ty) => match result_ok_type(ty) {
+            Some(ok) if is_responder(ok) => call,
+            Some(_) => quote! { #call.map(spring_boot::web::Json) },
+            None => quote! { spring_boot::web::Json(#call) },
+        },
+        _ => call,
+    };
+    quote! { spring_boot::web::IntoResponse::into_response(#value) }
+}
+
+/// 本身实现了 `IntoResponse` 的返回类型：`HttpResponse`、`Json<T>`、`String`、`&str`、`impl IntoResponse`
+fn is_responder(ty: &Type) -> bool {
+    match ty {
+        Type::ImplTrait(_) => true,
+        Type::Reference(reference) => matches!(&*reference.elem, Type::Path(p) if p.path.is_ident("str")),
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| ["HttpResponse", "Json", "String"].iter().any(|name| segment.ident == name)),
+        _ => false,
+    }
+}
+
+/// `Result<T, E>` → `T`
+fn result_ok_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else { return None };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    match args.args.first()? {
+        syn::GenericArgument::Type(ok) => Some(ok),
+        _ => None,
+    }
+}
+
+/// `&HttpRequest` / `&spring_boot::web::HttpRequest`
+fn is_http_request_ref(ty: &Type) -> bool {
+    let Type::Reference(type_ref) = ty else { return false };
+    let Type::Path(type_path) = &*type_ref.elem else { return false };
+    type_path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "HttpRequest")
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // 工具函数
 // ─────────────────────────────────────────────────────────────────────────────
@@ -0,0 +1,616 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::io::{self, Write};
+use std::net::{SocketAddr, TcpListener};
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::{pin, Pin};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::body::{Body, Bytes, Frame, Incoming};
+use hyper::header::{HeaderValue, CONNECTION};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, Version};
+use hyper_util::rt::{TokioIo, TokioTimer};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{mpsc, watch};
+use tokio::task::{JoinSet, LocalSet};
+use tokio::time::Sleep;
+
+use spring_context::context::application_context::ApplicationContext;
+use spring_context::context::configurable_application_context::ConfigurableApplicationContext;
+
+use crate::method::HttpMethod;
+use crate::request::{HttpRequest, RequestLimits, RequestParseError};
+use crate::request_context::{RequestAttributes, RequestContextHolder, SESSION_COOKIE_NAME};
+use crate::response::{BodyWriter, HttpResponse, StreamingBody};
+use crate::router::{ResponseFuture, Router};
+use crate::server::{finish_request, ShutdownHandle};
+use crate::server_properties::ServerProperties;
+
+type ResponseBody = UnsyncBoxBody<Bytes, io::Error>;
+
+/// 基于 tokio + hyper 的 HTTP 服务器（`async` feature）
+///
+/// 与 [`HttpServer`](crate::HttpServer) 共用 [`Router`]、`server.*` 配置（[`ServerProperties`]）与 [`ShutdownHandle`]。
+/// 监听任务 accept 连接后轮流交给 `server.threads` 个事件循环线程（每个线程一个单线程运行时），
+/// 连接上的所有请求都在同一个线程上处理：`async fn` handler 等待 I/O 时不占用线程，
+/// request / session 作用域照常可用；同步 handler 直接在事件循环线程上执行，应当尽快返回。
+///
+/// keep-alive、流水线、chunked 与 `Expect: 100-continue` 由 hyper 处理。`server.*` 的含义与 std 实现相同：
+/// - `server.read-timeout` 限制读取请求头的时间，`server.write-timeout` 限制每次写出等待的时间
+/// - `server.accept-queue` 是每个事件循环等待接手的连接数上限，所有事件循环的队列都满时新连接收到 `503`
+/// - `server.max-keep-alive-requests` 之后的响应带 `Connection: close`
+/// - `server.max-http-request-size` 超出时返回 `413`；请求头的个数与大小由 hyper 检查，超出时返回 `431`
+///
+/// 使用示例：
+/// ```rust,ignore
+/// let context = Application::run_concurrent();
+/// let properties = ServerProperties::from_environment(context.get_environment())?;
+/// let server = AsyncHttpServer::bind(properties, context)?;
+/// let context = server.serve().await;   // 或在同步代码中调用 server.serve_blocking()
+/// ```
+pub struct AsyncHttpServer<C> {
+    listener: TcpListener,
+    properties: ServerProperties,
+    context: C,
+    shutdown: ShutdownHandle,
+}
+
+impl<C: ConfigurableApplicationContext + Send + Sync + 'static> AsyncHttpServer<C> {
+    /// 在 `port` 端口启动 HTTP 服务，阻塞直到程序退出；其余配置从上下文的 `server.*` 属性读取
+    pub fn run(port: u16, context: C) {
+        let mut properties = ServerProperties::from_environment(context.get_environment())
+            .unwrap_or_else(|e| panic!("[spring-web] invalid server properties — {}", e));
+        properties.port = port;
+        let server = Self::bind(properties, context)
+            .unwrap_or_else(|e| panic!("[spring-web] failed to bind port {} — {}", port, e));
+        server.serve_blocking();
+    }
+
+    /// 绑定 `server.address:server.port`（端口为 `0` 时由系统分配），尚不开始处理请求
+    pub fn bind(properties: ServerProperties, context: C) -> io::Result<Self> {
+        let listener = TcpListener::bind((properties.address.as_str(), properties.port))?;
+        listener.set_nonblocking(true)?;
+        let shutdown = ShutdownHandle::for_listener(&listener)?;
+        Ok(Self { listener, properties, context, shutdown })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    /// 在新建的 tokio 运行时上执行 [`AsyncHttpServer::serve`]，不能在已有的运行时中调用
+    pub fn serve_blocking(self) -> C {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("[spring-web] failed to build tokio runtime")
+            .block_on(self.serve())
+    }
+
+    /// 处理请求直到 [`ShutdownHandle::shutdown`]：停止 accept，空闲连接立即关闭，
+    /// 进行中的请求处理完毕后关闭上下文并返回它
+    pub async fn serve(self) -> C {
+        let Self { listener, properties, context, shutdown } = self;
+        let listener = tokio::net::TcpListener::from_std(listener)
+            .expect("[spring-web] serve() must be called within a tokio runtime");
+        let context = Arc::new(context);
+
+        // 从 inventory 收集所有路由
+        let router = Arc::new(Router::from_registry());
+
+        let port = listener.local_addr().map(|addr| addr.port()).unwrap_or(properties.port);
+        let threads = properties.threads.max(1);
+        println!("[spring-web] ┌─────────────────────────────────────────┐");
+        println!("[spring-web] │  Server started on http://localhost:{}  │", port);
+        println!("[spring-web] └─────────────────────────────────────────┘");
+        println!("[spring-web] async (tokio + hyper), event loop threads: {}", threads);
+
+        let settings = ConnectionSettings {
+            read_timeout: properties.read_timeout,
+            write_timeout: properties.write_timeout,
+            max_keep_alive_requests: properties.max_keep_alive_requests.max(1),
+            limits: properties.request_limits(),
+        };
+        let (stop, stopped) = watch::channel(false);
+        let (connections, workers): (Vec<_>, Vec<JoinHandle<()>>) = (0..threads)
+            .map(|index| {
+                let (sender, receiver) = mpsc::channel(properties.accept_queue.max(1));
+                let event_loop = EventLoop {
+                    connections: receiver,
+                    router: router.clone(),
+                    context: context.clone(),
+                    stopped: stopped.clone(),
+                    settings,
+                };
+                let worker = thread::Builder::new()
+                    .name(format!("http-event-loop-{}", index))
+                    .spawn(move || event_loop.run())
+                    .expect("[spring-web] failed to spawn event loop thread");
+                (sender, worker)
+            })
+            .unzip();
+
+        for next in 0.. {
+            let accepted = listener.accept().await;
+            if shutdown.is_shutdown() {
+                break;
+            }
+            match accepted.and_then(|(stream, _)| stream.into_std()) {
+                Ok(stream) => {
+                    // 从轮到的事件循环开始，交给第一个队列未满的；全部已满时立即拒绝
+                    let mut stream = Some(stream);
+                    for offset in 0..threads {
+                        let Some(next_stream) = stream.take() else { break };
+                        match connections[(next + offset) % threads].try_send(next_stream) {
+                            Ok(()) => {}
+                            Err(TrySendError::Full(s) | TrySendError::Closed(s)) => stream = Some(s),
+                        }
+                    }
+                    if let Some(stream) = stream {
+                        tokio::task::spawn_blocking(move || reject_busy(stream));
+                    }
+                }
+                Err(e) => eprintln!("[spring-web] accept error: {}", e),
+            }
+        }
+
+        // 停止接受新连接，通知所有连接优雅关闭，等待事件循环处理完进行中的请求
+        drop(listener);
+        let _ = stop.send(true);
+        drop(connections);
+        println!("[spring-web] shutting down, draining in-flight requests...");
+        let joined = tokio::task::spawn_blocking(move || {
+            for worker in workers {
+                if worker.join().is_err() {
+                    eprintln!("[spring-web] event loop thread panicked");
+                }
+            }
+        });
+        let _ = joined.await;
+        drop(router);
+
+        let mut context = Arc::try_unwrap(context)
+            .unwrap_or_else(|_| unreachable!("all event loop threads have been joined"));
+        context.close();
+        println!("[spring-web] server stopped");
+        context
+    }
+}
+
+/// 所有事件循环都忙时回复 `503` 并关闭连接，与 std 实现相同
+fn reject_busy(mut stream: std::net::TcpStream) {
+    let _ = stream.set_nonblocking(false);
+    let _ = stream.set_write_timeout(Some(Duration::from_secs(1)));
+    let resp = HttpResponse::service_unavailable().text("503 Service Unavailable: server is busy");
+    if let Err(e) = resp.write_to(&mut stream) {
+        eprintln!("[spring-web] write error: {}", e);
+    }
+}
+
+/// 每个连接使用的 `server.*` 配置
+#[derive(Debug, Clone, Copy)]
+struct ConnectionSettings {
+    read_timeout: Duration,
+    write_timeout: Duration,
+    max_keep_alive_requests: usize,
+    limits: RequestLimits,
+}
+
+/// 事件循环线程：单线程运行时 + `LocalSet`，连接上的 future 不需要 `Send`
+struct EventLoop<C> {
+    connections: mpsc::Receiver<std::net::TcpStream>,
+    router: Arc<Router>,
+    context: Arc<C>,
+    stopped: watch::Receiver<bool>,
+    settings: ConnectionSettings,
+}
+
+impl<C: ApplicationContext + 'static> EventLoop<C> {
+    fn run(mut self) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("[spring-web] failed to build tokio runtime");
+        LocalSet::new().block_on(&runtime, async move {
+            let mut tasks = JoinSet::new();
+            while let Some(stream) = self.connections.recv().await {
+                match tokio::net::TcpStream::from_std(stream) {
+                    Ok(stream) => {
+                        tasks.spawn_local(serve_connection(
+                            stream,
+                            self.router.clone(),
+                            self.context.clone(),
+                            self.stopped.clone(),
+                            self.settings,
+                        ));
+                    }
+                    Err(e) => eprintln!("[spring-web] socket error: {}", e),
+                }
+                // 回收已经结束的连接
+                while tasks.try_join_next().is_some() {}
+            }
+            while tasks.join_next().await.is_some() {}
+        });
+    }
+}
+
+async fn serve_connection<C: ApplicationContext + 'static>(
+    stream: tokio::net::TcpStream,
+    router: Arc<Router>,
+    context: Arc<C>,
+    mut stopped: watch::Receiver<bool>,
+    settings: ConnectionSettings,
+) {
+    let peer: Arc<str> = stream
+        .peer_addr()
+        .map(|a| a.to_string())
+        .unwrap_or_else(|_| "?".to_string())
+        .into();
+    let served = Rc::new(Cell::new(0usize));
+    let service = service_fn(move |request| {
+        let router = router.clone();
+        let context = context.clone();
+        let peer = peer.clone();
+        served.set(served.get() + 1);
+        let last = served.get() >= settings.max_keep_alive_requests;
+        async move {
+            let mut response = handle(request, &router, &*context, &peer, &settings.limits).await;
+            if last {
+                // hyper 看到 Connection: close 后在这个响应之后关闭连接
+                response.headers_mut().insert(CONNECTION, HeaderValue::from_static("close"));
+            }
+            Ok::<_, Infallible>(response)
+        }
+    });
+
+    let mut builder = http1::Builder::new();
+    builder
+        .timer(TokioTimer::new())
+        .max_headers(settings.limits.max_headers)
+        .max_header_size(settings.limits.max_header_size);
+    // 超时为 0 表示不限制
+    if !settings.read_timeout.is_zero() {
+        builder.header_read_timeout(settings.read_timeout);
+    }
+    let stream = WriteTimeout::new(stream, settings.write_timeout);
+    let mut connection = pin!(builder.serve_connection(TokioIo::new(stream), service));
+    let result = tokio::select! {
+        result = connection.as_mut() => result,
+        _ = stopped.wait_for(|stopped| *stopped) => {
+            // 空闲连接立即关闭，进行中的请求写完响应后关闭
+            connection.as_mut().graceful_shutdown();
+            connection.await
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("[spring-web] connection error: {}", e);
+    }
+}
+
+async fn handle(
+    request: Request<Incoming>,
+    router: &Router,
+    context: &dyn ApplicationContext,
+    peer: &str,
+    limits: &RequestLimits,
+) -> Response<ResponseBody> {
+    let mut req = match read_request(request, limits.max_body_size).await {
+        Ok(req) => req,
+        Err(e) => {
+            eprintln!("[spring-web] parse error from {}: {}", peer, e);
+            let mut response = into_response(e.to_response());
+            // body 没有读完，不能继续在这个连接上解析下一个请求
+            response.headers_mut().insert(CONNECTION, HeaderValue::from_static("close"));
+            return response;
+        }
+    };
+    println!("[spring-web] {} {} from {}", req.method, req.path, peer);
+
+    let attributes = RequestAttributes::new(req.cookie(SESSION_COOKIE_NAME));
+    let resp = RequestScoped { attributes: Some(attributes), future: router.dispatch_async(&mut req, context) }.await;
+
+    if resp.is_streaming() {
+        println!("[spring-web] → {} (streaming body)", resp.status);
+    } else {
+        println!("[spring-web] → {} ({}B body)", resp.status, resp.body.len());
+    }
+    into_response(resp)
+}
+
+/// 在每次 poll 期间把请求绑定到当前线程（[`RequestContextHolder`]），
+/// 完成后返回 `Set-Cookie` 并销毁 request 作用域的 bean；handler panic 时返回 500
+struct RequestScoped<'a> {
+    attributes: Option<RequestAttributes>,
+    future: ResponseFuture<'a>,
+}
+
+impl Future for RequestScoped<'_> {
+    type Output = HttpResponse;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<HttpResponse> {
+        let this = self.get_mut();
+        if let Some(attributes) = this.attributes.take() {
+            RequestContextHolder::set_request_attributes(attributes);
+        }
+        match panic::catch_unwind(AssertUnwindSafe(|| this.future.as_mut().poll(cx))) {
+            Ok(Poll::Ready(resp)) => Poll::Ready(finish_request(resp)),
+            Ok(Poll::Pending) => {
+                this.attributes = RequestContextHolder::reset_request_attributes();
+                Poll::Pending
+            }
+            Err(_) => {
+                if let Some(attributes) = RequestContextHolder::reset_request_attributes() {
+                    attributes.request_completed();
+                }
+                Poll::Ready(HttpResponse::internal_error().text("500 Internal Server Error: handler panicked"))
+            }
+        }
+    }
+}
+
+/// hyper 请求 → [`HttpRequest`]：hyper 已经处理好 chunked 与 `100-continue`，这里收集完整的 body，
+/// 超过 `max_body_size` 时返回 `413`
+async fn read_request(request: Request<Incoming>, max_body_size: u64) -> Result<HttpRequest, RequestParseError> {
+    let (parts, body) = request.into_parts();
+    if body.size_hint().lower() > max_body_size {
+        return Err(RequestParseError::payload_too_large(max_body_size));
+    }
+    let method = HttpMethod::from_str(parts.method.as_str())
+        .ok_or_else(|| RequestParseError::bad_request(format!("unsupported method: {}", parts.method)))?;
+    let full_path = parts.uri.path_and_query().map_or("/", |pq| pq.as_str());
+    let version = if parts.version == Version::HTTP_10 { "HTTP/1.0" } else { "HTTP/1.1" };
+    let headers: HashMap<String, String> = parts
+        .headers
+        .iter()
+        .filter_map(|(name, value)| Some((name.as_str().to_string(), value.to_str().ok()?.to_string())))
+        .collect();
+    let limit = usize::try_from(max_body_size).unwrap_or(usize::MAX);
+    let body = Limited::new(body, limit).collect().await.map_err(|e| {
+        if e.is::<LengthLimitError>() {
+            RequestParseError::payload_too_large(max_body_size)
+        } else {
+            RequestParseError::bad_request(format!("read body: {}", e))
+        }
+    })?;
+    let body = body.to_bytes();
+    Ok(HttpRequest::from_parts(method, full_path, version.to_string(), headers, body.to_vec()))
+}
+
+/// `server.write-timeout`：一次写出（或 flush）在超时时间内没有任何进展时以 `TimedOut` 失败，
+/// 对应 std 实现中套接字的写超时；`0` 表示不限制
+struct WriteTimeout<S> {
+    inner: S,
+    timeout: Duration,
+    deadline: Option<Pin<Box<Sleep>>>,
+}
+
+impl<S> WriteTimeout<S> {
+    fn new(inner: S, timeout: Duration) -> Self {
+        Self { inner, timeout, deadline: None }
+    }
+
+    /// 写出结果为 Pending 时开始计时，有进展时清除
+    fn check<T>(&mut self, poll: Poll<io::Result<T>>, cx: &mut Context<'_>) -> Poll<io::Result<T>> {
+        if poll.is_ready() || self.timeout.is_zero() {
+            self.deadline = None;
+            return poll;
+        }
+        let timeout = self.timeout;
+        let deadline = self.deadline.get_or_insert_with(|| Box::pin(tokio::time::sleep(timeout)));
+        match deadline.as_mut().poll(cx) {
+            Poll::Ready(()) => {
+                self.deadline = None;
+                Poll::Ready(Err(io::Error::new(io::ErrorKind::TimedOut, "write timed out")))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for WriteTimeout<S> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for WriteTimeout<S> {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let poll = Pin::new(&mut self.inner).poll_write(cx, buf);
+        self.check(poll, cx)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let poll = Pin::new(&mut self.inner).poll_write_vectored(cx, bufs);
+        self.check(poll, cx)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let poll = Pin::new(&mut self.inner).poll_flush(cx);
+        self.check(poll, cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let poll = Pin::new(&mut self.inner).poll_shutdown(cx);
+        self.check(poll, cx)
+    }
+}
+
+/// [`HttpResponse`] → hyper 响应；连接管理与 body 长度相关的头部交给 hyper
+fn into_response(mut resp: HttpResponse) -> Response<ResponseBody> {
+    let body = match resp.take_streaming() {
+        Some(write) => streaming_body(write),
+        None => Full::new(Bytes::from(std::mem::take(&mut resp.body)))
+            .map_err(|never| match never {})
+            .boxed_unsync(),
+    };
+    let managed = ["Connection", "Content-Length", "Transfer-Encoding"];
+    let mut builder = Response::builder().status(resp.status.0);
+    for (key, val) in &resp.headers {
+        if !managed.iter().any(|m| key.eq_ignore_ascii_case(m)) {
+            builder = builder.header(key.as_str(), val.as_str());
+        }
+    }
+    builder.body(body).unwrap_or_else(|e| {
+        let message = format!("500 Internal Server Error: invalid response — {}", e);
+        let mut fallback = Response::new(Full::new(Bytes::from(message)).map_err(|never| match never {}).boxed_unsync());
+        *fallback.status_mut() = hyper::StatusCode::INTERNAL_SERVER_ERROR;
+        fallback
+    })
+}
+
+/// 流式 body 的写入函数是同步的：放到阻塞线程池中执行，写入的数据经 channel 交给 hyper 逐块发送
+fn streaming_body(write: StreamingBody) -> ResponseBody {
+    let (sender, receiver) = mpsc::channel(16);
+    tokio::task::spawn_blocking(move || {
+        let mut sink = ChannelWriter(sender.clone());
+        if let Err(e) = write(&mut BodyWriter::new(&mut sink, false)) {
+            let _ = sender.blocking_send(Err(e));
+        }
+    });
+    ChannelBody(receiver).boxed_unsync()
+}
+
+struct ChannelWriter(mpsc::Sender<io::Result<Bytes>>);
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .blocking_send(Ok(Bytes::copy_from_slice(buf)))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "client disconnected"))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+struct ChannelBody(mpsc::Receiver<io::Result<Bytes>>);
+
+impl Body for ChannelBody {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_frame(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Frame<Bytes>, io::Error>>> {
+        self.0.poll_recv(cx).map(|chunk| chunk.map(|chunk| chunk.map(Frame::data)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::net::TcpStream;
+
+    use spring_context::context::support::ConcurrentApplicationContext;
+
+    use super::*;
+    use crate::router::{Handler, RouteRegistration};
+
+    fn ping(_req: &HttpRequest) -> ResponseFuture<'_> {
+        Box::pin(async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            HttpResponse::ok().text("pong")
+        })
+    }
+
+    inventory::submit! {
+        RouteRegistration { method: HttpMethod::GET, path: "/async/ping", handler: Handler::Async(ping) }
+    }
+
+    #[test]
+    fn test_async_handler_keep_alive_and_graceful_shutdown() {
+        let mut context = ConcurrentApplicationContext::default();
+        context.refresh().unwrap();
+        let properties = ServerProperties {
+            address: "127.0.0.1".to_string(),
+            port: 0,
+            threads: 2,
+            ..ServerProperties::default()
+        };
+        let server = AsyncHttpServer::bind(properties, context).unwrap();
+        let addr = server.local_addr().unwrap();
+        let shutdown = server.shutdown_handle();
+        let serving = thread::spawn(move || server.serve_blocking());
+
+        // 同一连接上的两个请求：async handler 使用 tokio 的计时器，HEAD 只有头部
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(
+                b"GET /async/ping HTTP/1.1\r\nHost: localhost\r\n\r\n\
+                  HEAD /async/ping HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+            )
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (first, second) = response.split_at(response.rfind("HTTP/1.1").unwrap());
+        assert!(first.starts_with("HTTP/1.1 200") && first.ends_with("\r\n\r\npong"), "{}", response);
+        assert!(second.starts_with("HTTP/1.1 200") && second.ends_with("\r\n\r\n"), "{}", response);
+
+        // 停机时关闭空闲连接；之后上下文已 close()
+        let _idle = TcpStream::connect(addr).unwrap();
+        shutdown.shutdown();
+        let context = serving.join().unwrap();
+        assert!(!context.is_active());
+    }
+
+    #[test]
+    fn test_request_size_and_keep_alive_limits() {
+        let mut context = ConcurrentApplicationContext::default();
+        context.refresh().unwrap();
+        let properties = ServerProperties {
+            address: "127.0.0.1".to_string(),
+            port: 0,
+            threads: 1,
+            max_keep_alive_requests: 1,
+            max_http_request_size: spring_beans::env::bind::DataSize::of_bytes(4),
+            ..ServerProperties::default()
+        };
+        let server = AsyncHttpServer::bind(properties, context).unwrap();
+        let addr = server.local_addr().unwrap();
+        let shutdown = server.shutdown_handle();
+        let serving = thread::spawn(move || server.serve_blocking());
+
+        let exchange = |raw: &[u8]| {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream.write_all(raw).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+        // body 超限返回 413 并关闭连接
+        let response = exchange(b"POST /async/ping HTTP/1.1\r\nHost: localhost\r\nContent-Length: 5\r\n\r\nhello");
+        assert!(response.starts_with("HTTP/1.1 413"), "{}", response);
+        // 达到 max-keep-alive-requests 后连接关闭，流水线上的第二个请求不再处理
+        let response = exchange(
+            b"GET /async/ping HTTP/1.1\r\nHost: localhost\r\n\r\nGET /async/ping HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        );
+        assert!(response.to_ascii_lowercase().contains("connection: close"), "{}", response);
+        assert_eq!(response.matches("HTTP/1.1 200").count(), 1, "{}", response);
+
+        shutdown.shutdown();
+        serving.join().unwrap();
+    }
+}
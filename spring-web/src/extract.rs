@@ -0,0 +1,40 @@
+use crate::request::HttpRequest;
+use crate::response::HttpResponse;
+use crate::status::StatusCode;
+
+/// 从请求中构造 handler 参数。
+///
+/// `#[GetMapping]` 等宏对 `ctrl` 与 `&HttpRequest` 之外的参数调用 `from_request`；
+/// 返回 `Err(response)` 时直接把该响应返回给客户端（通常是 400）。
+/// 参数上标注 `#[Valid]` 时，构造成功后还会执行 `Validate::validate`，违规返回 400 + JSON。
+/// 标注 `#[RequestBody]` 的参数同样通过 `from_request` 构造，但 body 为空时直接返回 400。
+///
+/// ```rust,ignore
+/// impl FromRequest for NewUser {
+///     fn from_request(req: &HttpRequest) -> Result<Self, HttpResponse> {
+///         let form = req.form();
+///         Ok(NewUser { name: form.get("name").cloned().unwrap_or_default() })
+///     }
+/// }
+///
+/// #[PostMapping("/users")]
+/// fn create(ctrl: &UserController, req: &HttpRequest, #[Valid] user: NewUser) -> HttpResponse { ... }
+/// ```
+pub trait FromRequest: Sized {
+    fn from_request(req: &HttpRequest) -> Result<Self, HttpResponse>;
+}
+
+/// 原始 body（`#[RequestBody] body: Vec<u8>`）
+impl FromRequest for Vec<u8> {
+    fn from_request(req: &HttpRequest) -> Result<Self, HttpResponse> {
+        Ok(req.body.clone())
+    }
+}
+
+/// UTF-8 文本 body（`#[RequestBody] body: String`），不是合法 UTF-8 时返回 400
+impl FromRequest for String {
+    fn from_request(req: &HttpRequest) -> Result<Self, HttpResponse> {
+        String::from_utf8(req.body.clone())
+            .map_err(|_| HttpResponse::error(StatusCode::BAD_REQUEST, "request body is not valid UTF-8"))
+    }
+}
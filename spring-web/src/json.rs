@@ -0,0 +1,127 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use spring_context::validation::{ConstraintViolations, Validate};
+
+use crate::extract::FromRequest;
+use crate::request::HttpRequest;
+use crate::respond::IntoResponse;
+use crate::response::HttpResponse;
+use crate::status::StatusCode;
+
+/// JSON 请求体 / 响应体（`serde` feature）。
+///
+/// 作为 handler 参数时从 body 反序列化：`Content-Type` 不是 JSON 时返回 415，
+/// body 不是合法的 `T` 时返回 400；作为返回值时序列化为 200 + JSON。
+///
+/// ```rust,ignore
+/// #[PostMapping("/users")]
+/// fn create(ctrl: &UserController, #[Valid] #[RequestBody] user: Json<NewUser>) -> Json<User> {
+///     Json(ctrl.create(user.into_inner()))
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Json<T>(pub T);
+
+impl<T> Json<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Json<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: DeserializeOwned> FromRequest for Json<T> {
+    fn from_request(req: &HttpRequest) -> Result<Self, HttpResponse> {
+        if req.header("content-type").is_some() && !req.is_json() {
+            return Err(HttpResponse::error(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "expected Content-Type: application/json",
+            ));
+        }
+        req.json().map(Json)
+    }
+}
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self) -> HttpResponse {
+        HttpResponse::ok().json_of(&self.0)
+    }
+}
+
+/// `#[Valid] Json<T>` 校验其中的 `T`
+impl<T: Validate> Validate for Json<T> {
+    fn validate_into(&self, path: &str, violations: &mut ConstraintViolations) {
+        self.0.validate_into(path, violations);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fmt;
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::respond::ResponseError;
+
+    fn request(content_type: &str, body: &str) -> HttpRequest {
+        let raw = format!(
+            "POST /scores HTTP/1.1\r\nContent-Type: {}\r\nContent-Length: {}\r\n\r\n{}",
+            content_type,
+            body.len(),
+            body
+        );
+        HttpRequest::read_from(&mut Cursor::new(raw.into_bytes()), &mut Vec::new()).unwrap().unwrap()
+    }
+
+    #[derive(Debug)]
+    struct Missing(&'static str);
+
+    impl fmt::Display for Missing {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} not found", self.0)
+        }
+    }
+
+    impl ResponseError for Missing {
+        fn status_code(&self) -> StatusCode {
+            StatusCode::NOT_FOUND
+        }
+    }
+
+    #[test]
+    fn test_json_extract_and_respond() {
+        let Json(scores) =
+            Json::<BTreeMap<String, u32>>::from_request(&request("application/json", r#"{"a":1,"b":2}"#)).unwrap();
+        assert_eq!(scores["b"], 2);
+
+        let bad_body = Json::<BTreeMap<String, u32>>::from_request(&request("application/json", r#"{"a":"x"}"#));
+        assert_eq!(bad_body.unwrap_err().status, StatusCode::BAD_REQUEST);
+        let bad_type = Json::<BTreeMap<String, u32>>::from_request(&request("text/plain", "{}"));
+        assert_eq!(bad_type.unwrap_err().status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let ok: Result<Json<_>, Missing> = Ok(Json(scores));
+        let resp = ok.into_response();
+        assert_eq!(resp.status, StatusCode::OK);
+        assert_eq!(resp.body, br#"{"a":1,"b":2}"#);
+
+        let err: Result<Json<()>, Missing> = Err(Missing("user 7"));
+        let resp = err.into_response();
+        assert_eq!(resp.status, StatusCode::NOT_FOUND);
+        assert!(String::from_utf8(resp.body).unwrap().contains("user 7 not found"));
+    }
+}
@@ -2,24 +2,52 @@
 //!
 //! 提供：
 //! - [`HttpMethod`] / [`StatusCode`] — HTTP 基础类型
-//! - [`HttpRequest`] — 从 TCP 流解析 HTTP/1.x 请求（含 path params、query、header、body）
-//! - [`HttpResponse`] — 链式构建响应（text / json / html / body）
+//! - [`HttpRequest`] — 从 TCP 流解析 HTTP/1.x 请求（含 path params、query、header、body，支持 chunked 与持久连接）
+//! - [`HttpResponse`] — 链式构建响应（text / json / html / body，或经 [`BodyWriter`] 流式输出）
+//! - [`FromRequest`] — 自定义 handler 参数的提取（配合 `#[Valid]` 校验）
+//! - [`IntoResponse`] / [`ResponseError`] — handler 返回值与错误到响应的转换
+//! - `Json<T>` — `serde` feature：JSON 请求体 / 响应体
 //! - [`RouteRegistration`] / [`Handler`] — `inventory` 路由注册表
 //! - [`Router`] — 路径匹配（支持 `{param}`）+ IoC bean 注入分发
-//! - [`HttpServer`] — 单线程 TCP 监听循环
+//! - [`RequestScope`] / [`SessionScope`] — `#[Scope("request")]` / `#[Scope("session")]` 的 bean，
+//!   由 [`RequestContextHolder`] 绑定到处理请求的线程
+//! - [`HttpServer`] — 监听线程 + 工作线程池，[`ShutdownHandle`] 优雅停机
+//! - [`ServerProperties`] — `server.*` 配置（端口、线程数、accept 队列、读写超时、请求大小限制）
+//! - `AsyncHttpServer` — `async` feature：tokio + hyper 实现，与 [`HttpServer`] 共用路由与配置
 
+#[cfg(feature = "async")]
+pub mod async_server;
+pub mod extract;
+#[cfg(feature = "serde")]
+pub mod json;
 pub mod method;
 pub mod status;
 pub mod request;
 pub mod response;
+pub mod request_context;
+pub mod respond;
 pub mod router;
+pub mod scope;
 pub mod server;
+pub mod server_properties;
+pub mod session;
 
+#[cfg(feature = "async")]
+pub use async_server::AsyncHttpServer;
+pub use extract::FromRequest;
+#[cfg(feature = "serde")]
+pub use json::Json;
 pub use method::HttpMethod;
 pub use status::StatusCode;
-pub use request::HttpRequest;
-pub use response::HttpResponse;
+pub use request::{HttpRequest, RequestLimits, RequestParseError};
+pub use response::{BodyWriter, HttpResponse};
 pub use router::{
-    BeanHandlerFn, Handler, PlainHandlerFn, RouteRegistration, Router,
+    AsyncBeanHandlerFn, AsyncPlainHandlerFn, BeanHandlerFn, Handler, PlainHandlerFn, ResponseFuture,
+    RouteRegistration, Router,
 };
-pub use server::HttpServer;
+pub use respond::{IntoResponse, ResponseError};
+pub use request_context::{RequestAttributes, RequestContextHolder, SESSION_COOKIE_NAME};
+pub use scope::{RequestScope, SessionScope};
+pub use server::{HttpServer, ShutdownHandle};
+pub use server_properties::ServerProperties;
+pub use session::HttpSessions;
@@ -11,6 +11,8 @@ pub enum HttpMethod {
 }
 
 impl HttpMethod {
+    // 公开 API 保持返回 Option，不改为 FromStr
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_uppercase().as_str() {
             "GET"     => Some(Self::GET),
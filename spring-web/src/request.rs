@@ -1,8 +1,84 @@
 use std::collections::HashMap;
-use std::io::{BufRead, BufReader, Read};
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, BufReader, Read, Write};
 use std::net::TcpStream;
 
+use spring_core::convert::{ConversionService, GenericConversionService};
+
+use crate::extract::FromRequest;
 use crate::method::HttpMethod;
+use crate::response::HttpResponse;
+use crate::status::StatusCode;
+
+/// 分块长度行与 trailer 的总长度上限
+const CHUNK_LINE_LIMIT: usize = 8 * 1024;
+
+/// 读取请求时的大小限制，由 [`ServerProperties`](crate::ServerProperties) 的
+/// `server.max-http-request-size`、`server.max-http-request-header-size` 与 `server.max-http-request-headers` 给出
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestLimits {
+    /// body 的最大字节数（chunked 时为所有块之和）
+    pub max_body_size: u64,
+    /// 请求行加所有头部的最大字节数
+    pub max_header_size: usize,
+    /// 最多的头部个数
+    pub max_headers: usize,
+}
+
+impl Default for RequestLimits {
+    fn default() -> Self {
+        Self {
+            max_body_size: 2 << 20,
+            max_header_size: 8 << 10,
+            max_headers: 100,
+        }
+    }
+}
+
+/// 读取请求失败：[`RequestParseError::status`] 是应该回给客户端的状态码
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestParseError {
+    status: StatusCode,
+    reason: String,
+}
+
+impl RequestParseError {
+    pub fn bad_request(reason: impl Display) -> Self {
+        Self { status: StatusCode::BAD_REQUEST, reason: reason.to_string() }
+    }
+
+    pub fn header_too_large(reason: impl Display) -> Self {
+        Self { status: StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE, reason: reason.to_string() }
+    }
+
+    pub fn payload_too_large(max_size: u64) -> Self {
+        Self {
+            status: StatusCode::PAYLOAD_TOO_LARGE,
+            reason: format!("request body exceeds {} bytes", max_size),
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// 回给客户端的错误响应
+    pub fn to_response(&self) -> HttpResponse {
+        HttpResponse::error(self.status, &self.reason)
+    }
+}
+
+impl Display for RequestParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.reason)
+    }
+}
+
+impl std::error::Error for RequestParseError {}
 
 /// 一个完整的 HTTP 请求
 #[derive(Debug)]
@@ -11,6 +87,8 @@ pub struct HttpRequest {
     pub method: HttpMethod,
     /// URL 路径（不含 query string）
     pub path: String,
+    /// 协议版本（`HTTP/1.0` / `HTTP/1.1`）
+    pub version: String,
     /// Query 参数（?key=val&…）
     pub query: HashMap<String, String>,
     /// 请求头（全部小写键）
@@ -23,73 +101,238 @@ pub struct HttpRequest {
 
 impl HttpRequest {
     /// 从 TcpStream 读取并解析一个 HTTP/1.x 请求。
-    /// 使用 BufReader 逐行读取头部，然后按 Content-Length 读取 body。
+    ///
+    /// 内部的缓冲区在返回后丢弃，因此只适合每个连接一个请求；
+    /// 持久连接上的后续请求用 [`HttpRequest::read_from`] 配合同一个 `BufReader` 读取。
     pub fn parse(stream: &mut TcpStream) -> Result<Self, String> {
-        // 将 &mut TcpStream 包在 BufReader 里，方便逐行读取
-        let mut reader = BufReader::new(stream as &mut dyn Read);
+        let mut reader = BufReader::new(stream.try_clone().map_err(|e| format!("clone stream: {}", e))?);
+        Self::read_from(&mut reader, stream)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "empty request line".to_string())
+    }
 
-        // 1. 读请求行  "GET /path?q=1 HTTP/1.1"
-        let mut request_line = String::new();
-        reader
-            .read_line(&mut request_line)
-            .map_err(|e| format!("read request line: {}", e))?;
-        let request_line = request_line.trim_end_matches(|c| c == '\r' || c == '\n');
+    /// 按默认的 [`RequestLimits`] 从 `reader` 读取下一个请求，见 [`HttpRequest::read_with_limits`]
+    pub fn read_from<R: BufRead, W: Write>(reader: &mut R, interim: &mut W) -> Result<Option<Self>, RequestParseError> {
+        Self::read_with_limits(reader, interim, &RequestLimits::default())
+    }
 
-        if request_line.is_empty() {
-            return Err("empty request line".to_string());
+    /// 从 `reader` 读取下一个请求；连接在请求开始前被对方关闭时返回 `Ok(None)`。
+    ///
+    /// body 按 `Content-Length` 或 `Transfer-Encoding: chunked` 读取，不会多读属于下一个请求的字节，
+    /// 因此同一个 `reader` 可以连续读取流水线上的多个请求。
+    /// 请求带有 `Expect: 100-continue` 时，先向 `interim` 写入 `100 Continue` 再读取 body。
+    ///
+    /// 请求行加头部超过 `limits.max_header_size` 或头部个数超过 `limits.max_headers` 时返回 `431`，
+    /// body 超过 `limits.max_body_size` 时返回 `413`，均不会先按客户端声明的长度分配内存。
+    pub fn read_with_limits<R: BufRead, W: Write>(
+        reader: &mut R,
+        interim: &mut W,
+        limits: &RequestLimits,
+    ) -> Result<Option<Self>, RequestParseError> {
+        // 请求行与头部共用 max_header_size 的额度
+        let mut header_budget = limits.max_header_size;
+
+        // 1. 读请求行  "GET /path?q=1 HTTP/1.1"，跳过请求之间多余的空行
+        let mut request_line = String::new();
+        loop {
+            request_line.clear();
+            let read = Self::read_header_line(reader, &mut request_line, &mut header_budget, "request line")?;
+            if read == 0 {
+                return Ok(None);
+            }
+            if !request_line.trim_end_matches(['\r', '\n']).is_empty() {
+                break;
+            }
         }
+        let request_line = request_line.trim_end_matches(['\r', '\n']);
 
         let mut parts = request_line.splitn(3, ' ');
         let method_str = parts.next().unwrap_or("");
         let full_path   = parts.next().unwrap_or("/");
-        // HTTP version 暂不校验
+        let version = match parts.next().unwrap_or("HTTP/1.0") {
+            v @ ("HTTP/1.0" | "HTTP/1.1") => v.to_string(),
+            v => return Err(RequestParseError::bad_request(format!("unsupported HTTP version: {}", v))),
+        };
 
         let method = HttpMethod::from_str(method_str)
-            .ok_or_else(|| format!("unsupported method: {}", method_str))?;
-
-        // 2. 分离 path 和 query string
-        let (path, query) = Self::split_path_query(full_path);
+            .ok_or_else(|| RequestParseError::bad_request(format!("unsupported method: {}", method_str)))?;
 
-        // 3. 读请求头，遇到空行（\r\n）停止
+        // 2. 读请求头，遇到空行（\r\n）停止
         let mut headers = HashMap::new();
         loop {
             let mut line = String::new();
-            reader
-                .read_line(&mut line)
-                .map_err(|e| format!("read header: {}", e))?;
-            let line = line.trim_end_matches(|c| c == '\r' || c == '\n');
+            let read = Self::read_header_line(reader, &mut line, &mut header_budget, "header")?;
+            if read == 0 {
+                return Err(RequestParseError::bad_request("connection closed while reading headers"));
+            }
+            let line = line.trim_end_matches(['\r', '\n']);
             if line.is_empty() {
                 break; // 头部结束空行
             }
+            if headers.len() >= limits.max_headers {
+                return Err(RequestParseError::header_too_large(format!(
+                    "more than {} request headers",
+                    limits.max_headers
+                )));
+            }
             // "Header-Name: value"
             if let Some(colon) = line.find(':') {
                 let key   = line[..colon].trim().to_lowercase();
                 let value = line[colon + 1..].trim().to_string();
+                // 长度不一致时无法确定 body 在哪里结束（请求走私），只能拒绝
+                if key == "content-length" && headers.get(&key).is_some_and(|v| *v != value) {
+                    return Err(RequestParseError::bad_request("conflicting content-length headers"));
+                }
                 headers.insert(key, value);
             }
         }
 
-        // 4. 读 body（按 Content-Length）
-        let content_length: usize = headers
-            .get("content-length")
-            .and_then(|v| v.trim().parse().ok())
-            .unwrap_or(0);
+        // 3. 读 body（chunked 或按 Content-Length）；两者同时出现时同样无法确定 body 的边界
+        if headers.contains_key("transfer-encoding") && headers.contains_key("content-length") {
+            return Err(RequestParseError::bad_request("both transfer-encoding and content-length are present"));
+        }
+        let chunked = match headers.get("transfer-encoding") {
+            Some(coding) if coding.eq_ignore_ascii_case("chunked") => true,
+            Some(coding) => {
+                return Err(RequestParseError::bad_request(format!("unsupported transfer-encoding: {}", coding)))
+            }
+            None => false,
+        };
+        let content_length: u64 = match headers.get("content-length") {
+            Some(v) if !chunked => v
+                .trim()
+                .parse()
+                .map_err(|_| RequestParseError::bad_request(format!("invalid content-length: {}", v)))?,
+            _ => 0,
+        };
+        // 在回复 100 Continue 之前拒绝，客户端不必再发送 body
+        if content_length > limits.max_body_size {
+            return Err(RequestParseError::payload_too_large(limits.max_body_size));
+        }
 
-        let mut body = vec![0u8; content_length];
-        if content_length > 0 {
-            reader
-                .read_exact(&mut body)
-                .map_err(|e| format!("read body: {}", e))?;
+        // 客户端在发送 body 前等待确认
+        let expects_continue = headers
+            .get("expect")
+            .is_some_and(|v| v.eq_ignore_ascii_case("100-continue"));
+        if expects_continue && version == "HTTP/1.1" && (chunked || content_length > 0) {
+            interim
+                .write_all(b"HTTP/1.1 100 Continue\r\n\r\n")
+                .and_then(|_| interim.flush())
+                .map_err(|e| RequestParseError::bad_request(format!("write 100 Continue: {}", e)))?;
         }
 
-        Ok(HttpRequest {
+        let body = if chunked {
+            Self::read_chunked_body(reader, limits.max_body_size)?
+        } else {
+            // 按实际收到的字节增长，不信任客户端声明的长度预先分配
+            let mut body = Vec::new();
+            let read = reader
+                .by_ref()
+                .take(content_length)
+                .read_to_end(&mut body)
+                .map_err(|e| RequestParseError::bad_request(format!("read body: {}", e)))?;
+            if (read as u64) < content_length {
+                return Err(RequestParseError::bad_request("connection closed in the middle of the body"));
+            }
+            body
+        };
+
+        Ok(Some(Self::from_parts(method, full_path, version, headers, body)))
+    }
+
+    /// 读取请求行或一行头部，最多消耗 `budget` 个字节；超出时返回 `431`
+    fn read_header_line<R: BufRead>(
+        reader: &mut R,
+        line: &mut String,
+        budget: &mut usize,
+        what: &str,
+    ) -> Result<usize, RequestParseError> {
+        let read = reader
+            .by_ref()
+            .take(*budget as u64 + 1)
+            .read_line(line)
+            .map_err(|e| RequestParseError::bad_request(format!("read {}: {}", what, e)))?;
+        if read > *budget {
+            return Err(RequestParseError::header_too_large("request line and headers too large"));
+        }
+        *budget -= read;
+        Ok(read)
+    }
+
+    /// 由已经解析好的各部分构造请求；`full_path` 中的 query string 在这里拆分
+    pub(crate) fn from_parts(
+        method: HttpMethod,
+        full_path: &str,
+        version: String,
+        headers: HashMap<String, String>,
+        body: Vec<u8>,
+    ) -> Self {
+        let (path, query) = Self::split_path_query(full_path);
+        HttpRequest {
             method,
             path,
+            version,
             query,
             headers,
             body,
             path_params: HashMap::new(),
-        })
+        }
+    }
+
+    /// `Transfer-Encoding: chunked`：`<十六进制长度>[;扩展]\r\n<数据>\r\n` 重复，直到长度为 0 的块，
+    /// 之后是可选的 trailer 头部（忽略）和结尾空行
+    fn read_chunked_body<R: BufRead>(reader: &mut R, max_size: u64) -> Result<Vec<u8>, RequestParseError> {
+        let mut body = Vec::new();
+        loop {
+            let mut size_line = String::new();
+            Self::read_header_line(reader, &mut size_line, &mut { CHUNK_LINE_LIMIT }, "chunk size")?;
+            let size = size_line.split(';').next().unwrap_or("").trim();
+            let size = u64::from_str_radix(size, 16)
+                .map_err(|_| RequestParseError::bad_request(format!("invalid chunk size: {:?}", size)))?;
+            if size == 0 {
+                break;
+            }
+            if size > max_size - body.len() as u64 {
+                return Err(RequestParseError::payload_too_large(max_size));
+            }
+            // 按实际收到的字节增长，不信任客户端声明的长度预先分配
+            let read = reader
+                .by_ref()
+                .take(size)
+                .read_to_end(&mut body)
+                .map_err(|e| RequestParseError::bad_request(format!("read chunk: {}", e)))?;
+            if (read as u64) < size {
+                return Err(RequestParseError::bad_request("connection closed in the middle of a chunk"));
+            }
+            let mut crlf = [0u8; 2];
+            reader
+                .read_exact(&mut crlf)
+                .map_err(|e| RequestParseError::bad_request(format!("read chunk: {}", e)))?;
+            if &crlf != b"\r\n" {
+                return Err(RequestParseError::bad_request("chunk data not terminated by CRLF"));
+            }
+        }
+        let mut trailer_budget = CHUNK_LINE_LIMIT;
+        loop {
+            let mut trailer = String::new();
+            let read = Self::read_header_line(reader, &mut trailer, &mut trailer_budget, "trailer")?;
+            if read == 0 || trailer.trim_end_matches(['\r', '\n']).is_empty() {
+                return Ok(body);
+            }
+        }
+    }
+
+    /// 响应后连接能否继续使用：HTTP/1.1 默认保持，HTTP/1.0 需要 `Connection: keep-alive`，
+    /// 两者都可以被 `Connection: close` 关闭
+    pub fn is_keep_alive(&self) -> bool {
+        let has_token = |token: &str| {
+            self.header("connection")
+                .is_some_and(|v| v.split(',').any(|t| t.trim().eq_ignore_ascii_case(token)))
+        };
+        if has_token("close") {
+            return false;
+        }
+        self.version == "HTTP/1.1" || has_token("keep-alive")
     }
 
     /// 获取路径参数（由 Router 在路由匹配后填充）。
@@ -102,16 +345,106 @@ impl HttpRequest {
         self.query.get(key).map(|s| s.as_str())
     }
 
+    /// 获取路径参数并用共享的 `ConversionService` 转换为 `T`；
+    /// 参数缺失或转换失败时返回可直接交给客户端的 400 响应。
+    pub fn path_param_as<T: 'static>(&self, key: &str) -> Result<T, HttpResponse> {
+        let value = self.path_param(key).ok_or_else(|| {
+            HttpResponse::error(StatusCode::BAD_REQUEST, format!("missing path variable '{}'", key))
+        })?;
+        Self::convert_param("path variable", key, value)
+    }
+
+    /// 获取 Query 参数并转换为 `T`；参数不存在时为 `Ok(None)`，转换失败返回 400 响应。
+    pub fn query_param_as<T: 'static>(&self, key: &str) -> Result<Option<T>, HttpResponse> {
+        self.query_param(key)
+            .map(|value| Self::convert_param("request parameter", key, value))
+            .transpose()
+    }
+
+    /// 获取 Query 参数并转换为 `T`；参数不存在时转换 `default`，仍没有值则返回 400 响应。
+    /// `#[RequestParam]` 标注的 handler 参数由它提取。
+    pub fn required_query_param_as<T: 'static>(&self, key: &str, default: Option<&str>) -> Result<T, HttpResponse> {
+        Self::required_param("request parameter", key, self.query_param(key).or(default))
+    }
+
+    /// 获取请求头并转换为 `T`；请求头不存在时为 `Ok(None)`，转换失败返回 400 响应。
+    pub fn header_as<T: 'static>(&self, key: &str) -> Result<Option<T>, HttpResponse> {
+        self.header(key)
+            .map(|value| Self::convert_param("request header", key, value))
+            .transpose()
+    }
+
+    /// 获取请求头并转换为 `T`；请求头不存在时转换 `default`，仍没有值则返回 400 响应。
+    /// `#[RequestHeader]` 标注的 handler 参数由它提取。
+    pub fn required_header_as<T: 'static>(&self, key: &str, default: Option<&str>) -> Result<T, HttpResponse> {
+        Self::required_param("request header", key, self.header(key).or(default))
+    }
+
+    fn required_param<T: 'static>(kind: &str, key: &str, value: Option<&str>) -> Result<T, HttpResponse> {
+        let value = value.ok_or_else(|| {
+            HttpResponse::error(StatusCode::BAD_REQUEST, format!("missing {} '{}'", kind, key))
+        })?;
+        Self::convert_param(kind, key, value)
+    }
+
+    fn convert_param<T: 'static>(kind: &str, key: &str, value: &str) -> Result<T, HttpResponse> {
+        GenericConversionService::get_shared_instance()
+            .convert::<str, T>(value)
+            .map_err(|e| {
+                HttpResponse::error(
+                    StatusCode::BAD_REQUEST,
+                    format!("invalid value '{}' for {} '{}': {}", value, kind, key, e),
+                )
+            })
+    }
+
     /// 获取请求头（键不区分大小写，内部已统一小写）。
     pub fn header(&self, key: &str) -> Option<&str> {
         self.headers.get(&key.to_lowercase()).map(|s| s.as_str())
     }
 
+    /// 获取 `Cookie` 请求头中名为 `name` 的 cookie。
+    pub fn cookie(&self, name: &str) -> Option<&str> {
+        self.header("cookie")?
+            .split(';')
+            .filter_map(|pair| pair.trim().split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+    }
+
+    /// 用 [`FromRequest`] 从 body 构造 `T`；body 为空时为 `Ok(None)`。
+    pub fn body_as<T: FromRequest>(&self) -> Result<Option<T>, HttpResponse> {
+        if self.body.is_empty() {
+            return Ok(None);
+        }
+        T::from_request(self).map(Some)
+    }
+
+    /// 用 [`FromRequest`] 从 body 构造 `T`；body 为空时返回 400 响应。
+    /// `#[RequestBody]` 标注的 handler 参数由它提取。
+    pub fn required_body_as<T: FromRequest>(&self) -> Result<T, HttpResponse> {
+        self.body_as()?
+            .ok_or_else(|| HttpResponse::error(StatusCode::BAD_REQUEST, "required request body is missing"))
+    }
+
+    /// 把 JSON body 反序列化为 `T`；body 不是合法的 `T` 时返回 400 响应（`serde` feature）。
+    #[cfg(feature = "serde")]
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T, HttpResponse> {
+        serde_json::from_slice(&self.body).map_err(|e| {
+            HttpResponse::error(StatusCode::BAD_REQUEST, format!("invalid JSON body: {}", e))
+        })
+    }
+
     /// 以 UTF-8 字符串形式返回 body。
     pub fn body_str(&self) -> &str {
         std::str::from_utf8(&self.body).unwrap_or("")
     }
 
+    /// 把 `application/x-www-form-urlencoded` body 解析为键值对。
+    pub fn form(&self) -> HashMap<String, String> {
+        Self::parse_query(self.body_str())
+    }
+
     /// Content-Type 是否为 JSON。
     pub fn is_json(&self) -> bool {
         self.header("content-type")
@@ -125,6 +458,11 @@ impl HttpRequest {
             None    => (full, ""),
         };
 
+        (path_str.to_string(), Self::parse_query(query_str))
+    }
+
+    /// `a=1&b=2` → {a: 1, b: 2}
+    fn parse_query(query_str: &str) -> HashMap<String, String> {
         let mut query = HashMap::new();
         for pair in query_str.split('&') {
             if pair.is_empty() { continue; }
@@ -140,8 +478,7 @@ impl HttpRequest {
                 }
             }
         }
-
-        (path_str.to_string(), query)
+        query
     }
 
     /// 简单 URL 解码（替换 %XX 和 +）
@@ -169,3 +506,90 @@ impl HttpRequest {
         out
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_read_pipelined_chunked_request_with_continue() {
+        let raw = "POST /upload HTTP/1.1\r\nTransfer-Encoding: chunked\r\nExpect: 100-continue\r\n\r\n\
+                   5;ext=1\r\nhello\r\n6\r\n world\r\n0\r\nX-Trailer: t\r\n\r\n\
+                   GET /next HTTP/1.0\r\n\r\n";
+        let mut reader = Cursor::new(raw.as_bytes());
+        let mut interim = Vec::new();
+
+        let first = HttpRequest::read_from(&mut reader, &mut interim).unwrap().unwrap();
+        assert_eq!(first.body_str(), "hello world");
+        assert!(first.is_keep_alive());
+        assert_eq!(interim, b"HTTP/1.1 100 Continue\r\n\r\n");
+
+        // chunked body 之后的字节属于下一个请求
+        let second = HttpRequest::read_from(&mut reader, &mut interim).unwrap().unwrap();
+        assert_eq!(second.path, "/next");
+        assert!(!second.is_keep_alive());
+        assert!(HttpRequest::read_from(&mut reader, &mut interim).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_request_limits() {
+        let limits = RequestLimits { max_body_size: 8, max_header_size: 64, max_headers: 2 };
+        let read = |raw: &str| {
+            HttpRequest::read_with_limits(&mut Cursor::new(raw.as_bytes()), &mut Vec::new(), &limits)
+                .map(|req| req.unwrap().body_str().to_string())
+                .map_err(|e| e.status())
+        };
+
+        assert_eq!(read("POST / HTTP/1.1\r\nContent-Length: 8\r\n\r\n12345678").unwrap(), "12345678");
+        // 声明的长度超限时直接拒绝，不等待也不分配 body
+        assert_eq!(read("POST / HTTP/1.1\r\nContent-Length: 99999999999\r\n\r\n"), Err(StatusCode::PAYLOAD_TOO_LARGE));
+        assert_eq!(
+            read("POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n5\r\nworld\r\n0\r\n\r\n"),
+            Err(StatusCode::PAYLOAD_TOO_LARGE)
+        );
+        assert_eq!(read("POST / HTTP/1.1\r\nContent-Length: 4\r\n\r\nab"), Err(StatusCode::BAD_REQUEST));
+
+        // body 边界有歧义的请求一律 400；重复但一致的 Content-Length 可以接受
+        let ambiguous = |raw: &str| {
+            HttpRequest::read_from(&mut Cursor::new(raw.as_bytes()), &mut Vec::new())
+                .map(|req| req.unwrap().body_str().to_string())
+                .map_err(|e| e.status())
+        };
+        assert_eq!(ambiguous("POST / HTTP/1.1\r\nContent-Length: 2\r\nContent-Length: 2\r\n\r\nab").unwrap(), "ab");
+        assert_eq!(
+            ambiguous("POST / HTTP/1.1\r\nContent-Length: 2\r\nContent-Length: 4\r\n\r\nabcd"),
+            Err(StatusCode::BAD_REQUEST)
+        );
+        assert_eq!(
+            ambiguous("POST / HTTP/1.1\r\nContent-Length: 2\r\nTransfer-Encoding: chunked\r\n\r\n2\r\nab\r\n0\r\n\r\n"),
+            Err(StatusCode::BAD_REQUEST)
+        );
+
+        assert_eq!(read("GET / HTTP/1.1\r\nA: 1\r\nB: 2\r\nC: 3\r\n\r\n"), Err(StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE));
+        let long = format!("GET / HTTP/1.1\r\nX-Long: {}\r\n\r\n", "x".repeat(64));
+        assert_eq!(read(&long), Err(StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE));
+    }
+
+    #[test]
+    fn test_annotated_parameter_extraction() {
+        let raw = "GET /users?limit=5&page=x HTTP/1.1\r\nX-Request-Id: 42\r\n\r\n";
+        let req = HttpRequest::read_from(&mut Cursor::new(raw.as_bytes()), &mut Vec::new()).unwrap().unwrap();
+
+        assert_eq!(req.required_query_param_as::<usize>("limit", Some("10")).unwrap(), 5);
+        assert_eq!(req.required_query_param_as::<usize>("size", Some("10")).unwrap(), 10);
+        assert_eq!(req.required_header_as::<u64>("x-request-id", None).unwrap(), 42);
+        assert_eq!(req.header_as::<String>("If-None-Match").unwrap(), None);
+        assert_eq!(req.body_as::<String>().unwrap(), None);
+
+        // 缺失、转换失败与空 body 都是 400
+        for resp in [
+            req.required_query_param_as::<usize>("size", None).unwrap_err(),
+            req.required_query_param_as::<usize>("page", Some("1")).unwrap_err(),
+            req.required_body_as::<String>().unwrap_err(),
+        ] {
+            assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::cell::RefCell;
+
+use spring_beans::factory::config::ScopedObjects;
+
+use crate::session::HttpSessions;
+
+/// 会话 id 所在的 cookie 名称
+pub const SESSION_COOKIE_NAME: &str = "SESSIONID";
+
+/// 当前请求的作用域状态：request 作用域的 bean 与本次请求关联的会话。
+///
+/// 由 [`HttpServer`](crate::HttpServer) 在分发请求前放入 [`RequestContextHolder`]，
+/// 响应生成后取出并调用 [`RequestAttributes::request_completed`]。
+pub struct RequestAttributes {
+    objects: ScopedObjects,
+    session_id: Option<String>,
+    session_created: bool,
+}
+
+impl RequestAttributes {
+    /// `session_id` 通常来自请求的 `SESSIONID` cookie；已过期或不存在的会话会被忽略
+    pub fn new(session_id: Option<&str>) -> Self {
+        HttpSessions::evict_expired();
+        Self {
+            objects: ScopedObjects::new(),
+            session_id: session_id.filter(|id| HttpSessions::touch(id)).map(str::to_string),
+            session_created: false,
+        }
+    }
+
+    pub fn session_id(&self) -> Option<&str> {
+        self.session_id.as_deref()
+    }
+
+    /// 本次请求中新建的会话 id，服务器据此写回 `Set-Cookie`
+    pub fn new_session_id(&self) -> Option<&str> {
+        self.session_id.as_deref().filter(|_| self.session_created)
+    }
+
+    /// 当前会话 id，没有时新建一个会话
+    pub fn get_or_create_session_id(&mut self) -> String {
+        if let Some(id) = &self.session_id {
+            return id.clone();
+        }
+        let id = HttpSessions::create();
+        self.session_id = Some(id.clone());
+        self.session_created = true;
+        id
+    }
+
+    pub(crate) fn objects(&mut self) -> &mut ScopedObjects {
+        &mut self.objects
+    }
+
+    /// 请求结束：销毁 request 作用域的 bean
+    pub fn request_completed(mut self) {
+        self.objects.destroy();
+    }
+}
+
+thread_local! {
+    static REQUEST_ATTRIBUTES: RefCell<Option<RequestAttributes>> = const { RefCell::new(None) };
+}
+
+/// 把当前请求绑定到处理它的线程，对标 Spring 的 `RequestContextHolder`
+pub struct RequestContextHolder;
+
+impl RequestContextHolder {
+    pub fn set_request_attributes(attributes: RequestAttributes) {
+        REQUEST_ATTRIBUTES.with(|current| *current.borrow_mut() = Some(attributes));
+    }
+
+    /// 解除绑定并返回当前请求的状态
+    pub fn reset_request_attributes() -> Option<RequestAttributes> {
+        REQUEST_ATTRIBUTES.with(|current| current.borrow_mut().take())
+    }
+
+    /// 当前线程是否正在处理请求
+    pub fn is_active() -> bool {
+        REQUEST_ATTRIBUTES.with(|current| current.borrow().is_some())
+    }
+
+    /// 当前请求的会话 id；没有请求或请求不属于任何会话时返回 `None`
+    pub fn current_session_id() -> Option<String> {
+        Self::with(|attributes| attributes.session_id().map(str::to_string)).flatten()
+    }
+
+    /// 在当前请求的状态上执行 `f`；没有正在处理的请求时返回 `None`。
+    /// `f` 执行期间不能再次调用 `with`。
+    pub fn with<R>(f: impl FnOnce(&mut RequestAttributes) -> R) -> Option<R> {
+        REQUEST_ATTRIBUTES.with(|current| current.borrow_mut().as_mut().map(f))
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+
+use spring_context::validation::ConstraintViolations;
+
+use crate::response::HttpResponse;
+use crate::status::StatusCode;
+
+/// handler 返回值 → [`HttpResponse`]。
+///
+/// `#[GetMapping]` 等宏对 handler 的返回值调用 `into_response`：
+/// - `HttpResponse` 原样返回
+/// - `String` / `&'static str` 返回 200 + text/plain
+/// - `Json<T>` 序列化为 200 + JSON（`serde` feature）；其它返回类型 `T: Serialize`
+///   （包括 `Result<T, E>` 的 `T`）由宏包装成 `Json<T>`。serde 也为 `Result` 实现了 `Serialize`，
+///   所以包装在宏里按返回类型完成，而不是对 `T: Serialize` 做 blanket 实现
+/// - `Result<T, E>`：`Ok` 按 `T` 转换，`Err` 由 [`ResponseError::error_response`] 转换
+///
+/// ```rust,ignore
+/// #[GetMapping("/users/{id}")]
+/// fn get(ctrl: &UserController, #[PathVariable] id: u64) -> Result<User, UserError> { ... }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be returned from a handler",
+    label = "does not implement `IntoResponse`",
+    note = "return `HttpResponse`, `String`, `Json<T>`, or a `T: Serialize` written out in the signature (the macro does not see through type aliases; JSON requires the `serde` feature of spring-boot)"
+)]
+pub trait IntoResponse {
+    fn into_response(self) -> HttpResponse;
+}
+
+impl IntoResponse for HttpResponse {
+    fn into_response(self) -> HttpResponse {
+        self
+    }
+}
+
+impl IntoResponse for String {
+    fn into_response(self) -> HttpResponse {
+        HttpResponse::ok().text(self)
+    }
+}
+
+impl IntoResponse for &'static str {
+    fn into_response(self) -> HttpResponse {
+        HttpResponse::ok().text(self)
+    }
+}
+
+impl<T: IntoResponse, E: ResponseError> IntoResponse for Result<T, E> {
+    fn into_response(self) -> HttpResponse {
+        match self {
+            Ok(value) => value.into_response(),
+            Err(error) => error.error_response(),
+        }
+    }
+}
+
+/// 可以作为错误响应返回的错误类型，对标 Spring 的 `@ResponseStatus` / `@ExceptionHandler`。
+///
+/// 默认实现返回 `status_code()` 与 `{"status":..,"error":..,"message":"<Display>"}`：
+///
+/// ```rust,ignore
+/// impl ResponseError for UserError {
+///     fn status_code(&self) -> StatusCode {
+///         match self {
+///             UserError::NotFound(_) => StatusCode::NOT_FOUND,
+///             UserError::Duplicate(_) => StatusCode::CONFLICT,
+///         }
+///     }
+/// }
+/// ```
+pub trait ResponseError: fmt::Display {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::error(self.status_code(), self.to_string())
+    }
+}
+
+/// 校验失败：400 + 违规列表，与 `#[Valid]` 参数的响应相同
+impl ResponseError for ConstraintViolations {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::constraint_violations(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_into_response() {
+        for resp in [String::from("hello").into_response(), "hello".into_response()] {
+            assert_eq!(resp.status, StatusCode::OK);
+            assert_eq!(resp.body, b"hello");
+            assert!(resp.headers.get("Content-Type").is_some_and(|v| v.starts_with("text/plain")));
+        }
+    }
+}
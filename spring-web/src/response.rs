@@ -1,15 +1,35 @@
 use std::collections::HashMap;
-use std::io::Write;
+use std::fmt;
+use std::io::{self, Write};
 use std::net::TcpStream;
 
+use spring_context::validation::ConstraintViolations;
+
+use crate::method::HttpMethod;
+use crate::request::HttpRequest;
 use crate::status::StatusCode;
 
+/// 流式 body：写出响应时调用，写入 [`BodyWriter`] 的数据逐块发给客户端
+pub(crate) type StreamingBody = Box<dyn FnOnce(&mut BodyWriter<'_>) -> io::Result<()> + Send>;
+
 /// HTTP 响应构建器
-#[derive(Debug)]
 pub struct HttpResponse {
     pub status:  StatusCode,
     pub headers: HashMap<String, String>,
     pub body:    Vec<u8>,
+    /// 设置后忽略 `body`，以 `Transfer-Encoding: chunked` 发送
+    streaming: Option<StreamingBody>,
+}
+
+impl fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .field("body", &self.body)
+            .field("streaming", &self.streaming.is_some())
+            .finish()
+    }
 }
 
 impl HttpResponse {
@@ -22,6 +42,7 @@ impl HttpResponse {
             status,
             headers: HashMap::new(),
             body: Vec::new(),
+            streaming: None,
         }
     }
 
@@ -34,6 +55,7 @@ impl HttpResponse {
     pub fn not_found()        -> Self { Self::new(StatusCode::NOT_FOUND) }
     pub fn method_not_allowed()-> Self { Self::new(StatusCode::METHOD_NOT_ALLOWED) }
     pub fn internal_error()   -> Self { Self::new(StatusCode::INTERNAL_SERVER_ERROR) }
+    pub fn service_unavailable() -> Self { Self::new(StatusCode::SERVICE_UNAVAILABLE) }
 
     // ──────────────────────────────────────────────────────────────────────────
     // 链式 builder
@@ -72,6 +94,15 @@ impl HttpResponse {
         self
     }
 
+    /// 用 serde 把 `value` 序列化为 JSON body；序列化失败时变为 500 响应（`serde` feature）
+    #[cfg(feature = "serde")]
+    pub fn json_of<T: serde::Serialize + ?Sized>(self, value: &T) -> Self {
+        match serde_json::to_string(value) {
+            Ok(payload) => self.json(payload),
+            Err(e) => Self::error(StatusCode::INTERNAL_SERVER_ERROR, format!("failed to serialize response body: {}", e)),
+        }
+    }
+
     /// 设置任意 body 字节
     pub fn body(mut self, bytes: impl Into<Vec<u8>>) -> Self {
         let b = bytes.into();
@@ -80,31 +111,225 @@ impl HttpResponse {
         self
     }
 
+    /// 流式 body：写出响应时调用 `write`，写入的数据以 chunked 编码逐块发送，
+    /// 适合事先不知道长度或不想整体放进内存的内容
+    ///
+    /// ```rust,ignore
+    /// HttpResponse::ok()
+    ///     .header("Content-Type", "text/csv")
+    ///     .streaming(move |out| {
+    ///         for row in rows {
+    ///             writeln!(out, "{},{}", row.id, row.name)?;
+    ///         }
+    ///         Ok(())
+    ///     })
+    /// ```
+    pub fn streaming(mut self, write: impl FnOnce(&mut BodyWriter<'_>) -> io::Result<()> + Send + 'static) -> Self {
+        self.headers.retain(|key, _| !key.eq_ignore_ascii_case("Content-Length"));
+        self.body.clear();
+        self.streaming = Some(Box::new(write));
+        self
+    }
+
+    /// 流式 body：迭代器的每一项作为一个 chunk 发送
+    pub fn chunks<I>(self, chunks: I) -> Self
+    where
+        I: IntoIterator + Send + 'static,
+        I::Item: AsRef<[u8]>,
+    {
+        self.streaming(move |out| {
+            for chunk in chunks {
+                out.write_all(chunk.as_ref())?;
+                out.flush()?;
+            }
+            Ok(())
+        })
+    }
+
+    /// 是否为流式响应
+    pub fn is_streaming(&self) -> bool {
+        self.streaming.is_some()
+    }
+
+    pub(crate) fn take_streaming(&mut self) -> Option<StreamingBody> {
+        self.streaming.take()
+    }
+
+    /// 校验失败的 400 响应：
+    /// `{"status":400,"error":"Bad Request","message":"Validation failed","violations":[...]}`
+    pub fn constraint_violations(violations: &ConstraintViolations) -> Self {
+        Self::bad_request().json(format!(
+            r#"{{"status":400,"error":"Bad Request","message":"Validation failed","violations":{}}}"#,
+            violations.to_json()
+        ))
+    }
+
+    /// 带说明的错误响应：`{"status":400,"error":"Bad Request","message":"..."}`
+    pub fn error(status: StatusCode, message: impl AsRef<str>) -> Self {
+        Self::new(status).json(format!(
+            r#"{{"status":{},"error":"{}","message":"{}"}}"#,
+            status.0,
+            status.reason(),
+            escape_json(message.as_ref())
+        ))
+    }
+
     // ──────────────────────────────────────────────────────────────────────────
     // 序列化写入
     // ──────────────────────────────────────────────────────────────────────────
 
-    /// 将响应序列化为 HTTP/1.1 报文写入 TcpStream。
-    pub fn write_to(&self, stream: &mut TcpStream) -> std::io::Result<()> {
-        // 状态行
-        let status_line = format!("HTTP/1.1 {} {}\r\n", self.status.0, self.status.reason());
-        stream.write_all(status_line.as_bytes())?;
+    /// 将响应序列化为 HTTP/1.1 报文写入 TcpStream，并告知客户端关闭连接。
+    pub fn write_to(self, stream: &mut TcpStream) -> io::Result<()> {
+        self.write_message(stream, false, false, true)
+    }
+
+    /// 按请求协商的方式写出响应：`HEAD` 请求只发送头部，
+    /// `keep_alive` 为 true 时保持连接（流式响应遇到 HTTP/1.0 客户端时只能以关闭连接结束 body）。
+    ///
+    /// 返回连接在响应之后能否继续使用。
+    pub fn write_for<W: Write>(self, req: &HttpRequest, keep_alive: bool, writer: &mut W) -> io::Result<bool> {
+        let chunked = req.version == "HTTP/1.1";
+        let keep_alive = keep_alive && (chunked || self.streaming.is_none());
+        let head = req.method == HttpMethod::HEAD;
+        self.write_message(writer, keep_alive, head, chunked)?;
+        Ok(keep_alive)
+    }
+
+    fn write_message<W: Write>(mut self, writer: &mut W, keep_alive: bool, head: bool, chunked: bool) -> io::Result<()> {
+        // 1xx / 204 / 304 不能带 body
+        let bodiless = self.status.0 < 200 || self.status.0 == 204 || self.status.0 == 304;
+        let streaming = self.take_streaming().filter(|_| !bodiless);
 
-        // 固定头部
-        stream.write_all(b"Connection: close\r\n")?;
+        // 状态行与连接管理头部（由服务器决定，忽略 handler 设置的同名头部）
+        let mut message = format!("HTTP/1.1 {} {}\r\n", self.status.0, self.status.reason());
+        message.push_str(if keep_alive { "Connection: keep-alive\r\n" } else { "Connection: close\r\n" });
+        let managed = ["Connection", "Content-Length", "Transfer-Encoding"];
+        if streaming.is_some() {
+            if chunked {
+                message.push_str("Transfer-Encoding: chunked\r\n");
+            }
+        } else if !bodiless {
+            // HEAD 的 Content-Length 与对应的 GET 相同
+            message.push_str(&format!("Content-Length: {}\r\n", self.body.len()));
+        }
 
         // 用户定义的头部
         for (key, val) in &self.headers {
-            let header = format!("{}: {}\r\n", key, val);
-            stream.write_all(header.as_bytes())?;
+            if !managed.iter().any(|m| key.eq_ignore_ascii_case(m)) {
+                message.push_str(&format!("{}: {}\r\n", key, val));
+            }
         }
 
         // 空行
-        stream.write_all(b"\r\n")?;
+        message.push_str("\r\n");
+        writer.write_all(message.as_bytes())?;
 
         // body
-        stream.write_all(&self.body)?;
-        stream.flush()?;
+        if !head {
+            match streaming {
+                Some(write) => {
+                    let mut out = BodyWriter::new(writer, chunked);
+                    write(&mut out)?;
+                    out.finish()?;
+                }
+                None if !bodiless => writer.write_all(&self.body)?,
+                None => {}
+            }
+        }
+        writer.flush()
+    }
+}
+
+/// 流式响应的 body 写入端：每次 `write` 发送一个 chunk，结束时由服务器写出终止块
+pub struct BodyWriter<'a> {
+    inner: &'a mut dyn Write,
+    chunked: bool,
+}
+
+impl<'a> BodyWriter<'a> {
+    /// `chunked` 为 false 时原样写出，由调用方负责 body 的边界
+    pub(crate) fn new(inner: &'a mut dyn Write, chunked: bool) -> Self {
+        Self { inner, chunked }
+    }
+
+    fn finish(self) -> io::Result<()> {
+        if self.chunked {
+            self.inner.write_all(b"0\r\n\r\n")?;
+        }
         Ok(())
     }
 }
+
+impl Write for BodyWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // 空 chunk 表示 body 结束，不能在中途发送
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.chunked {
+            write!(self.inner, "{:X}\r\n", buf.len())?;
+            self.inner.write_all(buf)?;
+            self.inner.write_all(b"\r\n")?;
+        } else {
+            self.inner.write_all(buf)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn request(raw: &str) -> HttpRequest {
+        HttpRequest::read_from(&mut Cursor::new(raw.as_bytes()), &mut io::sink()).unwrap().unwrap()
+    }
+
+    fn written(resp: HttpResponse, req: &HttpRequest) -> (String, bool) {
+        let mut out = Vec::new();
+        let keep_alive = resp.write_for(req, true, &mut out).unwrap();
+        (String::from_utf8(out).unwrap(), keep_alive)
+    }
+
+    #[test]
+    fn test_chunked_streaming_and_head() {
+        let get = request("GET /feed HTTP/1.1\r\n\r\n");
+        let (out, keep_alive) = written(HttpResponse::ok().chunks(vec!["ab", "", "cde"]), &get);
+        assert!(keep_alive);
+        assert!(out.contains("Transfer-Encoding: chunked\r\n"));
+        assert!(out.ends_with("\r\n\r\n2\r\nab\r\n3\r\ncde\r\n0\r\n\r\n"), "{}", out);
+
+        // HTTP/1.0 不支持 chunked：原样输出 body 并关闭连接
+        let old = request("GET /feed HTTP/1.0\r\nConnection: keep-alive\r\n\r\n");
+        let (out, keep_alive) = written(HttpResponse::ok().chunks(vec!["ab", "cde"]), &old);
+        assert!(!keep_alive);
+        assert!(out.contains("Connection: close\r\n") && out.ends_with("\r\n\r\nabcde"), "{}", out);
+
+        // HEAD 与 GET 的头部相同，但没有 body
+        let head = request("HEAD /feed HTTP/1.1\r\n\r\n");
+        let (out, _) = written(HttpResponse::ok().text("hello"), &head);
+        assert!(out.contains("Content-Length: 5\r\n") && out.ends_with("\r\n\r\n"), "{}", out);
+    }
+}
@@ -1,5 +1,12 @@
+use std::any::Any;
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
 
+use spring_beans::bean::BeanWrapper;
 use spring_context::context::application_context::ApplicationContext;
 
 use crate::method::HttpMethod;
@@ -16,7 +23,18 @@ pub type PlainHandlerFn = fn(&HttpRequest) -> HttpResponse;
 /// 带 bean 注入的路由处理函数: fn(req, bean_any) -> HttpResponse
 pub type BeanHandlerFn  = fn(&HttpRequest, &dyn std::any::Any) -> HttpResponse;
 
-/// 路由处理器的两种形式
+/// `async fn` handler 返回的 future。
+///
+/// 不要求 `Send`：一个请求从头到尾在同一个线程上处理，handler 可以跨 `.await` 持有 `Rc` 等类型。
+pub type ResponseFuture<'a> = Pin<Box<dyn Future<Output = HttpResponse> + 'a>>;
+
+/// 无 bean 的异步路由处理函数: async fn(req) -> HttpResponse
+pub type AsyncPlainHandlerFn = for<'a> fn(&'a HttpRequest) -> ResponseFuture<'a>;
+
+/// 带 bean 注入的异步路由处理函数: async fn(req, bean_any) -> HttpResponse
+pub type AsyncBeanHandlerFn  = for<'a> fn(&'a HttpRequest, &'a dyn std::any::Any) -> ResponseFuture<'a>;
+
+/// 路由处理器：同步 / 异步，各自分为是否需要 IoC bean 两种
 pub enum Handler {
     /// 普通函数，不依赖 IoC bean
     Plain(PlainHandlerFn),
@@ -25,6 +43,24 @@ pub enum Handler {
         bean_name: &'static str,
         f: BeanHandlerFn,
     },
+    /// `async fn`，不依赖 IoC bean
+    Async(AsyncPlainHandlerFn),
+    /// `async fn`，需要从 IoC 容器取 bean 后调用
+    AsyncWithBean {
+        bean_name: &'static str,
+        f: AsyncBeanHandlerFn,
+    },
+}
+
+impl Clone for Handler {
+    fn clone(&self) -> Self {
+        match self {
+            Handler::Plain(f) => Handler::Plain(*f),
+            Handler::WithBean { bean_name, f } => Handler::WithBean { bean_name, f: *f },
+            Handler::Async(f) => Handler::Async(*f),
+            Handler::AsyncWithBean { bean_name, f } => Handler::AsyncWithBean { bean_name, f: *f },
+        }
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -46,6 +82,11 @@ inventory::collect!(RouteRegistration);
 // Router – 运行时路由表
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// 运行时路由表。
+///
+/// [`Router::dispatch`] 不依赖异步运行时，`async fn` handler 在调用线程上执行到完成；
+/// 用到 tokio 的 handler（I/O、定时器、`tokio::spawn`）在这里会因为没有 reactor 而 panic（响应 500），
+/// 只能通过 [`Router::dispatch_async`] 在 tokio 中执行，即使用 `AsyncHttpServer`（`async` feature）。
 pub struct Router {
     routes: Vec<RouteRegistration>,
 }
@@ -61,41 +102,65 @@ impl Router {
             routes.push(RouteRegistration {
                 method: reg.method.clone(),
                 path:   reg.path,
-                handler: match &reg.handler {
-                    Handler::Plain(f) => Handler::Plain(*f),
-                    Handler::WithBean { bean_name, f } => Handler::WithBean {
-                        bean_name,
-                        f: *f,
-                    },
-                },
+                handler: reg.handler.clone(),
             });
         }
         Self { routes }
     }
 
     /// 根据请求匹配路由，调用 handler，返回响应。
-    /// 若找不到路由，返回 404；若 bean 不存在，返回 500。
+    /// 若找不到路由，返回 404；若 bean 不存在或无法创建，返回 500。
+    /// request / session 作用域的 controller 在当前请求的作用域中获取。
+    /// 没有单独映射的 `HEAD` 请求交给同一路径的 `GET` handler，写出时省略 body。
+    ///
+    /// `async fn` handler 在当前线程上阻塞执行到完成，不依赖任何异步运行时，
+    /// 因此 handler 只能 await 与运行时无关的 future（见 [`Router`]）。
     pub fn dispatch(
         &self,
         req: &mut HttpRequest,
         context: &dyn ApplicationContext,
     ) -> HttpResponse {
+        block_on(self.dispatch_async(req, context))
+    }
+
+    /// 与 [`Router::dispatch`] 相同，但异步等待 `async fn` handler；同步 handler 在第一次 poll 时直接执行
+    pub fn dispatch_async<'a>(
+        &'a self,
+        req: &'a mut HttpRequest,
+        context: &'a dyn ApplicationContext,
+    ) -> ResponseFuture<'a> {
+        let handler = match self.find(req) {
+            Ok(handler) => handler,
+            Err(resp) => return Box::pin(std::future::ready(resp)),
+        };
+        let req: &'a HttpRequest = req;
+        Box::pin(async move {
+            match handler {
+                Handler::Plain(f) => f(req),
+                Handler::Async(f) => f(req).await,
+                Handler::WithBean { bean_name, f } => match resolve_bean(context, bean_name) {
+                    Ok(bean) => f(req, bean.as_any()),
+                    Err(resp) => resp,
+                },
+                Handler::AsyncWithBean { bean_name, f } => match resolve_bean(context, bean_name) {
+                    Ok(bean) => f(req, bean.as_any()).await,
+                    Err(resp) => resp,
+                },
+            }
+        })
+    }
+
+    /// 匹配路由并填充路径参数；没有匹配的路由时返回 404 / 405 响应
+    fn find(&self, req: &mut HttpRequest) -> Result<&Handler, HttpResponse> {
+        let head_as_get = req.method == HttpMethod::HEAD
+            && !self.routes.iter().any(|r| r.method == HttpMethod::HEAD && match_path(r.path, &req.path).is_some());
         for route in &self.routes {
-            if route.method != req.method {
+            if route.method != req.method && !(head_as_get && route.method == HttpMethod::GET) {
                 continue;
             }
             if let Some(params) = match_path(route.path, &req.path) {
                 req.path_params = params; // 填充路径参数
-                return match &route.handler {
-                    Handler::Plain(f) => f(req),
-                    Handler::WithBean { bean_name, f } => {
-                        match context.get_bean(bean_name) {
-                            Some(bean) => f(req, bean),
-                            None => HttpResponse::internal_error()
-                                .text(format!("[spring-web] bean '{}' not found in IoC container", bean_name)),
-                        }
-                    }
-                };
+                return Ok(&route.handler);
             }
         }
 
@@ -104,12 +169,62 @@ impl Router {
             match_path(r.path, &req.path).is_some()
         });
         if path_matched {
-            HttpResponse::method_not_allowed()
-                .text(format!("405 Method Not Allowed: {} {}", req.method, req.path))
+            Err(HttpResponse::method_not_allowed()
+                .text(format!("405 Method Not Allowed: {} {}", req.method, req.path)))
         } else {
-            HttpResponse::not_found()
-                .text(format!("404 Not Found: {} {}", req.method, req.path))
+            Err(HttpResponse::not_found()
+                .text(format!("404 Not Found: {} {}", req.method, req.path)))
+        }
+    }
+}
+
+/// 路由 handler 使用的 controller：已创建的 singleton 直接借用，
+/// 其它（lazy、request / session 作用域……）通过 `get_bean_wrapper` 获取
+enum ControllerBean<'a> {
+    Borrowed(&'a dyn Any),
+    Owned(BeanWrapper),
+}
+
+impl ControllerBean<'_> {
+    fn as_any(&self) -> &dyn Any {
+        match self {
+            ControllerBean::Borrowed(bean) => *bean,
+            ControllerBean::Owned(bean) => bean.as_any(),
+        }
+    }
+}
+
+fn resolve_bean<'a>(context: &'a dyn ApplicationContext, bean_name: &str) -> Result<ControllerBean<'a>, HttpResponse> {
+    match context.get_bean(bean_name) {
+        Some(bean) => Ok(ControllerBean::Borrowed(bean)),
+        None if context.contains_bean(bean_name) => match context.get_bean_wrapper(bean_name) {
+            Ok(bean) => Ok(ControllerBean::Owned(bean)),
+            Err(e) => Err(HttpResponse::internal_error()
+                .text(format!("[spring-web] failed to obtain bean '{}': {}", bean_name, e))),
+        },
+        None => Err(HttpResponse::internal_error()
+            .text(format!("[spring-web] bean '{}' not found in IoC container", bean_name))),
+    }
+}
+
+/// 在当前线程上把 future 执行到完成：没有就绪时 park，waker 负责 unpark
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
         }
+        thread::park();
     }
 }
 
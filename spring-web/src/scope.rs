@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use spring_beans::bean::lifecycle::DestroyFn;
+use spring_beans::bean::BeanWrapper;
+use spring_beans::error::BeansError;
+use spring_beans::factory::config::{ObjectFactory, Scope, ScopeRegistration};
+
+use crate::request_context::RequestContextHolder;
+use crate::session::HttpSessions;
+
+/// `#[Scope("request")]`：每个 HTTP 请求一个实例，请求结束时销毁。
+///
+/// 只能在处理请求的线程中获取，其它时候返回 [`BeansError::ScopeNotActive`]。
+#[derive(Debug, Default)]
+pub struct RequestScope;
+
+impl Scope for RequestScope {
+    fn get(&self, name: &str, object_factory: &mut ObjectFactory<'_>) -> Result<BeanWrapper, BeansError> {
+        let existing = RequestContextHolder::with(|attributes| attributes.objects().get(name))
+            .ok_or_else(|| not_active(name, "request"))?;
+        if let Some(object) = existing {
+            return Ok(object);
+        }
+        // 创建期间不持有借用：object_factory 可能获取其它 request 作用域的 bean
+        let object = object_factory()?;
+        RequestContextHolder::with(|attributes| attributes.objects().insert(name, object.clone()));
+        Ok(object)
+    }
+
+    fn remove(&self, name: &str) -> Option<BeanWrapper> {
+        RequestContextHolder::with(|attributes| attributes.objects().remove(name)).flatten()
+    }
+
+    fn register_destruction_callback(&self, name: &str, callback: DestroyFn) {
+        RequestContextHolder::with(|attributes| attributes.objects().register_destruction_callback(name, callback));
+    }
+}
+
+/// `#[Scope("session")]`：每个 HTTP 会话一个实例。
+///
+/// 会话通过 `SESSIONID` cookie 识别，第一次获取 session 作用域的 bean 时创建，
+/// 超时或 [`HttpSessions::invalidate`] 时销毁其中的 bean。同一会话的请求可能在不同线程上处理，
+/// 因此 bean 类型必须是 `Send + Sync`。
+#[derive(Debug, Default)]
+pub struct SessionScope;
+
+impl Scope for SessionScope {
+    fn get(&self, name: &str, object_factory: &mut ObjectFactory<'_>) -> Result<BeanWrapper, BeansError> {
+        let session_id = RequestContextHolder::with(|attributes| attributes.get_or_create_session_id())
+            .ok_or_else(|| not_active(name, "session"))?;
+        if let Some(bean) = HttpSessions::get_bean(&session_id, name) {
+            return Ok(BeanWrapper::Shared(bean));
+        }
+        match object_factory()? {
+            BeanWrapper::Shared(bean) => Ok(BeanWrapper::Shared(HttpSessions::put_bean_if_absent(&session_id, name, bean))),
+            BeanWrapper::Local(_) => Err(BeansError::BeanCreation {
+                bean_name: name.to_string(),
+                message: "session-scoped bean type must be Send + Sync".to_string(),
+                cause: None,
+            }),
+        }
+    }
+
+    fn remove(&self, name: &str) -> Option<BeanWrapper> {
+        let session_id = RequestContextHolder::current_session_id()?;
+        HttpSessions::remove_bean(&session_id, name).map(BeanWrapper::Shared)
+    }
+
+    fn register_destruction_callback(&self, name: &str, callback: DestroyFn) {
+        if let Some(session_id) = RequestContextHolder::current_session_id() {
+            HttpSessions::register_destruction_callback(&session_id, name, callback);
+        }
+    }
+
+    fn get_conversation_id(&self) -> Option<String> {
+        RequestContextHolder::current_session_id()
+    }
+}
+
+fn not_active(name: &str, scope_name: &str) -> BeansError {
+    BeansError::ScopeNotActive { bean_name: name.to_string(), scope_name: scope_name.to_string() }
+}
+
+inventory::submit! {
+    ScopeRegistration { name: "request", scope: || Arc::new(RequestScope) }
+}
+
+inventory::submit! {
+    ScopeRegistration { name: "session", scope: || Arc::new(SessionScope) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::request_context::RequestAttributes;
+
+    struct Cart;
+
+    static DESTROYED: AtomicUsize = AtomicUsize::new(0);
+
+    fn create_cart() -> Result<BeanWrapper, BeansError> {
+        Ok(BeanWrapper::Shared(Arc::new(Cart)))
+    }
+
+    #[test]
+    fn test_request_and_session_lifecycle() {
+        let scope = RequestScope;
+        assert!(matches!(scope.get("cart", &mut create_cart), Err(BeansError::ScopeNotActive { .. })));
+
+        RequestContextHolder::set_request_attributes(RequestAttributes::new(None));
+        let first = scope.get("cart", &mut create_cart).unwrap();
+        scope.register_destruction_callback("cart", |_| {
+            DESTROYED.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        });
+        let again = scope.get("cart", &mut || panic!("request-scoped bean created twice")).unwrap();
+        assert!(Arc::ptr_eq(&first.downcast_arc::<Cart>().unwrap(), &again.downcast_arc::<Cart>().unwrap()));
+
+        let session = SessionScope.get("cart", &mut create_cart).unwrap();
+        let attributes = RequestContextHolder::reset_request_attributes().unwrap();
+        let session_id = attributes.new_session_id().unwrap().to_string();
+        attributes.request_completed();
+        assert_eq!(DESTROYED.load(Ordering::SeqCst), 1);
+
+        // 下一个请求带着 cookie 回来：拿到同一个会话中的实例
+        RequestContextHolder::set_request_attributes(RequestAttributes::new(Some(&session_id)));
+        let same = SessionScope.get("cart", &mut || panic!("session-scoped bean created twice")).unwrap();
+        assert!(Arc::ptr_eq(&session.downcast_arc::<Cart>().unwrap(), &same.downcast_arc::<Cart>().unwrap()));
+        let attributes = RequestContextHolder::reset_request_attributes().unwrap();
+        assert!(attributes.new_session_id().is_none());
+
+        HttpSessions::invalidate(&session_id);
+        assert!(RequestAttributes::new(Some(&session_id)).session_id().is_none());
+    }
+}
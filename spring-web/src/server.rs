@@ -1,77 +1,530 @@
-use std::net::TcpListener;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader};
+use std::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, TrySendError};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 use spring_context::context::application_context::ApplicationContext;
+use spring_context::context::configurable_application_context::ConfigurableApplicationContext;
 
-use crate::request::HttpRequest;
+use crate::request::{HttpRequest, RequestLimits};
+use crate::request_context::{RequestAttributes, RequestContextHolder, SESSION_COOKIE_NAME};
+use crate::response::HttpResponse;
 use crate::router::Router;
+use crate::server_properties::ServerProperties;
 
 /// HTTP 服务器
 ///
 /// 基于 `std::net::TcpListener` 实现，**纯 std**，不依赖任何异步运行时。
-/// 采用单线程循环（每次处理一个请求），适合学习和演示场景。
+/// 监听线程只负责 accept，连接放进有界队列后由 `server.threads` 个工作线程处理，
+/// 一个慢客户端只占用一个工作线程；队列满时新连接直接收到 `503`。
+///
+/// 工作线程共享同一个上下文，因此上下文必须是 `Send + Sync`（`Application::run_concurrent()`）。
 ///
 /// 使用示例：
-/// ```rust
-/// let context = Application::run();
-/// HttpServer::run(8080, context);
+/// ```rust,ignore
+/// let context = Application::run_concurrent();
+/// let server = HttpServer::bind(ServerProperties::from_environment(context.get_environment())?, context)?;
+/// let shutdown = server.shutdown_handle();
+/// // 在其它线程中调用 shutdown.shutdown() 优雅停机
+/// let context = server.serve();
 /// ```
-pub struct HttpServer;
+pub struct HttpServer<C> {
+    listener: TcpListener,
+    properties: ServerProperties,
+    context: C,
+    shutdown: ShutdownHandle,
+}
+
+/// 停止 [`HttpServer`] 的句柄，可以克隆并发送到其它线程。
+///
+/// [`ShutdownHandle::shutdown`] 之后服务器不再接受新连接，正在等待下一个请求的持久连接被关闭，
+/// 已接受的连接（包括队列中的）上进行中的请求处理完毕后工作线程退出，
+/// 最后关闭上下文（`close()`）并从 [`HttpServer::serve`] 返回。
+#[derive(Debug, Clone)]
+pub struct ShutdownHandle {
+    state: Arc<ShutdownState>,
+    /// 监听地址，停机时连接一次以唤醒阻塞在 `accept` 上的监听线程
+    wake_addr: SocketAddr,
+}
+
+#[derive(Debug, Default)]
+struct ShutdownState {
+    stopping: AtomicBool,
+    /// 正在等待下一个请求的连接，停机时关闭其读端
+    idle: Mutex<HashMap<u64, TcpStream>>,
+    next_id: AtomicU64,
+}
 
-impl HttpServer {
+impl ShutdownHandle {
+    pub(crate) fn for_listener(listener: &TcpListener) -> io::Result<Self> {
+        let wake_addr = match listener.local_addr()? {
+            SocketAddr::V4(addr) if addr.ip().is_unspecified() => SocketAddr::from((Ipv4Addr::LOCALHOST, addr.port())),
+            SocketAddr::V6(addr) if addr.ip().is_unspecified() => SocketAddr::from((Ipv6Addr::LOCALHOST, addr.port())),
+            addr => addr,
+        };
+        Ok(Self { state: Arc::default(), wake_addr })
+    }
+
+    pub fn shutdown(&self) {
+        if self.state.stopping.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let _ = TcpStream::connect_timeout(&self.wake_addr, Duration::from_secs(1));
+        for (_, stream) in self.idle().drain() {
+            let _ = stream.shutdown(Shutdown::Read);
+        }
+    }
+
+    pub fn is_shutdown(&self) -> bool {
+        self.state.stopping.load(Ordering::SeqCst)
+    }
+
+    fn idle(&self) -> MutexGuard<'_, HashMap<u64, TcpStream>> {
+        self.state.idle.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 登记等待请求的连接；已经停机时返回 `None`。
+    /// 在锁内检查停机标志，保证 [`ShutdownHandle::shutdown`] 不会漏掉刚登记的连接
+    fn enter_idle(&self, stream: &TcpStream) -> Option<u64> {
+        let mut idle = self.idle();
+        if self.is_shutdown() {
+            return None;
+        }
+        let id = self.state.next_id.fetch_add(1, Ordering::Relaxed);
+        idle.insert(id, stream.try_clone().ok()?);
+        Some(id)
+    }
+
+    fn leave_idle(&self, id: u64) {
+        self.idle().remove(&id);
+    }
+}
+
+impl<C: ConfigurableApplicationContext + Send + Sync + 'static> HttpServer<C> {
     /// 在 `port` 端口启动 HTTP 服务，阻塞直到程序退出。
     ///
-    /// `context` 实现了 `ApplicationContext`，用于解析 `WithBean` 路由中的 IoC bean。
-    pub fn run<C: ApplicationContext>(port: u16, context: C) {
-        let addr = format!("0.0.0.0:{}", port);
-        let listener = TcpListener::bind(&addr)
-            .unwrap_or_else(|e| panic!("[spring-web] failed to bind {}:{} — {}", addr, port, e));
+    /// 其余配置从上下文的 `server.*` 属性读取（见 [`ServerProperties`]）。
+    /// `context` 用于解析 `WithBean` 路由中的 IoC bean。
+    pub fn run(port: u16, context: C) {
+        let mut properties = ServerProperties::from_environment(context.get_environment())
+            .unwrap_or_else(|e| panic!("[spring-web] invalid server properties — {}", e));
+        properties.port = port;
+        let server = Self::bind(properties, context)
+            .unwrap_or_else(|e| panic!("[spring-web] failed to bind port {} — {}", port, e));
+        server.serve();
+    }
+
+    /// 绑定 `server.address:server.port`（端口为 `0` 时由系统分配），尚不开始处理请求
+    pub fn bind(properties: ServerProperties, context: C) -> io::Result<Self> {
+        let listener = TcpListener::bind((properties.address.as_str(), properties.port))?;
+        let shutdown = ShutdownHandle::for_listener(&listener)?;
+        Ok(Self { listener, properties, context, shutdown })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    /// 处理请求直到 [`ShutdownHandle::shutdown`]：停止 accept，处理完已接受的连接，
+    /// 然后关闭上下文并返回它
+    pub fn serve(self) -> C {
+        let Self { listener, properties, context, shutdown } = self;
+        let context = Arc::new(context);
 
         // 从 inventory 收集所有路由
-        let router = Router::from_registry();
+        let router = Arc::new(Router::from_registry());
 
+        let port = listener.local_addr().map(|addr| addr.port()).unwrap_or(properties.port);
         println!("[spring-web] ┌─────────────────────────────────────────┐");
         println!("[spring-web] │  Server started on http://localhost:{}  │", port);
         println!("[spring-web] └─────────────────────────────────────────┘");
+        println!(
+            "[spring-web] worker threads: {}, accept queue: {}",
+            properties.threads.max(1),
+            properties.accept_queue.max(1)
+        );
+
+        let (sender, receiver) = mpsc::sync_channel::<TcpStream>(properties.accept_queue.max(1));
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers: Vec<JoinHandle<()>> = (0..properties.threads.max(1))
+            .map(|index| {
+                let worker = Worker {
+                    receiver: receiver.clone(),
+                    router: router.clone(),
+                    context: context.clone(),
+                    read_timeout: properties.read_timeout,
+                    write_timeout: properties.write_timeout,
+                    max_keep_alive_requests: properties.max_keep_alive_requests.max(1),
+                    limits: properties.request_limits(),
+                    shutdown: shutdown.clone(),
+                };
+                thread::Builder::new()
+                    .name(format!("http-worker-{}", index))
+                    .spawn(move || worker.run())
+                    .expect("[spring-web] failed to spawn worker thread")
+            })
+            .collect();
 
         for stream in listener.incoming() {
+            if shutdown.is_shutdown() {
+                break;
+            }
             match stream {
-                Ok(mut tcp_stream) => {
-                    // 解析请求
-                    match HttpRequest::parse(&mut tcp_stream) {
-                        Ok(mut req) => {
-                            println!(
-                                "[spring-web] {} {} from {}",
-                                req.method,
-                                req.path,
-                                tcp_stream
-                                    .peer_addr()
-                                    .map(|a| a.to_string())
-                                    .unwrap_or_else(|_| "?".to_string())
-                            );
-
-                            // 分发到路由
-                            let resp = router.dispatch(&mut req, &context);
-
-                            println!(
-                                "[spring-web] → {} ({}B body)",
-                                resp.status,
-                                resp.body.len()
-                            );
-
-                            // 写回响应
-                            if let Err(e) = resp.write_to(&mut tcp_stream) {
-                                eprintln!("[spring-web] write error: {}", e);
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("[spring-web] parse error: {}", e);
+                Ok(tcp_stream) => match sender.try_send(tcp_stream) {
+                    Ok(()) => {}
+                    Err(TrySendError::Full(mut tcp_stream)) => {
+                        // 所有工作线程都在忙且队列已满：立即拒绝，不让监听线程被拖住
+                        let _ = tcp_stream.set_write_timeout(Some(Duration::from_secs(1)));
+                        let resp = HttpResponse::service_unavailable().text("503 Service Unavailable: server is busy");
+                        if let Err(e) = resp.write_to(&mut tcp_stream) {
+                            eprintln!("[spring-web] write error: {}", e);
                         }
                     }
-                }
+                    Err(TrySendError::Disconnected(_)) => break,
+                },
                 Err(e) => {
                     eprintln!("[spring-web] accept error: {}", e);
                 }
             }
         }
+
+        // 停止接受新连接；关闭队列后工作线程处理完剩余连接即退出
+        drop(listener);
+        drop(sender);
+        println!("[spring-web] shutting down, draining in-flight requests...");
+        for worker in workers {
+            if worker.join().is_err() {
+                eprintln!("[spring-web] worker thread panicked");
+            }
+        }
+        drop(router);
+
+        let mut context = Arc::try_unwrap(context)
+            .unwrap_or_else(|_| unreachable!("all worker threads have been joined"));
+        context.close();
+        println!("[spring-web] server stopped");
+        context
+    }
+}
+
+/// 工作线程：从队列中取连接并处理，队列关闭且为空时退出
+struct Worker<C> {
+    receiver: Arc<Mutex<Receiver<TcpStream>>>,
+    router: Arc<Router>,
+    context: Arc<C>,
+    read_timeout: Duration,
+    write_timeout: Duration,
+    max_keep_alive_requests: usize,
+    limits: RequestLimits,
+    shutdown: ShutdownHandle,
+}
+
+impl<C: ApplicationContext> Worker<C> {
+    fn run(self) {
+        loop {
+            // 锁只在取连接时持有，处理请求期间其它工作线程可以继续取
+            let next = self.receiver.lock().unwrap_or_else(|e| e.into_inner()).recv();
+            match next {
+                Ok(stream) => self.handle(stream),
+                Err(_) => break,
+            }
+        }
+    }
+
+    /// 处理一个连接上的所有请求：持久连接上按顺序处理（包括流水线中已经到达的请求），
+    /// 直到客户端要求关闭、达到 `server.max-keep-alive-requests`、出错或停机
+    fn handle(&self, tcp_stream: TcpStream) {
+        // 超时为 0 表示不限制；读超时同时限制持久连接上等待下一个请求的时间
+        let limit = |timeout: Duration| (!timeout.is_zero()).then_some(timeout);
+        let mut writer = match tcp_stream
+            .set_read_timeout(limit(self.read_timeout))
+            .and_then(|_| tcp_stream.set_write_timeout(limit(self.write_timeout)))
+            .and_then(|_| tcp_stream.try_clone())
+        {
+            Ok(writer) => writer,
+            Err(e) => {
+                eprintln!("[spring-web] socket error: {}", e);
+                return;
+            }
+        };
+        let peer = tcp_stream
+            .peer_addr()
+            .map(|a| a.to_string())
+            .unwrap_or_else(|_| "?".to_string());
+        let mut reader = BufReader::new(tcp_stream);
+
+        for served in 1.. {
+            // 等待请求的第一个字节期间连接是空闲的，停机时关闭（连接上的第一个请求除外）
+            if !self.wait_for_request(&mut reader, served == 1) {
+                return;
+            }
+
+            // 解析请求
+            let mut req = match HttpRequest::read_with_limits(&mut reader, &mut writer, &self.limits) {
+                Ok(Some(req)) => req,
+                Ok(None) => return,
+                Err(e) => {
+                    // 请求没有读完，连接上剩下的字节无法再解析，回复错误后关闭
+                    eprintln!("[spring-web] parse error from {}: {}", peer, e);
+                    if let Err(e) = e.to_response().write_to(&mut writer) {
+                        eprintln!("[spring-web] write error: {}", e);
+                    }
+                    return;
+                }
+            };
+            println!("[spring-web] {} {} from {}", req.method, req.path, peer);
+
+            // 分发到路由；handler panic 时返回 500，工作线程继续服务
+            let context: &dyn ApplicationContext = &*self.context;
+            let resp = panic::catch_unwind(AssertUnwindSafe(|| service(&self.router, &mut req, context)))
+                .unwrap_or_else(|_| {
+                    if let Some(attributes) = RequestContextHolder::reset_request_attributes() {
+                        attributes.request_completed();
+                    }
+                    HttpResponse::internal_error().text("500 Internal Server Error: handler panicked")
+                });
+
+            if resp.is_streaming() {
+                println!("[spring-web] → {} (streaming body)", resp.status);
+            } else {
+                println!("[spring-web] → {} ({}B body)", resp.status, resp.body.len());
+            }
+
+            // 写回响应
+            let keep_alive = req.is_keep_alive()
+                && served < self.max_keep_alive_requests
+                && !self.shutdown.is_shutdown();
+            match resp.write_for(&req, keep_alive, &mut writer) {
+                Ok(true) => {}
+                Ok(false) => return,
+                Err(e) => {
+                    eprintln!("[spring-web] write error: {}", e);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// 等待下一个请求到达；连接被关闭、等待超时或服务器停机时返回 false。
+    /// 停机前接受、停机后才轮到的连接（`first`）仍然处理第一个请求，等待时间受 read-timeout 限制；
+    /// 只拒绝持久连接上的后续请求
+    fn wait_for_request(&self, reader: &mut BufReader<TcpStream>, first: bool) -> bool {
+        // 流水线中的请求已经在缓冲区里
+        if !reader.buffer().is_empty() {
+            return true;
+        }
+        let id = match self.shutdown.enter_idle(reader.get_ref()) {
+            Some(id) => Some(id),
+            None if first => None,
+            None => return false,
+        };
+        let arrived = reader.fill_buf().is_ok_and(|buf| !buf.is_empty());
+        if let Some(id) = id {
+            self.shutdown.leave_idle(id);
+        }
+        arrived
+    }
+}
+
+/// 在请求作用域内分发：request 作用域的 bean 在响应生成后销毁，
+/// 本次请求新建的会话通过 `Set-Cookie` 返回给客户端
+fn service(router: &Router, req: &mut HttpRequest, context: &dyn ApplicationContext) -> HttpResponse {
+    RequestContextHolder::set_request_attributes(RequestAttributes::new(req.cookie(SESSION_COOKIE_NAME)));
+    let resp = router.dispatch(req, context);
+    finish_request(resp)
+}
+
+/// 解除当前线程上的请求绑定：新建的会话加上 `Set-Cookie`，销毁 request 作用域的 bean
+pub(crate) fn finish_request(mut resp: HttpResponse) -> HttpResponse {
+    if let Some(attributes) = RequestContextHolder::reset_request_attributes() {
+        if let Some(session_id) = attributes.new_session_id() {
+            resp = resp.header("Set-Cookie", format!("{}={}; Path=/; HttpOnly", SESSION_COOKIE_NAME, session_id));
+        }
+        attributes.request_completed();
+    }
+    resp
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use spring_context::context::support::ConcurrentApplicationContext;
+
+    use super::*;
+    use crate::method::HttpMethod;
+    use crate::request::HttpRequest;
+    use crate::router::{Handler, RouteRegistration};
+
+    static SLOW_STARTED: AtomicBool = AtomicBool::new(false);
+
+    fn slow(_req: &HttpRequest) -> HttpResponse {
+        SLOW_STARTED.store(true, Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(300));
+        HttpResponse::ok().text("done")
+    }
+
+    inventory::submit! {
+        RouteRegistration { method: HttpMethod::GET, path: "/slow", handler: Handler::Plain(slow) }
+    }
+
+    fn exchange(addr: SocketAddr, requests: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(requests.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_keep_alive_pipelining_and_graceful_shutdown() {
+        let mut context = ConcurrentApplicationContext::default();
+        context.refresh().unwrap();
+        let properties = ServerProperties {
+            address: "127.0.0.1".to_string(),
+            port: 0,
+            threads: 2,
+            ..ServerProperties::default()
+        };
+        let server = HttpServer::bind(properties, context).unwrap();
+        let addr = server.local_addr().unwrap();
+        let shutdown = server.shutdown_handle();
+        let serving = thread::spawn(move || server.serve());
+
+        // 只连接不发送的客户端占住一个工作线程，另一个仍然可以处理请求
+        let _idle = TcpStream::connect(addr).unwrap();
+
+        // 同一连接上的两个流水线请求按顺序响应；HEAD 只有头部，最后一个请求要求关闭连接
+        let response = exchange(
+            addr,
+            "HEAD /missing HTTP/1.1\r\nHost: localhost\r\n\r\n\
+             GET /missing HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+        );
+        let (first, second) = response.split_at(response.rfind("HTTP/1.1").unwrap());
+        assert!(first.starts_with("HTTP/1.1 404"), "{}", response);
+        assert!(first.contains("Connection: keep-alive\r\n") && first.ends_with("\r\n\r\n"), "{}", response);
+        assert!(second.contains("Connection: close\r\n"), "{}", response);
+        assert!(second.ends_with("404 Not Found: GET /missing"), "{}", response);
+
+        // 停机时关闭仍在等待请求的连接；之后上下文已 close()
+        shutdown.shutdown();
+        let context = serving.join().unwrap();
+        assert!(!context.is_active());
+        assert!(TcpStream::connect(addr).is_err());
+    }
+
+    #[test]
+    fn test_full_accept_queue_gets_503() {
+        let mut context = ConcurrentApplicationContext::default();
+        context.refresh().unwrap();
+        let properties = ServerProperties {
+            address: "127.0.0.1".to_string(),
+            port: 0,
+            threads: 1,
+            accept_queue: 1,
+            // 排队的连接不发送请求：停机后仍会等它的第一个请求，最多 read-timeout
+            read_timeout: Duration::from_millis(200),
+            ..ServerProperties::default()
+        };
+        let server = HttpServer::bind(properties, context).unwrap();
+        let addr = server.local_addr().unwrap();
+        let shutdown = server.shutdown_handle();
+        let serving = thread::spawn(move || server.serve());
+
+        // 收到响应说明唯一的工作线程已经接手这个连接，并在上面等待下一个请求
+        let mut busy = TcpStream::connect(addr).unwrap();
+        busy.write_all(b"GET /missing HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+        let mut response = Vec::new();
+        let mut buf = [0u8; 256];
+        while !response.ends_with(b"GET /missing") {
+            let read = busy.read(&mut buf).unwrap();
+            assert!(read > 0, "{}", String::from_utf8_lossy(&response));
+            response.extend_from_slice(&buf[..read]);
+        }
+
+        // 第二个连接占满队列，第三个在 accept 时就收到 503。
+        // 不先发送请求：服务器不读取被拒绝连接上的数据，未读的字节会让关闭变成 RST
+        let _queued = TcpStream::connect(addr).unwrap();
+        let mut rejected = TcpStream::connect(addr).unwrap();
+        let mut response = String::new();
+        rejected.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 503"), "{}", response);
+
+        shutdown.shutdown();
+        serving.join().unwrap();
+    }
+
+    #[test]
+    fn test_idle_connection_closed_after_read_timeout() {
+        let mut context = ConcurrentApplicationContext::default();
+        context.refresh().unwrap();
+        let properties = ServerProperties {
+            address: "127.0.0.1".to_string(),
+            port: 0,
+            threads: 1,
+            read_timeout: Duration::from_millis(100),
+            ..ServerProperties::default()
+        };
+        let server = HttpServer::bind(properties, context).unwrap();
+        let addr = server.local_addr().unwrap();
+        let shutdown = server.shutdown_handle();
+        let serving = thread::spawn(move || server.serve());
+
+        // 不发送任何数据：超过 read-timeout 后服务器关闭连接，客户端读到 EOF
+        let mut idle = TcpStream::connect(addr).unwrap();
+        idle.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        let started = std::time::Instant::now();
+        assert_eq!(idle.read(&mut [0u8; 16]).unwrap(), 0);
+        assert!(started.elapsed() >= Duration::from_millis(100));
+
+        // 唯一的工作线程已经释放，可以继续处理新连接
+        let response = exchange(addr, "GET /missing HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 404"), "{}", response);
+
+        shutdown.shutdown();
+        serving.join().unwrap();
+    }
+
+    #[test]
+    fn test_queued_connection_is_served_after_shutdown() {
+        let mut context = ConcurrentApplicationContext::default();
+        context.refresh().unwrap();
+        let properties = ServerProperties {
+            address: "127.0.0.1".to_string(),
+            port: 0,
+            threads: 1,
+            ..ServerProperties::default()
+        };
+        let server = HttpServer::bind(properties, context).unwrap();
+        let addr = server.local_addr().unwrap();
+        let shutdown = server.shutdown_handle();
+        let serving = thread::spawn(move || server.serve());
+
+        // 唯一的工作线程在慢 handler 里，第二个连接排在队列中
+        let busy = thread::spawn(move || exchange(addr, "GET /slow HTTP/1.1\r\nHost: localhost\r\n\r\n"));
+        while !SLOW_STARTED.load(Ordering::SeqCst) {
+            thread::sleep(Duration::from_millis(5));
+        }
+        let mut queued = TcpStream::connect(addr).unwrap();
+        queued.write_all(b"GET /slow HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+        thread::sleep(Duration::from_millis(50));
+
+        // 停机前已经接受的连接仍然得到响应，持久连接随后关闭
+        shutdown.shutdown();
+        let mut response = String::new();
+        queued.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+        assert!(response.contains("Connection: close\r\n"), "{}", response);
+        assert!(busy.join().unwrap().starts_with("HTTP/1.1 200"));
+        serving.join().unwrap();
     }
 }
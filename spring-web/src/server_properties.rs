@@ -0,0 +1,108 @@
+use std::num::NonZeroUsize;
+use std::thread;
+use std::time::Duration;
+
+use spring_beans::env::bind::{Bind, BindErrors, Bindable, Binder, DataSize};
+use spring_beans::env::Environment;
+
+use crate::request::RequestLimits;
+
+/// `server.*` 配置，对标 Spring Boot 的 `ServerProperties`。
+///
+/// ```properties
+/// server.address=0.0.0.0
+/// server.port=8080
+/// server.threads=8
+/// server.accept-queue=64
+/// server.read-timeout=30s
+/// server.write-timeout=30s
+/// server.max-keep-alive-requests=100
+/// server.max-http-request-size=2MB
+/// server.max-http-request-header-size=8KB
+/// server.max-http-request-headers=100
+/// ```
+///
+/// 超时为 `0` 表示不限制。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerProperties {
+    /// 监听地址
+    pub address: String,
+    pub port: u16,
+    /// 处理请求的工作线程数，默认为 CPU 核数
+    pub threads: usize,
+    /// 已接受、等待工作线程处理的连接上限；队列满时新连接直接收到 `503`
+    pub accept_queue: usize,
+    /// 读取请求（请求行、头部与 body）以及持久连接上等待下一个请求的超时
+    pub read_timeout: Duration,
+    /// 写回响应的超时
+    pub write_timeout: Duration,
+    /// 一个持久连接上最多处理的请求数，之后的响应带 `Connection: close`
+    pub max_keep_alive_requests: usize,
+    /// 请求 body 的上限，超出时返回 `413`
+    pub max_http_request_size: DataSize,
+    /// 请求行加头部的上限，超出时返回 `431`
+    pub max_http_request_header_size: DataSize,
+    /// 请求头个数的上限，超出时返回 `431`
+    pub max_http_request_headers: usize,
+}
+
+impl Default for ServerProperties {
+    fn default() -> Self {
+        Self {
+            address: "0.0.0.0".to_string(),
+            port: 8080,
+            threads: thread::available_parallelism().map_or(4, NonZeroUsize::get),
+            accept_queue: 64,
+            read_timeout: Duration::from_secs(30),
+            write_timeout: Duration::from_secs(30),
+            max_keep_alive_requests: 100,
+            max_http_request_size: DataSize::of_megabytes(2),
+            max_http_request_header_size: DataSize::of_kilobytes(8),
+            max_http_request_headers: 100,
+        }
+    }
+}
+
+impl ServerProperties {
+    /// 从 `environment` 中绑定 `server.*`，未配置的字段保持默认值
+    pub fn from_environment(environment: &Environment) -> Result<Self, BindErrors> {
+        let mut properties = Self::default();
+        Binder::new(environment).bind_into("server", &mut properties)?;
+        Ok(properties)
+    }
+
+    /// 读取请求时使用的大小限制
+    pub fn request_limits(&self) -> RequestLimits {
+        RequestLimits {
+            max_body_size: self.max_http_request_size.to_bytes(),
+            max_header_size: usize::try_from(self.max_http_request_header_size.to_bytes()).unwrap_or(usize::MAX),
+            max_headers: self.max_http_request_headers,
+        }
+    }
+}
+
+// 手写的实现与 #[derive(Bindable)] 生成的代码一致（spring-web 不依赖过程宏）
+impl Bindable for ServerProperties {
+    fn bind_properties(&mut self, binder: &mut Binder<'_>, prefix: &str) -> bool {
+        let mut bound = false;
+        macro_rules! field {
+            ($field:ident) => {
+                if let Some(v) = Bind::bind(binder, &binder.field_name(prefix, stringify!($field))) {
+                    self.$field = v;
+                    bound = true;
+                }
+            };
+        }
+        field!(address);
+        field!(port);
+        field!(threads);
+        field!(accept_queue);
+        field!(read_timeout);
+        field!(write_timeout);
+        field!(max_keep_alive_requests);
+        field!(max_http_request_size);
+        field!(max_http_request_header_size);
+        field!(max_http_request_headers);
+        bound
+    }
+}
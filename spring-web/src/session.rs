@@ -0,0 +1,131 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant, SystemTime};
+
+use spring_beans::bean::lifecycle::DestroyFn;
+use spring_beans::factory::SharedBean;
+
+/// 会话在最后一次访问之后保留的时长
+pub const SESSION_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// 一个会话中 session 作用域的 bean；可能被不同线程上的请求访问，因此只保存 `Send + Sync` 的实例
+struct Session {
+    beans: HashMap<String, SharedBean>,
+    destruction_callbacks: Vec<(String, DestroyFn)>,
+    last_accessed: Instant,
+}
+
+impl Session {
+    fn new() -> Self {
+        Self { beans: HashMap::new(), destruction_callbacks: Vec::new(), last_accessed: Instant::now() }
+    }
+
+    fn destroy(self) {
+        for (name, callback) in self.destruction_callbacks.into_iter().rev() {
+            if let Some(bean) = self.beans.get(&name) {
+                if let Err(e) = callback(bean.as_ref()) {
+                    eprintln!("Destroy method on session-scoped bean with name '{}' threw an error: {}", name, e);
+                }
+            }
+        }
+    }
+}
+
+/// 进程内的会话存储，供 session 作用域使用
+pub struct HttpSessions;
+
+impl HttpSessions {
+    /// 使会话失效并销毁其中的 bean
+    pub fn invalidate(session_id: &str) {
+        let session = sessions().remove(session_id);
+        if let Some(session) = session {
+            session.destroy();
+        }
+    }
+
+    /// 销毁超过 [`SESSION_TIMEOUT`] 没有访问的会话
+    pub fn evict_expired() {
+        let expired: Vec<Session> = {
+            let mut sessions = sessions();
+            let ids: Vec<String> = sessions
+                .iter()
+                .filter(|(_, session)| session.last_accessed.elapsed() > SESSION_TIMEOUT)
+                .map(|(id, _)| id.clone())
+                .collect();
+            ids.iter().filter_map(|id| sessions.remove(id)).collect()
+        };
+        // 销毁回调可能耗时，不在锁内执行
+        expired.into_iter().for_each(Session::destroy);
+    }
+
+    pub(crate) fn create() -> String {
+        let id = generate_session_id();
+        sessions().insert(id.clone(), Session::new());
+        id
+    }
+
+    /// 刷新最后访问时间；会话不存在时返回 false
+    pub(crate) fn touch(session_id: &str) -> bool {
+        match sessions().get_mut(session_id) {
+            Some(session) => {
+                session.last_accessed = Instant::now();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn get_bean(session_id: &str, name: &str) -> Option<SharedBean> {
+        sessions().get(session_id)?.beans.get(name).cloned()
+    }
+
+    /// 放入会话；其它请求已经先放入同名 bean 时返回先放入的那个
+    pub(crate) fn put_bean_if_absent(session_id: &str, name: &str, bean: SharedBean) -> SharedBean {
+        let mut sessions = sessions();
+        let session = sessions.entry(session_id.to_string()).or_insert_with(Session::new);
+        session.beans.entry(name.to_string()).or_insert(bean).clone()
+    }
+
+    pub(crate) fn remove_bean(session_id: &str, name: &str) -> Option<SharedBean> {
+        let mut sessions = sessions();
+        let session = sessions.get_mut(session_id)?;
+        session.destruction_callbacks.retain(|(n, _)| n != name);
+        session.beans.remove(name)
+    }
+
+    pub(crate) fn register_destruction_callback(session_id: &str, name: &str, callback: DestroyFn) {
+        if let Some(session) = sessions().get_mut(session_id) {
+            session.destruction_callbacks.retain(|(n, _)| n != name);
+            session.destruction_callbacks.push((name.to_string(), callback));
+        }
+    }
+}
+
+fn sessions() -> MutexGuard<'static, HashMap<String, Session>> {
+    static SESSIONS: OnceLock<Mutex<HashMap<String, Session>>> = OnceLock::new();
+    SESSIONS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// 128 位随机 id：`RandomState` 每次使用不同的随机 key，再混入计数器与时间
+fn generate_session_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    (0..2)
+        .map(|_| {
+            let mut hasher = RandomState::new().build_hasher();
+            hasher.write_u64(count);
+            hasher.write_u128(nanos);
+            format!("{:016x}", hasher.finish())
+        })
+        .collect()
+}
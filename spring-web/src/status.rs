@@ -15,8 +15,11 @@ impl StatusCode {
     pub const FORBIDDEN:             Self = Self(403);
     pub const NOT_FOUND:             Self = Self(404);
     pub const METHOD_NOT_ALLOWED:    Self = Self(405);
+    pub const PAYLOAD_TOO_LARGE:     Self = Self(413);
+    pub const UNSUPPORTED_MEDIA_TYPE: Self = Self(415);
     pub const CONFLICT:              Self = Self(409);
     pub const UNPROCESSABLE_ENTITY:  Self = Self(422);
+    pub const REQUEST_HEADER_FIELDS_TOO_LARGE: Self = Self(431);
     pub const INTERNAL_SERVER_ERROR: Self = Self(500);
     pub const NOT_IMPLEMENTED:       Self = Self(501);
     pub const BAD_GATEWAY:           Self = Self(502);
@@ -36,8 +39,11 @@ impl StatusCode {
             403 => "Forbidden",
             404 => "Not Found",
             405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            415 => "Unsupported Media Type",
             409 => "Conflict",
             422 => "Unprocessable Entity",
+            431 => "Request Header Fields Too Large",
             500 => "Internal Server Error",
             501 => "Not Implemented",
             502 => "Bad Gateway",